@@ -0,0 +1,84 @@
+use clap::{Parser, Subcommand, ValueEnum};
+use std::path::PathBuf;
+
+/// Komut satırı argümanları
+#[derive(Debug, Parser)]
+#[command(
+    name = "meloic",
+    version,
+    about = "A CLI music player written in Rust",
+    // "meloic ~/Music scan": alt komut adı klasör sanılmaz; önce verilen klasörler alt komuta geçer
+    subcommand_precedence_over_arg = true
+)]
+pub struct Cli {
+    /// Music directories to scan (defaults to ./music or the current directory)
+    #[arg(value_name = "DIR")]
+    pub roots: Vec<PathBuf>,
+
+    /// Play tracks in random order
+    #[arg(long, global = true)]
+    pub shuffle: bool,
+
+    /// Playback volume in percent (0-100)
+    #[arg(long, global = true, value_parser = clap::value_parser!(u8).range(0..=100))]
+    pub volume: Option<u8>,
+
+    /// Path to an alternative config file
+    #[arg(long, global = true, value_name = "FILE")]
+    pub config: Option<PathBuf>,
+
+    /// Play without the terminal UI, printing each track as it starts
+    #[arg(long, global = true)]
+    pub no_ui: bool,
+
+    #[command(subcommand)]
+    pub command: Option<Command>,
+}
+
+/// Alt komutlar
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// Scan music directories and update the library index
+    Scan {
+        /// Music directories to scan
+        #[arg(value_name = "DIR")]
+        roots: Vec<PathBuf>,
+    },
+    /// Print the tracks in the library
+    List {
+        /// Music directories to list
+        #[arg(value_name = "DIR")]
+        roots: Vec<PathBuf>,
+
+        /// Output format
+        #[arg(long, value_enum, default_value_t = ListFormat::Plain)]
+        format: ListFormat,
+    },
+    /// Measure track and album loudness (EBU R128) and store ReplayGain values
+    Loudness {
+        /// Music directories to analyze
+        #[arg(value_name = "DIR")]
+        roots: Vec<PathBuf>,
+
+        /// Also write the values to the files' ReplayGain tags
+        #[arg(long)]
+        write_tags: bool,
+
+        /// Measure tracks that already have ReplayGain values again
+        #[arg(long)]
+        force: bool,
+    },
+    /// Play the given files, directories or playlists
+    Play {
+        /// Audio files, directories, playlists (M3U, PLS, XSPF) or CUE sheets
+        #[arg(value_name = "PATH", required = true)]
+        paths: Vec<PathBuf>,
+    },
+}
+
+/// `list` çıktı biçimi
+#[derive(Debug, Clone, Copy, PartialEq, ValueEnum)]
+pub enum ListFormat {
+    Plain,
+    Json,
+}
@@ -0,0 +1,547 @@
+use globset::{Glob, GlobSet, GlobSetBuilder};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use crate::library::ScanFilter;
+use crate::player::{
+    Crossfade, EffectConfig, EqPreset, FadeCurve, Normalization, ReplayGainMode, VolumeCurve,
+};
+use crate::playlist::smart::SmartPlaylist;
+use crate::ui::events::KeyMap;
+use crate::ui::theme::Theme;
+
+/// En uzun parçalar arası geçiş süresi (saniye)
+const MAX_CROSSFADE_SECS: f32 = 12.0;
+/// ReplayGain ön kazancının sınırı (dB)
+const MAX_PREAMP_DB: f32 = 15.0;
+
+/// ~/.config/meloic/config.toml dosyasından okunan ayarlar
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    pub library: LibraryConfig,
+    pub ui: UiConfig,
+    pub playback: PlaybackConfig,
+    pub theme: ThemeConfig,
+    pub equalizer: EqualizerConfig,
+    /// [[effects]] tabloları: ekolayzırdan sonra sırayla uygulanan efektler
+    pub effects: Vec<EffectConfig>,
+    pub keys: BTreeMap<String, KeySpec>,
+    /// [[smart_playlists]] tabloları: kurallarla doldurulan listeler
+    pub smart_playlists: Vec<SmartPlaylist>,
+}
+
+/// [library] bölümü
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct LibraryConfig {
+    pub roots: Vec<PathBuf>,
+    pub exclude: Vec<String>,
+    pub extensions: Vec<String>,
+}
+
+/// [ui] bölümü
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct UiConfig {
+    pub page_size: usize,
+    pub poll_interval_ms: u64,
+}
+
+/// [playback] bölümü
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct PlaybackConfig {
+    /// Varsayılan ses seviyesi (yüzde); kaydedilmiş seviye varsa o kullanılır
+    pub volume: u8,
+    /// Ses açma/kısma adımı (yüzde)
+    pub volume_step: u8,
+    /// Seviyenin kazanca çevrilme eğrisi: "linear" veya "logarithmic"
+    pub volume_curve: VolumeCurve,
+    /// Kısa ileri/geri sarma adımı (saniye)
+    pub seek_step_secs: u64,
+    /// Uzun ileri/geri sarma adımı (saniye)
+    pub seek_long_step_secs: u64,
+    /// Parçalar arası geçiş süresi (saniye); sıfırsa parçalar boşluksuz art arda çalar
+    pub crossfade_secs: f32,
+    /// Geçiş eğrisi: "linear" veya "equal-power"
+    pub crossfade_curve: FadeCurve,
+    /// Aynı albümdeki parçalar arasında geçiş yapma
+    pub crossfade_skip_same_album: bool,
+    /// ReplayGain kipi: "off", "track", "album" veya "auto"
+    pub replaygain: ReplayGainMode,
+    /// ReplayGain kazancına eklenen değer (dB)
+    pub replaygain_preamp_db: f32,
+    /// Tepe değerine göre kazancı kırpılmayacak kadar sınırla
+    pub replaygain_prevent_clipping: bool,
+}
+
+/// [theme] bölümü: hazır tema ve isteğe bağlı renk değişiklikleri
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ThemeConfig {
+    pub preset: String,
+    pub primary: Option<String>,
+    pub secondary: Option<String>,
+    pub accent: Option<String>,
+    pub success: Option<String>,
+    pub info: Option<String>,
+    pub warning: Option<String>,
+    pub error: Option<String>,
+    pub text: Option<String>,
+    pub text_dim: Option<String>,
+    pub muted: Option<String>,
+}
+
+/// [equalizer] bölümü: açılıştaki ayar ve kullanıcının hazır ayarları
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct EqualizerConfig {
+    pub enabled: bool,
+    /// Açılıştaki hazır ayar; arayüzde yapılan değişiklikler config değişene kadar korunur
+    pub preset: String,
+    /// [[equalizer.presets]] tabloları
+    pub presets: Vec<EqPreset>,
+}
+
+/// [keys] değeri: tek tuş ("q") veya tuş listesi (["q", "Esc"])
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+pub enum KeySpec {
+    One(String),
+    Many(Vec<String>),
+}
+
+impl KeySpec {
+    pub fn keys(&self) -> Vec<&str> {
+        match self {
+            KeySpec::One(key) => vec![key.as_str()],
+            KeySpec::Many(keys) => keys.iter().map(String::as_str).collect(),
+        }
+    }
+}
+
+impl Default for LibraryConfig {
+    fn default() -> Self {
+        LibraryConfig {
+            roots: Vec::new(),
+            exclude: Vec::new(),
+            extensions: ["mp3", "flac", "wav", "ogg", "m4a", "aac"]
+                .map(String::from)
+                .to_vec(),
+        }
+    }
+}
+
+impl Default for UiConfig {
+    fn default() -> Self {
+        UiConfig {
+            page_size: 10,
+            poll_interval_ms: 100,
+        }
+    }
+}
+
+impl PlaybackConfig {
+    /// Parçalar arası geçiş ayarları
+    pub fn crossfade(&self) -> Crossfade {
+        Crossfade {
+            duration: Duration::from_secs_f32(self.crossfade_secs.clamp(0.0, MAX_CROSSFADE_SECS)),
+            curve: self.crossfade_curve,
+            skip_same_album: self.crossfade_skip_same_album,
+        }
+    }
+
+    /// ReplayGain ayarları
+    pub fn normalization(&self) -> Normalization {
+        Normalization {
+            mode: self.replaygain,
+            preamp_db: self.replaygain_preamp_db,
+            prevent_clipping: self.replaygain_prevent_clipping,
+        }
+    }
+}
+
+impl Default for PlaybackConfig {
+    fn default() -> Self {
+        PlaybackConfig {
+            volume: 100,
+            volume_step: 5,
+            volume_curve: VolumeCurve::default(),
+            seek_step_secs: 5,
+            seek_long_step_secs: 30,
+            crossfade_secs: 0.0,
+            crossfade_curve: FadeCurve::default(),
+            crossfade_skip_same_album: true,
+            replaygain: ReplayGainMode::default(),
+            replaygain_preamp_db: 0.0,
+            replaygain_prevent_clipping: true,
+        }
+    }
+}
+
+impl EqualizerConfig {
+    /// Hazır ayarlar ve ardından kullanıcının ayarları
+    pub fn presets(&self) -> Vec<EqPreset> {
+        let mut presets = EqPreset::builtin();
+        presets.extend(self.presets.iter().cloned());
+        presets
+    }
+
+    /// Açılışta kullanılacak hazır ayar
+    pub fn startup_preset(&self) -> Option<EqPreset> {
+        self.presets()
+            .into_iter()
+            .find(|preset| preset.name == self.preset)
+    }
+}
+
+impl Default for EqualizerConfig {
+    fn default() -> Self {
+        EqualizerConfig {
+            enabled: false,
+            preset: "flat".to_string(),
+            presets: Vec::new(),
+        }
+    }
+}
+
+impl Default for ThemeConfig {
+    fn default() -> Self {
+        ThemeConfig {
+            preset: "neon".to_string(),
+            primary: None,
+            secondary: None,
+            accent: None,
+            success: None,
+            info: None,
+            warning: None,
+            error: None,
+            text: None,
+            text_dim: None,
+            muted: None,
+        }
+    }
+}
+
+/// Config yükleme hataları
+#[derive(Debug)]
+pub enum ConfigError {
+    Io(PathBuf, io::Error),
+    Parse(PathBuf, toml::de::Error),
+    Invalid(PathBuf, Vec<String>),
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigError::Io(path, e) => {
+                write!(f, "could not read config file {}: {}", path.display(), e)
+            }
+            ConfigError::Parse(path, e) => {
+                write!(f, "could not parse config file {}:\n{}", path.display(), e)
+            }
+            ConfigError::Invalid(path, problems) => {
+                write!(f, "invalid config file {}:", path.display())?;
+                for problem in problems {
+                    write!(f, "\n  - {}", problem)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+impl Config {
+    /// Varsayılan config dosyasının yolu (~/.config/meloic/config.toml)
+    pub fn default_path() -> Option<PathBuf> {
+        dirs::config_dir().map(|dir| dir.join("meloic").join("config.toml"))
+    }
+
+    /// Config'i yükle: verilen yol yoksa hata, varsayılan dosya yoksa varsayılan ayarlar
+    pub fn load(explicit: Option<&Path>) -> Result<Config, ConfigError> {
+        let path = match explicit {
+            Some(path) => path.to_path_buf(),
+            None => match Config::default_path() {
+                Some(path) if path.is_file() => path,
+                _ => return Ok(Config::default()),
+            },
+        };
+
+        let text = fs::read_to_string(&path).map_err(|e| ConfigError::Io(path.clone(), e))?;
+        let mut config: Config =
+            toml::from_str(&text).map_err(|e| ConfigError::Parse(path.clone(), e))?;
+
+        let problems = config.validate();
+        if !problems.is_empty() {
+            return Err(ConfigError::Invalid(path, problems));
+        }
+
+        config.library.roots = config
+            .library
+            .roots
+            .iter()
+            .map(|p| expand_home(p))
+            .collect();
+        config.library.extensions = config
+            .library
+            .extensions
+            .iter()
+            .map(|ext| ext.to_lowercase())
+            .collect();
+        Ok(config)
+    }
+
+    /// Değerleri kontrol et ve bulunan tüm sorunları döndür
+    fn validate(&self) -> Vec<String> {
+        let mut problems = Vec::new();
+
+        if self.library.extensions.is_empty() {
+            problems.push("library.extensions must list at least one extension".to_string());
+        }
+        for ext in &self.library.extensions {
+            if ext.is_empty() || ext.starts_with('.') || ext.contains(['/', '\\']) {
+                problems.push(format!(
+                    "library.extensions: '{}' is not a valid extension (write it without a dot, e.g. \"mp3\")",
+                    ext
+                ));
+            }
+        }
+        for pattern in &self.library.exclude {
+            if let Err(e) = Glob::new(pattern) {
+                problems.push(format!(
+                    "library.exclude: invalid glob '{}': {}",
+                    pattern, e
+                ));
+            }
+        }
+
+        if self.ui.page_size == 0 {
+            problems.push("ui.page_size must be at least 1".to_string());
+        }
+        if !(10..=1000).contains(&self.ui.poll_interval_ms) {
+            problems.push(format!(
+                "ui.poll_interval_ms must be between 10 and 1000 (got {})",
+                self.ui.poll_interval_ms
+            ));
+        }
+
+        if self.playback.volume > 100 {
+            problems.push(format!(
+                "playback.volume must be between 0 and 100 (got {})",
+                self.playback.volume
+            ));
+        }
+        if !(1..=50).contains(&self.playback.volume_step) {
+            problems.push(format!(
+                "playback.volume_step must be between 1 and 50 (got {})",
+                self.playback.volume_step
+            ));
+        }
+        if self.playback.seek_step_secs == 0 || self.playback.seek_long_step_secs == 0 {
+            problems.push(
+                "playback.seek_step_secs and playback.seek_long_step_secs must be at least 1"
+                    .to_string(),
+            );
+        }
+
+        if !(0.0..=MAX_CROSSFADE_SECS).contains(&self.playback.crossfade_secs) {
+            problems.push(format!(
+                "playback.crossfade_secs must be between 0 and {} (got {})",
+                MAX_CROSSFADE_SECS, self.playback.crossfade_secs
+            ));
+        }
+
+        if !(-MAX_PREAMP_DB..=MAX_PREAMP_DB).contains(&self.playback.replaygain_preamp_db) {
+            problems.push(format!(
+                "playback.replaygain_preamp_db must be between -{} and {} (got {})",
+                MAX_PREAMP_DB, MAX_PREAMP_DB, self.playback.replaygain_preamp_db
+            ));
+        }
+
+        let presets = self.equalizer.presets();
+        for (i, preset) in presets.iter().enumerate() {
+            if let Err(e) = preset.validate() {
+                problems.push(format!("equalizer.presets '{}': {}", preset.name, e));
+            }
+            if presets[..i].iter().any(|other| other.name == preset.name) {
+                problems.push(format!(
+                    "equalizer.presets: '{}' is already defined",
+                    preset.name
+                ));
+            }
+        }
+        if self.equalizer.startup_preset().is_none() {
+            problems.push(format!(
+                "equalizer.preset: unknown preset '{}'",
+                self.equalizer.preset
+            ));
+        }
+
+        for (i, effect) in self.effects.iter().enumerate() {
+            if let Err(e) = effect.validate() {
+                problems.push(format!("effects '{}': {}", effect.name, e));
+            }
+            if self.effects[..i]
+                .iter()
+                .any(|other| other.name == effect.name)
+            {
+                problems.push(format!(
+                    "effects: '{}' is listed more than once",
+                    effect.name
+                ));
+            }
+        }
+
+        if let Err(e) = Theme::from_config(&self.theme) {
+            problems.push(e);
+        }
+        if let Err(e) = KeyMap::from_config(&self.keys) {
+            problems.push(e);
+        }
+        for (i, smart) in self.smart_playlists.iter().enumerate() {
+            if let Err(e) = smart.validate() {
+                problems.push(format!("smart_playlists '{}': {}", smart.name, e));
+            }
+            if self.smart_playlists[..i]
+                .iter()
+                .any(|other| other.name == smart.name)
+            {
+                problems.push(format!(
+                    "smart_playlists: '{}' is defined more than once",
+                    smart.name
+                ));
+            }
+        }
+
+        problems
+    }
+
+    /// Taramada kullanılacak uzantı ve hariç tutma filtresi
+    pub fn scan_filter(&self) -> ScanFilter {
+        let mut builder = GlobSetBuilder::new();
+        for pattern in &self.library.exclude {
+            // Doğrulanmış config'de geçersiz glob olmaz
+            if let Ok(glob) = Glob::new(pattern) {
+                builder.add(glob);
+            }
+        }
+        ScanFilter {
+            extensions: self.library.extensions.clone(),
+            exclude: builder.build().unwrap_or_else(|_| GlobSet::empty()),
+        }
+    }
+}
+
+/// "~/Music" gibi yolları ev dizinine genişlet
+pub fn expand_home(path: &Path) -> PathBuf {
+    match (path.strip_prefix("~"), dirs::home_dir()) {
+        (Ok(rest), Some(home)) => home.join(rest),
+        _ => path.to_path_buf(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// TOML metnini oku ve bulunan sorunları döndür
+    fn problems(text: &str) -> Vec<String> {
+        toml::from_str::<Config>(text).unwrap().validate()
+    }
+
+    #[test]
+    fn defaults_are_valid() {
+        assert_eq!(problems(""), Vec::<String>::new());
+    }
+
+    #[test]
+    fn bad_globs_are_reported() {
+        assert_eq!(
+            problems("[library]\nexclude = [\"**/Podcasts\", \"[abc\"]"),
+            vec![
+                "library.exclude: invalid glob '[abc': error parsing glob '[abc': unclosed character class; missing ']'"
+                    .to_string()
+            ]
+        );
+    }
+
+    #[test]
+    fn bad_extensions_are_reported() {
+        let found = problems("[library]\nextensions = [\"mp3\", \".flac\", \"\", \"a/b\"]");
+        assert_eq!(found.len(), 3);
+        assert!(found[0].starts_with("library.extensions: '.flac' is not a valid extension"));
+        assert!(found[1].starts_with("library.extensions: '' is not a valid extension"));
+        assert!(found[2].starts_with("library.extensions: 'a/b' is not a valid extension"));
+        assert_eq!(
+            problems("[library]\nextensions = []"),
+            vec!["library.extensions must list at least one extension".to_string()]
+        );
+    }
+
+    #[test]
+    fn out_of_range_playback_values_are_reported() {
+        assert_eq!(
+            problems("[playback]\nvolume = 101\nvolume_step = 0"),
+            vec![
+                "playback.volume must be between 0 and 100 (got 101)".to_string(),
+                "playback.volume_step must be between 1 and 50 (got 0)".to_string(),
+            ]
+        );
+        assert_eq!(
+            problems("[playback]\ncrossfade_secs = 12.5\nreplaygain_preamp_db = -20.0"),
+            vec![
+                "playback.crossfade_secs must be between 0 and 12 (got 12.5)".to_string(),
+                "playback.replaygain_preamp_db must be between -15 and 15 (got -20)".to_string(),
+            ]
+        );
+        // u8 sınırını aşan değer daha okunurken reddedilir
+        assert!(toml::from_str::<Config>("[playback]\nvolume = 300").is_err());
+    }
+
+    #[test]
+    fn unknown_preset_names_are_reported() {
+        assert_eq!(
+            problems("[equalizer]\npreset = \"loudest\""),
+            vec!["equalizer.preset: unknown preset 'loudest'".to_string()]
+        );
+        // Kullanıcının tanımladığı ayar seçilebilir
+        let text = "[equalizer]\npreset = \"mine\"\n\
+                    [[equalizer.presets]]\nname = \"mine\"\ngains = [0, 0, 0, 0, 0, 0, 0, 0, 0, 0]";
+        assert_eq!(problems(text), Vec::<String>::new());
+    }
+
+    #[test]
+    fn load_reports_every_problem_with_the_path() {
+        let dir = std::env::temp_dir().join(format!("meloic-config-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("config.toml");
+        fs::write(
+            &path,
+            "[ui]\npage_size = 0\n[playback]\nvolume = 120\n[equalizer]\npreset = \"nope\"\n",
+        )
+        .unwrap();
+        let Err(ConfigError::Invalid(at, problems)) = Config::load(Some(&path)) else {
+            panic!("config should be invalid");
+        };
+        assert_eq!(at, path);
+        assert_eq!(problems.len(), 3);
+
+        // Uzantılar küçük harfe çevrilir
+        fs::write(&path, "[library]\nextensions = [\"MP3\", \"Flac\"]\n").unwrap();
+        let config = Config::load(Some(&path)).unwrap();
+        assert_eq!(config.library.extensions, vec!["mp3", "flac"]);
+
+        assert!(matches!(
+            Config::load(Some(&dir.join("missing.toml"))),
+            Err(ConfigError::Io(..))
+        ));
+    }
+}
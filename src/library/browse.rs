@@ -0,0 +1,95 @@
+use std::collections::BTreeMap;
+
+use super::Track;
+
+/// Sanatçısı olmayan parçaların gruplandığı ad
+pub const UNKNOWN_ARTIST: &str = "Unknown Artist";
+/// Albümü olmayan parçaların gruplandığı ad
+pub const UNKNOWN_ALBUM: &str = "Unknown Album";
+
+/// Bir sanatçı ve albümleri
+#[derive(Debug, Clone)]
+pub struct ArtistEntry {
+    pub name: String,
+    pub albums: Vec<AlbumEntry>,
+}
+
+/// Bir albüm ve parçaları
+#[derive(Debug, Clone)]
+pub struct AlbumEntry {
+    pub title: String,
+    pub year: Option<u32>,
+    /// Parça listesindeki sıralar, disk/parça numarası sırasıyla
+    pub tracks: Vec<usize>,
+}
+
+impl ArtistEntry {
+    /// Sanatçının tüm parçaları, albüm ve parça sırasıyla
+    pub fn tracks(&self) -> impl Iterator<Item = usize> + '_ {
+        self.albums
+            .iter()
+            .flat_map(|album| album.tracks.iter().copied())
+    }
+
+    /// Sanatçının parça sayısı
+    pub fn track_count(&self) -> usize {
+        self.albums.iter().map(|album| album.tracks.len()).sum()
+    }
+}
+
+/// Parçaların gruplandığı sanatçı: önce albüm sanatçısı, sonra parça sanatçısı
+pub fn artist_name(track: &Track) -> &str {
+    track
+        .album_artist
+        .as_deref()
+        .or(track.artist.as_deref())
+        .unwrap_or(UNKNOWN_ARTIST)
+}
+
+/// Parçaları sanatçı > albüm > parça ağacına grupla; sanatçılar alfabetik,
+/// albümler yıla göre (yılı olmayanlar sonda), parçalar disk/parça numarasına göre sıralanır
+pub fn group_by_artist(tracks: &[Track]) -> Vec<ArtistEntry> {
+    // Büyük/küçük harf farkı gözetmeden grupla, ilk görülen yazımı göster
+    let mut artists: BTreeMap<String, (String, BTreeMap<String, Vec<usize>>)> = BTreeMap::new();
+    for (index, track) in tracks.iter().enumerate() {
+        let name = artist_name(track);
+        let album = track.album.as_deref().unwrap_or(UNKNOWN_ALBUM);
+        artists
+            .entry(name.to_lowercase())
+            .or_insert_with(|| (name.to_string(), BTreeMap::new()))
+            .1
+            .entry(album.to_string())
+            .or_default()
+            .push(index);
+    }
+
+    artists
+        .into_values()
+        .map(|(name, albums)| {
+            let mut albums: Vec<AlbumEntry> = albums
+                .into_iter()
+                .map(|(title, mut indices)| {
+                    indices.sort_by_key(|&i| {
+                        let track = &tracks[i];
+                        (
+                            track.disc_number.unwrap_or(1),
+                            track.track_number.unwrap_or(u32::MAX),
+                            &track.path,
+                        )
+                    });
+                    // Albüm yılı olarak parçalardaki en eski yıl kullanılır
+                    let year = indices.iter().filter_map(|&i| tracks[i].year).min();
+                    AlbumEntry {
+                        title,
+                        year,
+                        tracks: indices,
+                    }
+                })
+                .collect();
+            albums.sort_by_key(|album| {
+                (album.year.is_none(), album.year, album.title.to_lowercase())
+            });
+            ArtistEntry { name, albums }
+        })
+        .collect()
+}
@@ -0,0 +1,445 @@
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use super::track::{CueRange, Track};
+
+/// CUE zamanlarında saniyedeki kare sayısı (mm:ss:ff)
+const FRAMES_PER_SECOND: u64 = 75;
+
+/// CUE sayfası: tek dosyalık albümleri parçalara bölen dizin
+#[derive(Debug, Clone, Default)]
+pub struct CueSheet {
+    pub title: Option<String>,
+    pub performer: Option<String>,
+    pub genre: Option<String>,
+    pub year: Option<u32>,
+    pub files: Vec<CueFile>,
+}
+
+/// Sayfadaki FILE satırı ve o dosyadaki parçalar
+#[derive(Debug, Clone, Default)]
+pub struct CueFile {
+    pub name: String,
+    pub tracks: Vec<CueTrack>,
+}
+
+/// Sayfadaki TRACK kaydı; başlangıç INDEX 01 satırından alınır
+#[derive(Debug, Clone, Default)]
+pub struct CueTrack {
+    pub number: u32,
+    pub title: Option<String>,
+    pub performer: Option<String>,
+    pub start: Option<Duration>,
+}
+
+impl CueSheet {
+    /// Sayfayı oku (UTF-8 değilse Latin-1 olarak yorumlanır)
+    pub fn read(path: &Path) -> io::Result<CueSheet> {
+        let bytes = fs::read(path)?;
+        let text = match String::from_utf8(bytes) {
+            Ok(text) => text,
+            Err(e) => e.into_bytes().iter().map(|&b| b as char).collect(),
+        };
+        Ok(CueSheet::parse(text.trim_start_matches('\u{feff}')))
+    }
+
+    /// Sayfayı ayrıştır; tanınmayan satırlar atlanır
+    pub fn parse(text: &str) -> CueSheet {
+        let mut sheet = CueSheet::default();
+        for line in text.lines() {
+            let args = arguments(line);
+            let Some((command, args)) = args.split_first() else {
+                continue;
+            };
+            let value = args.first().cloned();
+            let track = sheet
+                .files
+                .last_mut()
+                .and_then(|file| file.tracks.last_mut());
+            match (command.to_uppercase().as_str(), track) {
+                ("FILE", _) => sheet.files.push(CueFile {
+                    name: value.unwrap_or_default(),
+                    tracks: Vec::new(),
+                }),
+                ("TRACK", _) => {
+                    let number = value.and_then(|v| v.parse().ok()).unwrap_or(0);
+                    if let Some(file) = sheet.files.last_mut() {
+                        file.tracks.push(CueTrack {
+                            number,
+                            ..CueTrack::default()
+                        });
+                    }
+                }
+                ("TITLE", Some(track)) => track.title = value,
+                ("PERFORMER", Some(track)) => track.performer = value,
+                ("INDEX", Some(track)) if value.as_deref() == Some("01") => {
+                    track.start = args.get(1).and_then(|time| parse_time(time));
+                }
+                ("TITLE", None) => sheet.title = value,
+                ("PERFORMER", None) => sheet.performer = value,
+                ("REM", None) => match value.as_deref().map(str::to_uppercase).as_deref() {
+                    Some("GENRE") => sheet.genre = args.get(1).cloned(),
+                    Some("DATE") => {
+                        sheet.year = args.get(1).and_then(|date| date.get(..4)?.parse().ok())
+                    }
+                    _ => {}
+                },
+                _ => {}
+            }
+        }
+        sheet
+    }
+
+    /// Ses dosyasına ait FILE kaydı; sayfada tek dosya varsa uzantısı farklı olsa da
+    /// (ör. rip sırasında .wav yazılmış ama dosya .flac) o kabul edilir
+    pub fn file_for(&self, audio: &Path) -> Option<&CueFile> {
+        let name = audio.file_name()?.to_string_lossy().to_lowercase();
+        let base_name = |file: &CueFile| {
+            let name = file.name.rsplit(['/', '\\']).next().unwrap_or_default();
+            name.to_lowercase()
+        };
+        self.files
+            .iter()
+            .find(|file| base_name(file) == name)
+            .or_else(|| {
+                self.files
+                    .iter()
+                    .find(|file| stem(&base_name(file)) == stem(&name))
+            })
+            .or(match self.files.as_slice() {
+                [only] => Some(only),
+                _ => None,
+            })
+    }
+
+    /// Dosyanın parçasını sayfadaki sanal parçalara böl; sayfada bu dosyaya ait
+    /// başlangıcı bilinen parça yoksa `None`
+    pub fn split(&self, track: &Track) -> Option<Vec<Track>> {
+        let file = self.file_for(&track.path)?;
+        let entries: Vec<(&CueTrack, Duration)> = file
+            .tracks
+            .iter()
+            .filter_map(|entry| entry.start.map(|start| (entry, start)))
+            .collect();
+        if entries.is_empty() {
+            return None;
+        }
+
+        let tracks = entries
+            .iter()
+            .enumerate()
+            .map(|(i, &(entry, start))| {
+                let end = entries.get(i + 1).map(|&(_, next)| next);
+                let duration = match end.or(track.duration) {
+                    Some(end) if end > start => Some(end - start),
+                    _ => None,
+                };
+                Track {
+                    path: track.path.clone(),
+                    title: entry
+                        .title
+                        .clone()
+                        .unwrap_or_else(|| format!("Track {:02}", entry.number)),
+                    artist: entry
+                        .performer
+                        .clone()
+                        .or_else(|| self.performer.clone())
+                        .or_else(|| track.artist.clone()),
+                    album: self.title.clone().or_else(|| track.album.clone()),
+                    album_artist: self
+                        .performer
+                        .clone()
+                        .or_else(|| track.album_artist.clone()),
+                    track_number: Some(entry.number),
+                    disc_number: track.disc_number,
+                    year: self.year.or(track.year),
+                    genre: self.genre.clone().or_else(|| track.genre.clone()),
+                    duration,
+                    rating: None,
+                    added: track.added,
+                    cue: Some(CueRange { start, end }),
+                    replay_gain: track.replay_gain,
+                }
+            })
+            .collect();
+        Some(tracks)
+    }
+}
+
+/// Ses dosyasının yanındaki CUE sayfası ("album.cue" veya "album.flac.cue")
+pub fn find_sheet(audio: &Path) -> Option<PathBuf> {
+    let mut with_suffix = audio.as_os_str().to_owned();
+    with_suffix.push(".cue");
+    [audio.with_extension("cue"), PathBuf::from(with_suffix)]
+        .into_iter()
+        .find(|path| path.is_file())
+}
+
+/// Bir tarama boyunca okunan CUE sayfaları; her sayfa bir kez ayrıştırılır
+#[derive(Debug, Default)]
+pub struct SheetCache {
+    /// Okunamayan sayfalar da `None` olarak tutulur
+    sheets: HashMap<PathBuf, Option<CueSheet>>,
+}
+
+impl SheetCache {
+    /// Parçanın yanında CUE sayfası varsa sanal parçalarını, yoksa parçanın kendisini döndür
+    pub fn expand(&mut self, track: Track) -> Vec<Track> {
+        if track.cue.is_some() {
+            return vec![track];
+        }
+        let split = find_sheet(&track.path)
+            .and_then(|path| {
+                self.sheets
+                    .entry(path)
+                    .or_insert_with_key(|path| CueSheet::read(path).ok())
+                    .as_ref()
+            })
+            .and_then(|sheet| sheet.split(&track));
+        split.unwrap_or_else(|| vec![track])
+    }
+}
+
+/// Dosya bir CUE sayfası mı?
+pub fn is_cue_sheet(path: &Path) -> bool {
+    path.extension()
+        .and_then(|ext| ext.to_str())
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("cue"))
+}
+
+/// Uzantısız dosya adı
+fn stem(name: &str) -> &str {
+    name.rsplit_once('.').map_or(name, |(stem, _)| stem)
+}
+
+/// "mm:ss:ff" zamanını çöz
+fn parse_time(time: &str) -> Option<Duration> {
+    let mut parts = time.split(':').map(|part| part.parse::<u64>().ok());
+    let (minutes, seconds, frames) = (parts.next()??, parts.next()??, parts.next()??);
+    if parts.next().is_some() || seconds >= 60 || frames >= FRAMES_PER_SECOND {
+        return None;
+    }
+    let frames = (minutes * 60 + seconds) * FRAMES_PER_SECOND + frames;
+    Some(Duration::from_nanos(
+        frames * 1_000_000_000 / FRAMES_PER_SECOND,
+    ))
+}
+
+/// Satırı boşluklarla ayrılmış, tırnaklı olabilen parçalara böl
+fn arguments(line: &str) -> Vec<String> {
+    let mut args = Vec::new();
+    let mut chars = line.trim().chars().peekable();
+    while let Some(&c) = chars.peek() {
+        if c.is_whitespace() {
+            chars.next();
+        } else if c == '"' {
+            chars.next();
+            let mut arg = String::new();
+            for c in chars.by_ref() {
+                if c == '"' {
+                    break;
+                }
+                arg.push(c);
+            }
+            args.push(arg);
+        } else {
+            let mut arg = String::new();
+            while let Some(&c) = chars.peek() {
+                if c.is_whitespace() {
+                    break;
+                }
+                arg.push(c);
+                chars.next();
+            }
+            args.push(arg);
+        }
+    }
+    args
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ALBUM: &str = r#"REM GENRE Jazz
+REM DATE 1959-08-17
+PERFORMER "Miles Davis"
+TITLE "Kind of Blue"
+FILE "Kind of Blue.wav" WAVE
+  TRACK 01 AUDIO
+    TITLE "So What"
+    INDEX 01 00:00:00
+  TRACK 02 AUDIO
+    TITLE "Freddie Freeloader"
+    PERFORMER "Miles Davis Sextet"
+    INDEX 00 09:20:50
+    INDEX 01 09:22:37
+  track 03 audio
+    index 01 19:08:74
+"#;
+
+    const TWO_DISCS: &str = r#"TITLE "Live"
+FILE "disc1.flac" WAVE
+  TRACK 01 AUDIO
+    INDEX 01 00:00:00
+  TRACK 02 AUDIO
+    INDEX 01 03:00:00
+FILE "disc2.flac" WAVE
+  TRACK 03 AUDIO
+    TITLE "Encore"
+    INDEX 01 00:00:00
+  TRACK 04 AUDIO
+    TITLE "No index"
+"#;
+
+    fn secs(secs: f64) -> Duration {
+        Duration::from_secs_f64(secs)
+    }
+
+    fn audio(path: &str, duration: Option<Duration>) -> Track {
+        let mut track = Track::from_file_name(Path::new(path));
+        track.duration = duration;
+        track
+    }
+
+    #[test]
+    fn index_times_are_minutes_seconds_and_frames() {
+        assert_eq!(parse_time("00:00:00"), Some(Duration::ZERO));
+        assert_eq!(parse_time("01:02:03"), Some(secs(62.04)));
+        assert_eq!(
+            parse_time("09:22:37"),
+            Some(Duration::from_nanos(562_493_333_333))
+        );
+        // Dakika 59'u aşabilir, saniye ve kare aşamaz
+        assert_eq!(
+            parse_time("74:59:74"),
+            Some(Duration::from_nanos(4_499_986_666_666))
+        );
+        for time in [
+            "00:60:00",
+            "00:00:75",
+            "01:02",
+            "01:02:03:04",
+            "aa:00:00",
+            "",
+        ] {
+            assert_eq!(parse_time(time), None, "{}", time);
+        }
+    }
+
+    #[test]
+    fn parse_reads_header_and_tracks() {
+        let sheet = CueSheet::parse(ALBUM);
+        assert_eq!(sheet.title.as_deref(), Some("Kind of Blue"));
+        assert_eq!(sheet.performer.as_deref(), Some("Miles Davis"));
+        assert_eq!(sheet.genre.as_deref(), Some("Jazz"));
+        assert_eq!(sheet.year, Some(1959));
+        assert_eq!(sheet.files.len(), 1);
+        assert_eq!(sheet.files[0].name, "Kind of Blue.wav");
+
+        let tracks = &sheet.files[0].tracks;
+        assert_eq!(tracks.len(), 3);
+        assert_eq!(tracks[0].title.as_deref(), Some("So What"));
+        assert_eq!(tracks[0].performer, None);
+        assert_eq!(tracks[1].performer.as_deref(), Some("Miles Davis Sextet"));
+        // Başlangıç INDEX 00 değil INDEX 01 satırından alınır
+        assert_eq!(tracks[1].start, parse_time("09:22:37"));
+        // Komutlar büyük/küçük harf duyarsızdır
+        assert_eq!(tracks[2].number, 3);
+        assert_eq!(tracks[2].start, parse_time("19:08:74"));
+    }
+
+    #[test]
+    fn split_ends_last_track_at_file_duration() {
+        let sheet = CueSheet::parse(ALBUM);
+        // Uzantısı farklı olsa da sayfadaki tek dosya kabul edilir
+        let file = audio("/music/Kind of Blue.flac", Some(secs(1500.0)));
+        let tracks = sheet.split(&file).unwrap();
+        assert_eq!(tracks.len(), 3);
+
+        let starts = [
+            Duration::ZERO,
+            parse_time("09:22:37").unwrap(),
+            parse_time("19:08:74").unwrap(),
+        ];
+        for (i, track) in tracks.iter().enumerate() {
+            assert_eq!(track.path, file.path);
+            assert_eq!(track.track_number, Some(i as u32 + 1));
+            assert_eq!(track.album.as_deref(), Some("Kind of Blue"));
+            assert_eq!(track.year, Some(1959));
+            let range = track.cue.unwrap();
+            assert_eq!(range.start, starts[i]);
+            assert_eq!(range.end, starts.get(i + 1).copied());
+        }
+        assert_eq!(tracks[0].duration, Some(starts[1]));
+        assert_eq!(tracks[1].artist.as_deref(), Some("Miles Davis Sextet"));
+        assert_eq!(tracks[2].artist.as_deref(), Some("Miles Davis"));
+        assert_eq!(tracks[2].title, "Track 03");
+        // Son parça dosyanın sonunda biter
+        assert_eq!(tracks[2].duration, Some(secs(1500.0) - starts[2]));
+
+        // Dosya süresi bilinmiyorsa son parçanın süresi de bilinmez
+        let tracks = sheet
+            .split(&audio("/music/Kind of Blue.flac", None))
+            .unwrap();
+        assert_eq!(tracks[2].duration, None);
+        assert_eq!(tracks[2].cue.unwrap().end, None);
+    }
+
+    #[test]
+    fn split_uses_only_the_matching_file_of_multi_file_sheets() {
+        let sheet = CueSheet::parse(TWO_DISCS);
+        assert_eq!(sheet.files.len(), 2);
+
+        let disc1 = sheet
+            .split(&audio("/live/disc1.flac", Some(secs(400.0))))
+            .unwrap();
+        assert_eq!(disc1.len(), 2);
+        assert_eq!(disc1[1].duration, Some(secs(220.0)));
+
+        // INDEX 01 olmayan parça atlanır
+        let disc2 = sheet
+            .split(&audio("/live/DISC2.wav", Some(secs(300.0))))
+            .unwrap();
+        assert_eq!(disc2.len(), 1);
+        assert_eq!(disc2[0].title, "Encore");
+        assert_eq!(disc2[0].track_number, Some(3));
+        assert_eq!(disc2[0].duration, Some(secs(300.0)));
+
+        // Birden fazla dosyalı sayfada adı tutmayan dosya bölünmez
+        assert!(sheet.split(&audio("/live/bonus.flac", None)).is_none());
+    }
+
+    #[test]
+    fn expand_reads_each_sheet_once() {
+        let dir = std::env::temp_dir().join(format!("meloic-cue-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let flac = dir.join("Kind of Blue.flac");
+        let sheet = dir.join("Kind of Blue.cue");
+        fs::write(&flac, b"").unwrap();
+        fs::write(&sheet, ALBUM).unwrap();
+
+        let mut sheets = SheetCache::default();
+        let track = audio(flac.to_str().unwrap(), Some(secs(1500.0)));
+        let expanded = sheets.expand(track.clone());
+        assert_eq!(expanded.len(), 3);
+
+        // Sanal parçalar yeniden bölünmez
+        assert_eq!(
+            sheets.expand(expanded[1].clone()),
+            vec![expanded[1].clone()]
+        );
+
+        // Sayfa değişse de aynı taramada önbellekteki kopyası kullanılır
+        fs::write(&sheet, "FILE \"Kind of Blue.flac\" WAVE\n").unwrap();
+        assert_eq!(sheets.expand(track.clone()), expanded);
+        assert_eq!(SheetCache::default().expand(track.clone()), vec![track]);
+
+        // Sayfası olmayan parça olduğu gibi döner
+        let single = audio(dir.join("single.flac").to_str().unwrap(), None);
+        assert_eq!(sheets.expand(single.clone()), vec![single]);
+    }
+}
@@ -0,0 +1,159 @@
+use fuzzy_matcher::FuzzyMatcher;
+use fuzzy_matcher::skim::SkimMatcherV2;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::mpsc::{self, Receiver, Sender, TryRecvError};
+use std::thread;
+
+use super::Track;
+
+/// Eşleştirme sırasında yeni sorgu gelip gelmediğine bakma aralığı (aday sayısı)
+const CANCEL_CHECK_INTERVAL: usize = 1024;
+
+/// Bir adayın sorguyla eşleşmesi
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FuzzyMatch {
+    /// Aday listesindeki sıra
+    pub index: usize,
+    pub score: i64,
+}
+
+/// Tamamlanan bir aramanın sonucu; eşleşmeler puana göre sıralıdır
+#[derive(Debug)]
+pub struct FinderResult {
+    pub matches: Vec<FuzzyMatch>,
+    generation: u64,
+}
+
+enum Request {
+    Candidates(Arc<Vec<String>>),
+    Query(u64, String),
+}
+
+/// Parçanın bulanık aramada eşleştirilen metni: "Sanatçı - Başlık Albüm"
+pub fn candidate_text(track: &Track) -> String {
+    match &track.album {
+        Some(album) => format!("{} {}", track.display_name(), album),
+        None => track.display_name(),
+    }
+}
+
+/// fzf tarzı bulanık arama; eşleştirme arka plandaki bir thread'de yapılır
+pub struct Finder {
+    tx: Sender<Request>,
+    rx: Receiver<FinderResult>,
+    /// Son gönderilen sorgunun numarası; eski sonuçlar atılır, eski aramalar yarıda kesilir
+    generation: Arc<AtomicU64>,
+    matcher: SkimMatcherV2,
+}
+
+impl Finder {
+    /// Eşleştirme thread'ini başlat
+    pub fn new() -> Finder {
+        let (tx, request_rx) = mpsc::channel();
+        let (result_tx, rx) = mpsc::channel();
+        let generation = Arc::new(AtomicU64::new(0));
+        let latest = Arc::clone(&generation);
+        thread::spawn(move || run_worker(request_rx, result_tx, latest));
+        Finder {
+            tx,
+            rx,
+            generation,
+            matcher: SkimMatcherV2::default(),
+        }
+    }
+
+    /// Aranacak metinleri değiştir (kütüphane listesiyle aynı sırada)
+    pub fn set_candidates(&self, candidates: Vec<String>) {
+        let _ = self.tx.send(Request::Candidates(Arc::new(candidates)));
+    }
+
+    /// Yeni arama başlat; önceki arama bitmemişse bırakılır
+    pub fn search(&self, query: &str) {
+        let generation = self.generation.fetch_add(1, Ordering::Relaxed) + 1;
+        let _ = self.tx.send(Request::Query(generation, query.to_string()));
+    }
+
+    /// Son aramanın sonucu geldiyse döndür
+    pub fn poll(&self) -> Option<FinderResult> {
+        let current = self.generation.load(Ordering::Relaxed);
+        let mut latest = None;
+        loop {
+            match self.rx.try_recv() {
+                Ok(result) if result.generation == current => latest = Some(result),
+                Ok(_) => {}
+                Err(_) => return latest,
+            }
+        }
+    }
+
+    /// Vurgulanacak karakterlerin sırası (bayt değil karakter sırası)
+    pub fn positions(&self, text: &str, query: &str) -> Vec<usize> {
+        self.matcher
+            .fuzzy_indices(text, query)
+            .map(|(_, positions)| positions)
+            .unwrap_or_default()
+    }
+}
+
+/// Eşleştirme thread'i: biriken isteklerden yalnızca sonuncusunu işler
+fn run_worker(rx: Receiver<Request>, tx: Sender<FinderResult>, latest: Arc<AtomicU64>) {
+    let matcher = SkimMatcherV2::default();
+    let mut candidates: Arc<Vec<String>> = Arc::new(Vec::new());
+    // Önceki sorgu ve eşleşen adaylar; sorgu uzadıkça yalnızca bunlar taranır
+    let mut previous: Option<(String, Vec<usize>)> = None;
+
+    while let Ok(mut request) = rx.recv() {
+        let mut query = None;
+        loop {
+            match request {
+                Request::Candidates(list) => {
+                    candidates = list;
+                    previous = None;
+                }
+                Request::Query(generation, text) => query = Some((generation, text)),
+            }
+            match rx.try_recv() {
+                Ok(next) => request = next,
+                Err(TryRecvError::Empty) => break,
+                Err(TryRecvError::Disconnected) => return,
+            }
+        }
+        let Some((generation, query)) = query else {
+            continue;
+        };
+
+        let pool: Vec<usize> = match &previous {
+            Some((prev, indices)) if !prev.is_empty() && query.starts_with(prev.as_str()) => {
+                indices.clone()
+            }
+            _ => (0..candidates.len()).collect(),
+        };
+
+        let mut matches = Vec::new();
+        let mut cancelled = false;
+        for (n, &index) in pool.iter().enumerate() {
+            if n % CANCEL_CHECK_INTERVAL == 0 && latest.load(Ordering::Relaxed) != generation {
+                cancelled = true;
+                break;
+            }
+            if let Some(score) = matcher.fuzzy_match(&candidates[index], &query) {
+                matches.push(FuzzyMatch { index, score });
+            }
+        }
+        if cancelled {
+            continue;
+        }
+
+        previous = Some((query.clone(), matches.iter().map(|m| m.index).collect()));
+        // Yüksek puan önce; eşit puanda kütüphane sırası korunur
+        matches.sort_by(|a, b| b.score.cmp(&a.score).then(a.index.cmp(&b.index)));
+        let result = FinderResult {
+            matches,
+            generation,
+        };
+        if tx.send(result).is_err() {
+            return;
+        }
+    }
+}
@@ -0,0 +1,212 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use crate::library::index::unix_now;
+use crate::library::track::TrackId;
+
+/// Parçanın çalınma bilgisi
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+pub struct PlayRecord {
+    pub count: u32,
+    /// Son çalınma zamanı (Unix saniyesi)
+    pub last: u64,
+}
+
+/// Parçaların kaç kez çalındığı (~/.local/share/meloic/history.toml); CUE sayfasındaki
+/// parçalar aynı dosyada olsalar da ayrı sayılır
+#[derive(Debug, Clone, Default)]
+pub struct PlayHistory {
+    pub plays: HashMap<TrackId, PlayRecord>,
+}
+
+/// Geçmiş dosyasının biçimi
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(default)]
+struct HistoryFile {
+    tracks: Vec<TrackPlays>,
+    /// Eski biçim: yalnızca dosya yoluna göre sayılır. Okunurken sanal olmayan parçaların
+    /// kaydına çevrilir; CUE parçalarının sayısı hangi parçaya ait olduğu bilinmediğinden
+    /// dosyanın kendisinde kalır
+    #[serde(skip_serializing)]
+    plays: HashMap<PathBuf, PlayRecord>,
+}
+
+/// Dosyadaki tek parçanın kaydı
+#[derive(Debug, Serialize, Deserialize)]
+struct TrackPlays {
+    path: PathBuf,
+    /// CUE parçasının dosyadaki başlangıcı (nanosaniye)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    start_ns: Option<u64>,
+    #[serde(flatten)]
+    record: PlayRecord,
+}
+
+impl From<HistoryFile> for PlayHistory {
+    fn from(file: HistoryFile) -> PlayHistory {
+        let mut plays: HashMap<TrackId, PlayRecord> = file
+            .plays
+            .into_iter()
+            .map(|(path, record)| ((path, None), record))
+            .collect();
+        for entry in file.tracks {
+            let start = entry.start_ns.map(Duration::from_nanos);
+            plays.insert((entry.path, start), entry.record);
+        }
+        PlayHistory { plays }
+    }
+}
+
+impl PlayHistory {
+    /// Varsayılan geçmiş dosyasının yolu
+    pub fn default_path() -> Option<PathBuf> {
+        dirs::data_dir().map(|dir| dir.join("meloic").join("history.toml"))
+    }
+
+    /// Geçmişi yükle; dosya yoksa veya okunamıyorsa boş geçmiş döndür
+    pub fn load() -> PlayHistory {
+        Self::default_path()
+            .map(|path| Self::load_from(&path))
+            .unwrap_or_default()
+    }
+
+    /// Geçmişi verilen dosyadan yükle; eski biçimdeki kayıtlar çevrilir
+    fn load_from(path: &Path) -> PlayHistory {
+        fs::read_to_string(path)
+            .ok()
+            .and_then(|text| toml::from_str::<HistoryFile>(&text).ok())
+            .map(PlayHistory::from)
+            .unwrap_or_default()
+    }
+
+    /// Geçmişi varsayılan dosyaya yaz
+    pub fn save(&self) -> io::Result<()> {
+        match Self::default_path() {
+            Some(path) => self.save_to(&path),
+            None => Ok(()),
+        }
+    }
+
+    /// Geçmişi diske yaz (önce geçici dosyaya, sonra yerine taşı)
+    fn save_to(&self, path: &Path) -> io::Result<()> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let mut tracks: Vec<TrackPlays> = self
+            .plays
+            .iter()
+            .map(|((path, start), record)| TrackPlays {
+                path: path.clone(),
+                start_ns: start.map(|start| start.as_nanos() as u64),
+                record: *record,
+            })
+            .collect();
+        tracks.sort_by(|a, b| (&a.path, a.start_ns).cmp(&(&b.path, b.start_ns)));
+        let file = HistoryFile {
+            tracks,
+            plays: HashMap::new(),
+        };
+        let text = toml::to_string(&file).map_err(io::Error::other)?;
+        let tmp_path = path.with_extension("toml.tmp");
+        fs::write(&tmp_path, text)?;
+        fs::rename(tmp_path, path)
+    }
+
+    /// Parçanın çalındığını kaydet
+    pub fn record(&mut self, id: TrackId) {
+        let record = self.plays.entry(id).or_default();
+        record.count += 1;
+        record.last = unix_now();
+    }
+
+    /// Parça kaç kez çalındı?
+    pub fn count(&self, id: &TrackId) -> u32 {
+        self.plays.get(id).map_or(0, |record| record.count)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_path(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("meloic-history-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        dir.join(name)
+    }
+
+    /// Aynı dosyadaki iki CUE parçası ve ayrı bir dosya
+    fn ids() -> [TrackId; 3] {
+        [
+            ("/music/album.flac".into(), Some(Duration::ZERO)),
+            (
+                "/music/album.flac".into(),
+                Some(Duration::from_nanos(562_493_333_333)),
+            ),
+            ("/music/single.mp3".into(), None),
+        ]
+    }
+
+    #[test]
+    fn cue_tracks_are_counted_separately() {
+        let [first, second, single] = ids();
+        let mut history = PlayHistory::default();
+        history.record(second.clone());
+        history.record(second.clone());
+        history.record(single.clone());
+
+        assert_eq!(history.count(&first), 0);
+        assert_eq!(history.count(&second), 2);
+        assert_eq!(history.count(&single), 1);
+        // Dosyanın kendisi ayrı bir parçadır
+        assert_eq!(history.count(&(first.0, None)), 0);
+    }
+
+    #[test]
+    fn save_and_load_round_trip() {
+        let [first, second, single] = ids();
+        let mut history = PlayHistory::default();
+        history.record(first.clone());
+        history.record(second.clone());
+        history.record(second.clone());
+        history.record(single.clone());
+
+        let path = temp_path("round-trip.toml");
+        history.save_to(&path).unwrap();
+        let loaded = PlayHistory::load_from(&path);
+        assert_eq!(loaded.plays.len(), 3);
+        for id in [&first, &second, &single] {
+            assert_eq!(loaded.count(id), history.count(id));
+            assert_eq!(loaded.plays[id].last, history.plays[id].last);
+        }
+    }
+
+    #[test]
+    fn old_path_keyed_history_is_migrated() {
+        let path = temp_path("old.toml");
+        fs::write(
+            &path,
+            "[plays.\"/music/single.mp3\"]\ncount = 3\nlast = 1700000000\n\n\
+             [plays.\"/music/album.flac\"]\ncount = 5\nlast = 1700000100\n",
+        )
+        .unwrap();
+        let history = PlayHistory::load_from(&path);
+        let [first, _, single] = ids();
+        assert_eq!(history.count(&single), 3);
+        assert_eq!(history.plays[&single].last, 1_700_000_000);
+        // Dosyanın sayısı CUE parçalarına dağıtılmaz
+        assert_eq!(history.count(&first), 0);
+        assert_eq!(history.count(&(first.0, None)), 5);
+
+        // Yeniden kaydedilince yeni biçimde yazılır
+        history.save_to(&path).unwrap();
+        let text = fs::read_to_string(&path).unwrap();
+        assert!(text.contains("[[tracks]]"));
+        assert!(!text.contains("[plays"));
+        assert_eq!(PlayHistory::load_from(&path).count(&single), 3);
+    }
+}
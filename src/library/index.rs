@@ -0,0 +1,314 @@
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::fs::{self, File};
+use std::io::{self, BufReader, BufWriter, Read, Write};
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::library::Track;
+use crate::library::track::ReplayGain;
+
+/// Dosya başlığı ve format sürümü; format değişirse sürüm artırılmalı
+const INDEX_MAGIC: &[u8; 4] = b"MLIX";
+const INDEX_VERSION: u32 = 4;
+
+/// İndekste saklanan tek bir dosya kaydı
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IndexEntry {
+    pub mtime: u64,
+    pub size: u64,
+    pub track: Track,
+}
+
+/// Diskte saklanan kütüphane indeksi
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct LibraryIndex {
+    pub entries: HashMap<PathBuf, IndexEntry>,
+}
+
+/// Şu anki zaman (Unix saniyesi)
+pub fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+/// Dosyanın değişip değişmediğini anlamak için kullanılan bilgiler
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FileStamp {
+    pub mtime: u64,
+    pub size: u64,
+}
+
+impl FileStamp {
+    /// Dosyanın boyutunu ve değiştirilme zamanını oku
+    pub fn read(path: &Path) -> io::Result<FileStamp> {
+        let metadata = fs::metadata(path)?;
+        let mtime = metadata
+            .modified()?
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_nanos() as u64)
+            .unwrap_or(0);
+        Ok(FileStamp {
+            mtime,
+            size: metadata.len(),
+        })
+    }
+}
+
+impl LibraryIndex {
+    /// Varsayılan indeks dosyasının yolu (~/.local/share/meloic/library.idx)
+    pub fn default_path() -> Option<PathBuf> {
+        dirs::data_dir().map(|dir| dir.join("meloic").join("library.idx"))
+    }
+
+    /// İndeksi diskten yükle; dosya yoksa, bozuksa veya sürümü eskiyse boş indeks döndür
+    pub fn load(path: &Path) -> LibraryIndex {
+        Self::try_load(path).unwrap_or_default()
+    }
+
+    fn try_load(path: &Path) -> Option<LibraryIndex> {
+        let mut reader = BufReader::new(File::open(path).ok()?);
+
+        let mut magic = [0u8; 4];
+        reader.read_exact(&mut magic).ok()?;
+        let mut version = [0u8; 4];
+        reader.read_exact(&mut version).ok()?;
+        if &magic != INDEX_MAGIC || u32::from_le_bytes(version) != INDEX_VERSION {
+            return None;
+        }
+
+        bincode::deserialize_from(reader).ok()
+    }
+
+    /// İndeksi diske yaz (önce geçici dosyaya, sonra yerine taşı)
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        let tmp_path = path.with_extension("idx.tmp");
+        {
+            let mut writer = BufWriter::new(File::create(&tmp_path)?);
+            writer.write_all(INDEX_MAGIC)?;
+            writer.write_all(&INDEX_VERSION.to_le_bytes())?;
+            bincode::serialize_into(&mut writer, self).map_err(io::Error::other)?;
+            writer.flush()?;
+        }
+        fs::rename(tmp_path, path)
+    }
+
+    /// Dosya değişmediyse indeksteki parçayı döndür
+    pub fn lookup(&self, path: &Path, stamp: FileStamp) -> Option<&Track> {
+        self.entries
+            .get(path)
+            .filter(|entry| entry.mtime == stamp.mtime && entry.size == stamp.size)
+            .map(|entry| &entry.track)
+    }
+
+    /// Kaydı ekle veya güncelle ve kaydedilen parçayı döndür; dosya değişse de eklenme zamanı korunur,
+    /// ilk kez görülen dosyalarda değiştirilme zamanı kullanılır (ilk taramada tüm kütüphane yeni sayılmasın)
+    pub fn insert(&mut self, stamp: FileStamp, mut track: Track) -> &Track {
+        let previous = self
+            .entries
+            .get(&track.path)
+            .and_then(|entry| entry.track.added);
+        track.added = previous.or_else(|| Some((stamp.mtime / 1_000_000_000).min(unix_now())));
+        let path = track.path.clone();
+        self.entries.insert(
+            track.path.clone(),
+            IndexEntry {
+                mtime: stamp.mtime,
+                size: stamp.size,
+                track,
+            },
+        );
+        &self.entries[&path].track
+    }
+
+    /// Ölçülen ses yüksekliği değerlerini kayda yaz; etiketler de yazılmış olabileceğinden
+    /// dosya bilgisi yenilenir. Dosya indekste yoksa `false`
+    pub fn set_replay_gain(&mut self, path: &Path, replay_gain: ReplayGain) -> bool {
+        let Some(entry) = self.entries.get_mut(path) else {
+            return false;
+        };
+        entry.track.replay_gain = replay_gain;
+        if let Ok(stamp) = FileStamp::read(path) {
+            entry.mtime = stamp.mtime;
+            entry.size = stamp.size;
+        }
+        true
+    }
+
+    /// Kök klasör altında olup artık diskte bulunmayan kayıtları sil; taramada okunamayan
+    /// klasör ve dosyaların altındaki kayıtlar korunur (ör. ağ diski geçici olarak koptuysa)
+    pub fn prune(&mut self, root: &Path, seen: &HashSet<PathBuf>, unreadable: &[PathBuf]) -> usize {
+        let before = self.entries.len();
+        self.entries.retain(|path, _| {
+            !path.starts_with(root)
+                || seen.contains(path)
+                || unreadable.iter().any(|failed| path.starts_with(failed))
+        });
+        before - self.entries.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Teste özel geçici dosya yolu
+    fn temp_path(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("meloic-index-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        dir.join(name)
+    }
+
+    fn track(path: &str) -> Track {
+        let mut track = Track::from_file_name(Path::new(path));
+        track.artist = Some("Artist".to_string());
+        track.duration = Some(std::time::Duration::from_millis(183_250));
+        track
+    }
+
+    const STAMP: FileStamp = FileStamp {
+        mtime: 1_700_000_000_000_000_000,
+        size: 4096,
+    };
+
+    #[test]
+    fn save_and_load_round_trip() {
+        let path = temp_path("round-trip.idx");
+        let mut index = LibraryIndex::default();
+        index.insert(STAMP, track("/music/a.flac"));
+        index.insert(STAMP, track("/music/b.mp3"));
+        index.save(&path).unwrap();
+
+        let loaded = LibraryIndex::load(&path);
+        assert_eq!(loaded.entries.len(), 2);
+        for (path, entry) in &index.entries {
+            let other = &loaded.entries[path];
+            assert_eq!(other.track, entry.track);
+            assert_eq!((other.mtime, other.size), (entry.mtime, entry.size));
+        }
+        assert!(!path.with_extension("idx.tmp").exists());
+    }
+
+    #[test]
+    fn rejects_wrong_magic_or_version() {
+        let mut index = LibraryIndex::default();
+        index.insert(STAMP, track("/music/a.flac"));
+        let path = temp_path("header.idx");
+        index.save(&path).unwrap();
+        let bytes = fs::read(&path).unwrap();
+
+        let mut wrong_magic = bytes.clone();
+        wrong_magic[..4].copy_from_slice(b"XXXX");
+        fs::write(&path, &wrong_magic).unwrap();
+        assert!(LibraryIndex::load(&path).entries.is_empty());
+
+        let mut wrong_version = bytes.clone();
+        wrong_version[4..8].copy_from_slice(&(INDEX_VERSION + 1).to_le_bytes());
+        fs::write(&path, &wrong_version).unwrap();
+        assert!(LibraryIndex::load(&path).entries.is_empty());
+
+        fs::write(&path, &bytes[..bytes.len() / 2]).unwrap();
+        assert!(LibraryIndex::load(&path).entries.is_empty());
+
+        assert!(
+            LibraryIndex::load(&temp_path("missing.idx"))
+                .entries
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn lookup_requires_matching_stamp() {
+        let mut index = LibraryIndex::default();
+        index.insert(STAMP, track("/music/a.flac"));
+        let path = Path::new("/music/a.flac");
+
+        assert!(index.lookup(path, STAMP).is_some());
+        let touched = FileStamp {
+            mtime: STAMP.mtime + 1,
+            ..STAMP
+        };
+        assert!(index.lookup(path, touched).is_none());
+        let resized = FileStamp {
+            size: STAMP.size + 1,
+            ..STAMP
+        };
+        assert!(index.lookup(path, resized).is_none());
+        assert!(
+            index
+                .lookup(Path::new("/music/other.flac"), STAMP)
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn insert_keeps_added_time() {
+        let mut index = LibraryIndex::default();
+        let added = index.insert(STAMP, track("/music/a.flac")).added;
+        assert_eq!(added, Some(1_700_000_000));
+
+        let changed = FileStamp {
+            mtime: STAMP.mtime + 5_000_000_000,
+            size: 10,
+        };
+        assert_eq!(index.insert(changed, track("/music/a.flac")).added, added);
+    }
+
+    #[test]
+    fn prune_removes_only_unseen_paths_under_root() {
+        let mut index = LibraryIndex::default();
+        for path in ["/music/a.flac", "/music/b.flac", "/other/c.flac"] {
+            index.insert(STAMP, track(path));
+        }
+        let seen = HashSet::from([PathBuf::from("/music/a.flac")]);
+
+        assert_eq!(index.prune(Path::new("/music"), &seen, &[]), 1);
+        assert!(index.entries.contains_key(Path::new("/music/a.flac")));
+        assert!(!index.entries.contains_key(Path::new("/music/b.flac")));
+        assert!(index.entries.contains_key(Path::new("/other/c.flac")));
+    }
+
+    #[test]
+    fn prune_keeps_entries_under_unreadable_paths() {
+        let mut index = LibraryIndex::default();
+        for path in [
+            "/music/nas/a.flac",
+            "/music/nas/sub/b.flac",
+            "/music/nasty.flac",
+            "/music/c.flac",
+            "/music/d.flac",
+        ] {
+            index.insert(STAMP, track(path));
+        }
+        // "nas" klasörü ve "c.flac" okunamadı; hiçbiri görülmedi
+        let unreadable = [PathBuf::from("/music/nas"), PathBuf::from("/music/c.flac")];
+
+        assert_eq!(
+            index.prune(Path::new("/music"), &HashSet::new(), &unreadable),
+            2
+        );
+        assert!(index.entries.contains_key(Path::new("/music/nas/a.flac")));
+        assert!(
+            index
+                .entries
+                .contains_key(Path::new("/music/nas/sub/b.flac"))
+        );
+        assert!(index.entries.contains_key(Path::new("/music/c.flac")));
+        // Yol bileşeni olarak eşleşir, ad öneki olarak değil
+        assert!(!index.entries.contains_key(Path::new("/music/nasty.flac")));
+        assert!(!index.entries.contains_key(Path::new("/music/d.flac")));
+
+        // Kökün kendisi okunamadıysa hiçbir şey silinmez
+        let unreadable = [PathBuf::from("/music")];
+        assert_eq!(
+            index.prune(Path::new("/music"), &HashSet::new(), &unreadable),
+            0
+        );
+    }
+}
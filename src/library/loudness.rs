@@ -0,0 +1,255 @@
+use ebur128::{EbuR128, Mode};
+use lofty::config::WriteOptions;
+use lofty::prelude::*;
+use lofty::tag::Tag;
+use rodio::{Decoder, Source};
+use std::collections::{HashMap, HashSet};
+use std::fs::File;
+use std::io::BufReader;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc::{self, Receiver};
+use std::thread;
+
+use super::track::{ReplayGain, Track};
+
+/// ReplayGain 2.0'ın hedeflediği ses yüksekliği (LUFS)
+const REFERENCE_LUFS: f64 = -18.0;
+/// Ölçüme tek seferde verilen kare sayısı
+const CHUNK_FRAMES: usize = 4096;
+
+/// Bir dosyanın ölçüm sonucu
+#[derive(Debug, Clone)]
+pub struct Measurement {
+    pub path: PathBuf,
+    pub result: Result<ReplayGain, String>,
+}
+
+/// Arka plandaki ölçümden UI'a gönderilen mesajlar
+pub enum LoudnessEvent {
+    /// Ölçümü biten dosya sayısı
+    Progress(usize),
+    Finished(Vec<Measurement>),
+}
+
+/// Tek dosyanın ölçümü; albüm değeri için ölçer saklanır
+struct FileLoudness {
+    meter: EbuR128,
+    /// Bütünleşik ses yüksekliği (LUFS)
+    loudness: f64,
+    /// Kanalların en yüksek gerçek tepesi (doğrusal)
+    peak: f64,
+}
+
+/// Parçaların dosyaları, her dosya bir kez (CUE sayfasındaki parçalar aynı dosyayı paylaşır)
+pub fn files(tracks: &[Track]) -> Vec<Track> {
+    let mut seen = HashSet::new();
+    tracks
+        .iter()
+        .filter(|track| seen.insert(track.path.clone()))
+        .cloned()
+        .collect()
+}
+
+/// Ölçülmesi gereken dosyalar: kazancı eksik bir parçası olan albümlerin tüm dosyaları
+/// (albüm değeri albümün tamamından hesaplanır); albümü olmayan parçalar tek başına alınır
+pub fn pending(tracks: &[Track]) -> Vec<Track> {
+    let missing = |track: &Track| {
+        let gain = &track.replay_gain;
+        gain.track_gain.is_none() || (track.album.is_some() && gain.album_gain.is_none())
+    };
+    let albums: HashSet<(PathBuf, String)> = tracks
+        .iter()
+        .filter(|track| missing(track))
+        .filter_map(album_key)
+        .collect();
+    let pending: Vec<Track> = tracks
+        .iter()
+        .filter(|track| match album_key(track) {
+            Some(album) => albums.contains(&album),
+            None => missing(track),
+        })
+        .cloned()
+        .collect();
+    files(&pending)
+}
+
+/// Albüm: klasör ve küçük harfli albüm adı
+fn album_key(track: &Track) -> Option<(PathBuf, String)> {
+    let album = track.album.as_ref()?;
+    let dir = track.path.parent().unwrap_or(Path::new("")).to_path_buf();
+    Some((dir, album.to_lowercase()))
+}
+
+/// Dosyaları paralel olarak çöz, EBU R128 ses yüksekliğini ve gerçek tepeyi ölç;
+/// her dosya bitince `on_file` biten dosya sayısı, yol ve ses yüksekliği (LUFS) ile çağrılır
+pub fn analyze<F>(files: &[Track], on_file: F) -> Vec<Measurement>
+where
+    F: Fn(usize, &Path, Result<f64, &str>) + Sync,
+{
+    let next = AtomicUsize::new(0);
+    let done = AtomicUsize::new(0);
+    let slots: Mutex<Vec<Option<Result<FileLoudness, String>>>> =
+        Mutex::new((0..files.len()).map(|_| None).collect());
+    let workers = thread::available_parallelism()
+        .map_or(1, |n| n.get())
+        .min(files.len());
+
+    thread::scope(|scope| {
+        for _ in 0..workers {
+            scope.spawn(|| {
+                loop {
+                    let i = next.fetch_add(1, Ordering::Relaxed);
+                    let Some(file) = files.get(i) else {
+                        break;
+                    };
+                    let result = measure(&file.path);
+                    let done = done.fetch_add(1, Ordering::Relaxed) + 1;
+                    on_file(
+                        done,
+                        &file.path,
+                        result.as_ref().map(|m| m.loudness).map_err(String::as_str),
+                    );
+                    slots.lock().unwrap()[i] = Some(result);
+                }
+            });
+        }
+    });
+    let measured: Vec<Result<FileLoudness, String>> = slots
+        .into_inner()
+        .unwrap()
+        .into_iter()
+        .map(|slot| slot.unwrap_or_else(|| Err("not measured".to_string())))
+        .collect();
+
+    // Albüm değeri albümdeki tüm dosyaların blokları birlikte ölçülerek bulunur
+    let mut albums: HashMap<(PathBuf, String), Vec<usize>> = HashMap::new();
+    for (i, file) in files.iter().enumerate() {
+        if let (Some(album), Ok(_)) = (album_key(file), &measured[i]) {
+            albums.entry(album).or_default().push(i);
+        }
+    }
+    let mut album_values = vec![None; files.len()];
+    for indices in albums.values() {
+        let members = || indices.iter().filter_map(|&i| measured[i].as_ref().ok());
+        let loudness = EbuR128::loudness_global_multiple(members().map(|m| &m.meter));
+        let peak = members().map(|m| m.peak).fold(0.0, f64::max);
+        if let Ok(loudness) = loudness
+            && loudness.is_finite()
+        {
+            for &i in indices {
+                album_values[i] = Some((gain(loudness), peak as f32));
+            }
+        }
+    }
+
+    files
+        .iter()
+        .zip(measured)
+        .zip(album_values)
+        .map(|((file, measured), album)| Measurement {
+            path: file.path.clone(),
+            result: measured.map(|m| ReplayGain {
+                track_gain: Some(gain(m.loudness)),
+                track_peak: Some(m.peak as f32),
+                album_gain: album.map(|(gain, _)| gain),
+                album_peak: album.map(|(_, peak)| peak),
+            }),
+        })
+        .collect()
+}
+
+/// Ölçümü ayrı bir thread'de başlat; ilerleme ve sonuçlar kanal üzerinden gönderilir
+pub fn spawn_analyze(files: Vec<Track>) -> Receiver<LoudnessEvent> {
+    let (tx, rx) = mpsc::channel();
+    thread::spawn(move || {
+        let measurements = analyze(&files, |done, _, _| {
+            // UI kapandıysa gönderim başarısız olur, sorun değil
+            let _ = tx.send(LoudnessEvent::Progress(done));
+        });
+        let _ = tx.send(LoudnessEvent::Finished(measurements));
+    });
+    rx
+}
+
+/// Dosyayı baştan sona çözerek ölç
+fn measure(path: &Path) -> Result<FileLoudness, String> {
+    let file = File::open(path).map_err(|e| e.to_string())?;
+    let decoder = Decoder::new(BufReader::new(file)).map_err(|e| e.to_string())?;
+    let channels = decoder.channels().max(1) as usize;
+    let mut meter = EbuR128::new(
+        channels as u32,
+        decoder.sample_rate(),
+        // Histogram modu uzun dosyalarda da belleği sabit tutar
+        Mode::I | Mode::TRUE_PEAK | Mode::HISTOGRAM,
+    )
+    .map_err(|_| "unsupported sample rate or channel count".to_string())?;
+
+    let chunk = CHUNK_FRAMES * channels;
+    let mut buffer = Vec::with_capacity(chunk);
+    for sample in decoder {
+        buffer.push(sample);
+        if buffer.len() == chunk {
+            meter.add_frames_i16(&buffer).map_err(|e| e.to_string())?;
+            buffer.clear();
+        }
+    }
+    // Yarım kalan son kare atlanır
+    buffer.truncate(buffer.len() - buffer.len() % channels);
+    meter.add_frames_i16(&buffer).map_err(|e| e.to_string())?;
+
+    let loudness = meter.loudness_global().map_err(|e| e.to_string())?;
+    if !loudness.is_finite() {
+        return Err("no audible signal".to_string());
+    }
+    let peak = (0..channels as u32)
+        .filter_map(|channel| meter.true_peak(channel).ok())
+        .fold(0.0, f64::max);
+    Ok(FileLoudness {
+        meter,
+        loudness,
+        peak,
+    })
+}
+
+/// Ses yüksekliğinden referansa göre kazanç (dB, iki basamak)
+fn gain(loudness: f64) -> f32 {
+    ((REFERENCE_LUFS - loudness) * 100.0).round() as f32 / 100.0
+}
+
+/// Değerleri dosyanın birincil etiketine ReplayGain olarak yaz; etiket yoksa eklenir,
+/// değeri olmayan alanlar etiketten silinir
+pub fn write_tags(path: &Path, replay_gain: &ReplayGain) -> Result<(), String> {
+    let mut tagged_file = lofty::read_from_path(path).map_err(|e| e.to_string())?;
+    if tagged_file.primary_tag().is_none() {
+        let tag_type = tagged_file.primary_tag_type();
+        tagged_file.insert_tag(Tag::new(tag_type));
+    }
+    let tag = tagged_file
+        .primary_tag_mut()
+        .ok_or("file format does not support tags")?;
+
+    let gain = |value: Option<f32>| value.map(|gain| format!("{:.2} dB", gain));
+    let peak = |value: Option<f32>| value.map(|peak| format!("{:.6}", peak));
+    let values = [
+        (ItemKey::ReplayGainTrackGain, gain(replay_gain.track_gain)),
+        (ItemKey::ReplayGainTrackPeak, peak(replay_gain.track_peak)),
+        (ItemKey::ReplayGainAlbumGain, gain(replay_gain.album_gain)),
+        (ItemKey::ReplayGainAlbumPeak, peak(replay_gain.album_peak)),
+    ];
+    for (key, value) in values {
+        match value {
+            Some(value) => {
+                if !tag.insert_text(key, value) {
+                    return Err("tag format cannot store ReplayGain values".to_string());
+                }
+            }
+            None => tag.remove_key(&key),
+        }
+    }
+
+    tagged_file
+        .save_to_path(path, WriteOptions::default())
+        .map_err(|e| e.to_string())
+}
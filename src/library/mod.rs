@@ -0,0 +1,338 @@
+pub mod browse;
+pub mod cue;
+pub mod finder;
+pub mod history;
+pub mod index;
+pub mod loudness;
+pub mod scanner;
+pub mod track;
+pub mod tree;
+
+use globset::GlobSet;
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::Receiver;
+use walkdir::WalkDir;
+
+pub use index::LibraryIndex;
+pub use scanner::{ScanEvent, ScanProgress};
+pub use track::Track;
+
+use cue::{CueSheet, SheetCache};
+use index::FileStamp;
+
+use crate::playlist;
+
+/// Taramada bulunan dosya
+pub enum ScanItem {
+    Track(Box<Track>),
+    /// Çalma listesi dosyası (M3U, PLS, XSPF)
+    Playlist(PathBuf),
+}
+
+/// Kök klasörler ve diskteki indeksle birlikte müzik kütüphanesi
+pub struct Library {
+    pub roots: Vec<PathBuf>,
+    pub filter: ScanFilter,
+    pub index: LibraryIndex,
+    index_path: Option<PathBuf>,
+}
+
+/// Taramada hangi dosyaların alınacağını belirleyen filtre
+#[derive(Debug, Clone)]
+pub struct ScanFilter {
+    /// Küçük harfli uzantılar ("mp3", "flac", ...)
+    pub extensions: Vec<String>,
+    /// Kök klasöre göre göreli yol veya tam yol ile eşleşen dosya/klasörler atlanır
+    pub exclude: GlobSet,
+}
+
+impl Default for ScanFilter {
+    fn default() -> Self {
+        ScanFilter {
+            extensions: ["mp3", "flac", "wav", "ogg", "m4a", "aac"]
+                .map(String::from)
+                .to_vec(),
+            exclude: GlobSet::empty(),
+        }
+    }
+}
+
+impl ScanFilter {
+    /// Desteklenen ses dosyası mı?
+    pub fn is_supported(&self, path: &Path) -> bool {
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some(ext) => {
+                let ext = ext.to_lowercase();
+                self.extensions.contains(&ext)
+            }
+            None => false,
+        }
+    }
+
+    /// Yol hariç tutulanlardan biriyle eşleşiyor mu?
+    pub fn is_excluded(&self, root: &Path, path: &Path) -> bool {
+        if self.exclude.is_empty() {
+            return false;
+        }
+        let relative = path.strip_prefix(root).unwrap_or(path);
+        self.exclude.is_match(relative) || self.exclude.is_match(path)
+    }
+}
+
+impl Library {
+    /// Kütüphaneyi aç ve varsa kayıtlı indeksi yükle
+    pub fn open(roots: Vec<PathBuf>, filter: ScanFilter) -> Library {
+        Library::with_index(roots, filter, LibraryIndex::default_path())
+    }
+
+    /// Kütüphaneyi verilen indeks dosyasıyla aç; yol yoksa indeks yalnızca bellekte tutulur
+    pub fn with_index(
+        roots: Vec<PathBuf>,
+        filter: ScanFilter,
+        index_path: Option<PathBuf>,
+    ) -> Library {
+        let index = index_path
+            .as_deref()
+            .map(LibraryIndex::load)
+            .unwrap_or_default();
+        Library {
+            roots,
+            filter,
+            index,
+            index_path,
+        }
+    }
+
+    /// İndekste kayıtlı, kökler altındaki parçalar (tarama bitmeden gösterilebilir)
+    pub fn cached_tracks(&self) -> Vec<Track> {
+        let roots: Vec<PathBuf> = self
+            .roots
+            .iter()
+            .filter_map(|root| root.canonicalize().ok())
+            .collect();
+        let mut sheets = SheetCache::default();
+        let mut tracks: Vec<Track> = self
+            .index
+            .entries
+            .values()
+            .filter(|entry| roots.iter().any(|root| entry.track.path.starts_with(root)))
+            .flat_map(|entry| sheets.expand(entry.track.clone()))
+            .collect();
+        tracks.sort_by(|a, b| a.path.cmp(&b.path));
+        tracks
+    }
+
+    /// Tüm kökleri bu thread'de artımlı olarak tara
+    pub fn scan(&mut self) -> (Vec<Track>, ScanProgress) {
+        let mut tracks = Vec::new();
+        let mut progress = ScanProgress::default();
+        for root in &self.roots {
+            scan_music(
+                root,
+                &mut self.index,
+                &self.filter,
+                &mut progress,
+                |item, _| {
+                    if let ScanItem::Track(track) = item {
+                        tracks.push(*track);
+                    }
+                },
+            );
+        }
+        progress.done = true;
+        (tracks, progress)
+    }
+
+    /// Verilen dosya ve klasörlerden parça listesi oluştur (klasörler taranır,
+    /// çalma listelerindeki dosyalar sırayla eklenir); okunamayan listeler ve bulunamayan
+    /// satırlar için uyarılar ayrıca döndürülür
+    pub fn load_paths(&mut self, paths: &[PathBuf]) -> (Vec<Track>, Vec<String>) {
+        let mut tracks = Vec::new();
+        let mut warnings = Vec::new();
+        let mut progress = ScanProgress::default();
+        let mut sheets = SheetCache::default();
+        for path in paths {
+            if path.is_dir() {
+                scan_music(
+                    path,
+                    &mut self.index,
+                    &self.filter,
+                    &mut progress,
+                    |item, _| {
+                        if let ScanItem::Track(track) = item {
+                            tracks.push(*track);
+                        }
+                    },
+                );
+            } else if path.is_file() && playlist::is_playlist(path) {
+                let list = match playlist::load(path) {
+                    Ok(list) => list,
+                    Err(e) => {
+                        warnings.push(format!("{}: {}", path.display(), e));
+                        continue;
+                    }
+                };
+                for entry in list.entries {
+                    if entry.path.is_file() {
+                        tracks.push(self.load_file(&entry.path));
+                    } else {
+                        warnings.push(format!(
+                            "{}: entry not found: {}",
+                            path.display(),
+                            entry.describe()
+                        ));
+                    }
+                }
+            } else if path.is_file() && cue::is_cue_sheet(path) {
+                match self.load_cue_sheet(path) {
+                    Ok(loaded) => tracks.extend(loaded),
+                    Err(e) => warnings.push(format!("{}: {}", path.display(), e)),
+                }
+            } else if path.is_file() {
+                let path = path.canonicalize().unwrap_or_else(|_| path.clone());
+                tracks.extend(sheets.expand(self.load_file(&path)));
+            }
+        }
+        (tracks, warnings)
+    }
+
+    /// CUE sayfasındaki dosyaları sanal parçalarına bölerek oku
+    fn load_cue_sheet(&self, path: &Path) -> Result<Vec<Track>, String> {
+        let sheet = CueSheet::read(path).map_err(|e| e.to_string())?;
+        let base = path.parent().unwrap_or(Path::new("."));
+        let mut tracks = Vec::new();
+        for file in &sheet.files {
+            // Sayfada yazan uzantı dosyanınkinden farklı olabilir (ör. .wav yerine .flac)
+            let named = base.join(file.name.replace('\\', "/"));
+            let audio = Some(named.clone()).filter(|p| p.is_file()).or_else(|| {
+                self.filter
+                    .extensions
+                    .iter()
+                    .map(|ext| named.with_extension(ext))
+                    .find(|p| p.is_file())
+            });
+            let Some(audio) = audio else {
+                return Err(format!("file not found: {}", file.name));
+            };
+            let audio = audio.canonicalize().unwrap_or(audio);
+            let track = self.load_file(&audio);
+            match sheet.split(&track) {
+                Some(split) => tracks.extend(split),
+                None => tracks.push(track),
+            }
+        }
+        Ok(tracks)
+    }
+
+    /// Tek dosyayı indeksten veya etiketlerinden oku
+    pub fn load_file(&self, path: &Path) -> Track {
+        let cached = FileStamp::read(path)
+            .ok()
+            .and_then(|stamp| self.index.lookup(path, stamp).cloned());
+        cached.unwrap_or_else(|| Track::read(path).unwrap_or_else(|_| Track::from_file_name(path)))
+    }
+
+    /// İndeksi diske kaydet
+    pub fn save(&self) -> std::io::Result<()> {
+        match &self.index_path {
+            Some(path) => self.index.save(path),
+            None => Ok(()),
+        }
+    }
+
+    /// Taramayı arka planda başlat; thread indeksin bir kopyasını günceller ve bitince geri verir,
+    /// tarama süresince buradaki indeks okunabilir kalır
+    pub fn spawn_scan(&self) -> Receiver<ScanEvent> {
+        scanner::spawn_scan(
+            self.roots.clone(),
+            self.filter.clone(),
+            self.index.clone(),
+            self.index_path.clone(),
+        )
+    }
+
+    /// İndeksin bu oturumda diske yazılmasını engelle (ör. tarama thread'i güncel indeksi
+    /// geri vermeden bittiyse diskteki kayıt eskisiyle ezilmesin)
+    pub fn disable_save(&mut self) {
+        self.index_path = None;
+    }
+}
+
+/// Klasörü tara, indeksteki değişmemiş dosyaları tekrar okuma ve silinenleri indeksten çıkar
+pub fn scan_music<F>(
+    folder: &Path,
+    index: &mut LibraryIndex,
+    filter: &ScanFilter,
+    progress: &mut ScanProgress,
+    mut on_item: F,
+) where
+    F: FnMut(ScanItem, &ScanProgress),
+{
+    // Klasör mevcut değilse hiçbir şey yapma
+    let Ok(root) = folder.canonicalize() else {
+        return;
+    };
+
+    let mut seen = HashSet::new();
+    // Okunamayan klasör ve dosyalar; altlarındaki indeks kayıtları silinmez
+    let mut unreadable = Vec::new();
+    let mut sheets = SheetCache::default();
+    let walker = WalkDir::new(&root)
+        .sort_by_file_name()
+        .into_iter()
+        .filter_entry(|entry| entry.depth() == 0 || !filter.is_excluded(&root, entry.path()));
+    for entry in walker {
+        let entry = match entry {
+            Ok(entry) => entry,
+            Err(e) => {
+                progress.errors += 1;
+                // Yolu bilinmeyen hatada kökün tamamı korunur
+                unreadable.push(e.path().unwrap_or(&root).to_path_buf());
+                continue;
+            }
+        };
+        if !entry.file_type().is_file() {
+            continue;
+        }
+        if playlist::is_playlist(entry.path()) {
+            on_item(ScanItem::Playlist(entry.path().to_path_buf()), progress);
+            continue;
+        }
+        if !filter.is_supported(entry.path()) {
+            continue;
+        }
+        let path = entry.path();
+        progress.seen += 1;
+        let Ok(stamp) = FileStamp::read(path) else {
+            progress.errors += 1;
+            unreadable.push(path.to_path_buf());
+            continue;
+        };
+
+        let track = match index.lookup(path, stamp) {
+            Some(track) => track.clone(),
+            None => {
+                let track = match Track::read(path) {
+                    Ok(track) => {
+                        progress.parsed += 1;
+                        track
+                    }
+                    Err(_) => {
+                        // Okunamayan dosya yine de listelenir, bilgiler dosya adından alınır
+                        progress.errors += 1;
+                        Track::from_file_name(path)
+                    }
+                };
+                index.insert(stamp, track).clone()
+            }
+        };
+        seen.insert(path.to_path_buf());
+        // Yanında CUE sayfası olan dosyalar sayfadaki parçalar olarak listelenir
+        for track in sheets.expand(track) {
+            on_item(ScanItem::Track(Box::new(track)), progress);
+        }
+    }
+
+    index.prune(&root, &seen, &unreadable);
+}
@@ -0,0 +1,72 @@
+use std::path::PathBuf;
+use std::sync::mpsc::{self, Receiver};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::library::{LibraryIndex, ScanFilter, ScanItem, Track, scan_music};
+
+/// Arka plan taramasının ilerleme bilgisi
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct ScanProgress {
+    pub seen: usize,
+    pub parsed: usize,
+    pub errors: usize,
+    pub done: bool,
+}
+
+/// Tarama thread'inden UI'a gönderilen mesajlar
+pub enum ScanEvent {
+    Track(Track),
+    /// Bulunan çalma listesi dosyası
+    Playlist(PathBuf),
+    Progress(ScanProgress),
+    /// Tarama bitti; güncellenmiş indeks geri verilir
+    Finished(ScanProgress, LibraryIndex),
+}
+
+/// İlerleme mesajları arasındaki en kısa süre
+const PROGRESS_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Kökleri ayrı bir thread'de tara ve bulunan parçaları kanal üzerinden gönder
+pub fn spawn_scan(
+    roots: Vec<PathBuf>,
+    filter: ScanFilter,
+    mut index: LibraryIndex,
+    index_path: Option<PathBuf>,
+) -> Receiver<ScanEvent> {
+    let (tx, rx) = mpsc::channel();
+
+    thread::spawn(move || {
+        let mut progress = ScanProgress::default();
+        let mut last_report = Instant::now();
+
+        for root in &roots {
+            scan_music(
+                root,
+                &mut index,
+                &filter,
+                &mut progress,
+                |item, progress| {
+                    // UI kapandıysa gönderim başarısız olur, sorun değil
+                    let _ = tx.send(match item {
+                        ScanItem::Track(track) => ScanEvent::Track(*track),
+                        ScanItem::Playlist(path) => ScanEvent::Playlist(path),
+                    });
+                    if last_report.elapsed() >= PROGRESS_INTERVAL {
+                        let _ = tx.send(ScanEvent::Progress(*progress));
+                        last_report = Instant::now();
+                    }
+                },
+            );
+        }
+
+        if let Some(path) = index_path {
+            let _ = index.save(&path);
+        }
+
+        progress.done = true;
+        let _ = tx.send(ScanEvent::Finished(progress, index));
+    });
+
+    rx
+}
@@ -0,0 +1,469 @@
+use lofty::prelude::*;
+use lofty::tag::{ItemValue, Tag};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/// R128 kazancını ReplayGain referansına çevirmek için eklenen fark (-18 - -23 LUFS)
+const R128_TO_REPLAYGAIN_DB: f32 = 5.0;
+
+/// Kütüphanedeki tek bir parça ve etiketlerden okunan bilgileri
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Track {
+    pub path: PathBuf,
+    pub title: String,
+    pub artist: Option<String>,
+    pub album: Option<String>,
+    pub album_artist: Option<String>,
+    pub track_number: Option<u32>,
+    pub disc_number: Option<u32>,
+    pub year: Option<u32>,
+    pub genre: Option<String>,
+    pub duration: Option<Duration>,
+    /// Etiketteki puan (1-5 yıldız)
+    pub rating: Option<u8>,
+    /// Kütüphaneye eklenme zamanı (Unix saniyesi; indekse ilk girişte belirlenir)
+    pub added: Option<u64>,
+    /// CUE sayfasından gelen sanal parçanın dosyadaki aralığı
+    pub cue: Option<CueRange>,
+    /// Etiketlerden okunan ses yüksekliği düzeltmesi
+    pub replay_gain: ReplayGain,
+}
+
+/// ReplayGain değerleri: kazanç desibel, tepe doğrusal ölçekte (1.0 = tam ölçek)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct ReplayGain {
+    pub track_gain: Option<f32>,
+    pub track_peak: Option<f32>,
+    pub album_gain: Option<f32>,
+    pub album_peak: Option<f32>,
+}
+
+impl ReplayGain {
+    /// Hiç değer yok mu?
+    pub fn is_empty(&self) -> bool {
+        *self == ReplayGain::default()
+    }
+}
+
+/// Sanal parçanın dosya içindeki başlangıcı ve (son parça değilse) bitişi
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct CueRange {
+    pub start: Duration,
+    pub end: Option<Duration>,
+}
+
+/// Kodlayıcının parçanın başına ve sonuna eklediği sessizlik (örnek karesi cinsinden)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EncoderGap {
+    pub delay: u64,
+    pub padding: u64,
+    /// Sessizlik hariç kare sayısı (biliniyorsa)
+    pub frames: Option<u64>,
+}
+
+impl EncoderGap {
+    /// M4A dosyalarındaki iTunSMPB etiketini oku; MP3'teki LAME bilgisini çözücü zaten uygular
+    pub fn read(path: &Path) -> Option<EncoderGap> {
+        let ext = path.extension()?.to_str()?.to_lowercase();
+        if !["m4a", "m4b", "mp4"].contains(&ext.as_str()) {
+            return None;
+        }
+        let tagged_file = lofty::read_from_path(path).ok()?;
+        let key = ItemKey::Unknown("----:com.apple.iTunes:iTunSMPB".to_string());
+        tagged_file
+            .tags()
+            .iter()
+            .find_map(|tag| tag.get_string(&key))
+            .and_then(EncoderGap::parse)
+    }
+
+    /// " 00000000 00000840 000001CA 00000000000E1A76 ..." biçimini çöz: ikinci alan
+    /// gecikme, üçüncüsü dolgu, dördüncüsü asıl kare sayısı (onaltılık)
+    fn parse(text: &str) -> Option<EncoderGap> {
+        let fields: Vec<u64> = text
+            .split_whitespace()
+            .take(4)
+            .map(|field| u64::from_str_radix(field, 16).ok())
+            .collect::<Option<_>>()?;
+        let [_, delay, padding, frames] = fields[..] else {
+            return None;
+        };
+        if delay == 0 && padding == 0 {
+            return None;
+        }
+        Some(EncoderGap {
+            delay,
+            padding,
+            frames: Some(frames).filter(|&frames| frames > 0),
+        })
+    }
+}
+
+/// Parçayı ayırt eden anahtar: aynı dosyadaki sanal parçalar başlangıçlarıyla ayrılır
+pub type TrackId = (PathBuf, Option<Duration>);
+
+impl Track {
+    /// Dosyanın etiketlerini oku; etiket yoksa bilgiler dosya adından tahmin edilir
+    pub fn read(path: &Path) -> lofty::error::Result<Track> {
+        let mut track = Track::from_file_name(path);
+        let tagged_file = lofty::read_from_path(path)?;
+
+        let duration = tagged_file.properties().duration();
+        if !duration.is_zero() {
+            track.duration = Some(duration);
+        }
+
+        // Birincil etiket önce gelir (ör. ID3v2), eksik alanlar diğerlerinden (ör. ID3v1) tamamlanır
+        let mut tags: Vec<&Tag> = tagged_file.tags().iter().collect();
+        if let Some(primary) = tagged_file.primary_tag() {
+            tags.retain(|tag| tag.tag_type() != primary.tag_type());
+            tags.insert(0, primary);
+        }
+
+        if let Some(title) = first_string(&tags, |tag| tag.title().map(|s| s.into_owned())) {
+            track.title = title;
+        }
+        if let Some(artist) = first_string(&tags, |tag| tag.artist().map(|s| s.into_owned())) {
+            track.artist = Some(artist);
+        }
+        if let Some(album) = first_string(&tags, |tag| tag.album().map(|s| s.into_owned())) {
+            track.album = Some(album);
+        }
+        track.album_artist = first_string(&tags, |tag| {
+            tag.get_string(&ItemKey::AlbumArtist).map(str::to_string)
+        });
+        track.genre = first_string(&tags, |tag| tag.genre().map(|s| s.into_owned()));
+        if let Some(number) = tags.iter().find_map(|tag| tag.track()) {
+            track.track_number = Some(number);
+        }
+        track.disc_number = tags.iter().find_map(|tag| tag.disk());
+        track.year = tags.iter().find_map(|tag| tag.year());
+        track.rating = tags.iter().find_map(|tag| {
+            tag.get(&ItemKey::Popularimeter)
+                .and_then(|item| parse_rating(item.value()))
+        });
+
+        track.replay_gain = read_replay_gain(&tags);
+
+        Ok(track)
+    }
+
+    /// Etiketsiz dosyalar için "01 - Sanatçı - Başlık.mp3" gibi isimleri ayrıştır
+    pub fn from_file_name(path: &Path) -> Track {
+        let stem = path
+            .file_stem()
+            .and_then(|s| s.to_str())
+            .unwrap_or("Unknown")
+            .trim();
+
+        // Baştaki parça numarasını ayır ("01 - ", "01. ", "01_")
+        let digits: String = stem.chars().take_while(|c| c.is_ascii_digit()).collect();
+        let after_digits = &stem[digits.len()..];
+        let has_separator = after_digits.starts_with([' ', '.', '-', '_']);
+        let rest = after_digits.trim_start_matches([' ', '.', '-', '_']);
+        let (track_number, rest) =
+            if !digits.is_empty() && digits.len() <= 3 && has_separator && !rest.is_empty() {
+                (digits.parse().ok(), rest)
+            } else {
+                (None, stem)
+            };
+
+        let (artist, title) = match rest.split_once(" - ") {
+            Some((artist, title)) if !artist.trim().is_empty() && !title.trim().is_empty() => {
+                (Some(artist.trim().to_string()), title.trim().to_string())
+            }
+            _ => (None, rest.replace('_', " ").trim().to_string()),
+        };
+
+        // Albüm adı olarak üst klasörü kullan
+        let album = path
+            .parent()
+            .and_then(|p| p.file_name())
+            .and_then(|name| name.to_str())
+            .filter(|name| *name != "." && *name != "music")
+            .map(str::to_string);
+
+        Track {
+            path: path.to_path_buf(),
+            title,
+            artist,
+            album,
+            album_artist: None,
+            track_number,
+            disc_number: None,
+            year: None,
+            genre: None,
+            duration: None,
+            rating: None,
+            added: None,
+            cue: None,
+            replay_gain: ReplayGain::default(),
+        }
+    }
+
+    /// Parçanın anahtarı
+    pub fn id(&self) -> TrackId {
+        (self.path.clone(), self.cue.map(|cue| cue.start))
+    }
+
+    /// İki kayıt aynı parçayı mı gösteriyor? (etiketler farklı okunmuş olabilir)
+    pub fn is_same(&self, other: &Track) -> bool {
+        self.path == other.path && self.cue.map(|c| c.start) == other.cue.map(|c| c.start)
+    }
+
+    /// Listede gösterilecek "Sanatçı - Başlık" metni
+    pub fn display_name(&self) -> String {
+        match &self.artist {
+            Some(artist) => format!("{} - {}", artist, self.title),
+            None => self.title.clone(),
+        }
+    }
+
+    /// Aramada kullanılan metin: başlık, sanatçı, albüm ve yol (küçük harf)
+    pub fn search_text(&self) -> String {
+        let mut text = self.title.to_lowercase();
+        for field in [&self.artist, &self.album_artist, &self.album]
+            .into_iter()
+            .flatten()
+        {
+            text.push('\n');
+            text.push_str(&field.to_lowercase());
+        }
+        text.push('\n');
+        text.push_str(&self.path.to_string_lossy().to_lowercase());
+        text
+    }
+
+    /// Dosya uzantısı (küçük harf)
+    pub fn extension(&self) -> Option<String> {
+        self.path
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .map(|ext| ext.to_lowercase())
+    }
+}
+
+/// Süreyi "m:ss" veya "h:mm:ss" biçiminde yaz
+pub fn format_duration(duration: Duration) -> String {
+    let secs = duration.as_secs();
+    if secs >= 3600 {
+        format!("{}:{:02}:{:02}", secs / 3600, (secs / 60) % 60, secs % 60)
+    } else {
+        format!("{}:{:02}", secs / 60, secs % 60)
+    }
+}
+
+/// ReplayGain etiketlerini oku; yoksa Opus dosyalarındaki R128 kazançlarına bak
+fn read_replay_gain(tags: &[&Tag]) -> ReplayGain {
+    let value = |key: ItemKey| {
+        tags.iter()
+            .find_map(|tag| tag.get_string(&key))
+            .and_then(parse_decibels)
+    };
+    // R128 kazancı -23 LUFS'ye göre Q7.8 tamsayıdır; ReplayGain -18 LUFS'yi hedefler
+    let r128 = |key: &str| {
+        value(ItemKey::Unknown(key.to_string())).map(|gain| gain / 256.0 + R128_TO_REPLAYGAIN_DB)
+    };
+    ReplayGain {
+        track_gain: value(ItemKey::ReplayGainTrackGain).or_else(|| r128("R128_TRACK_GAIN")),
+        track_peak: value(ItemKey::ReplayGainTrackPeak),
+        album_gain: value(ItemKey::ReplayGainAlbumGain).or_else(|| r128("R128_ALBUM_GAIN")),
+        album_peak: value(ItemKey::ReplayGainAlbumPeak),
+    }
+}
+
+/// "-6.54 dB" veya "0.988547" gibi değerlerin sayısı
+fn parse_decibels(text: &str) -> Option<f32> {
+    let number = text.split_whitespace().next()?;
+    number.parse::<f32>().ok().filter(|value| value.is_finite())
+}
+
+/// Puan etiketini 1-5 yıldıza çevir: ID3 POPM (0-255), metin olarak 0-100, 1-5 veya
+/// FMPS tarzı 0.0-1.0; 0 puansız demektir
+fn parse_rating(value: &ItemValue) -> Option<u8> {
+    let stars = match value {
+        // POPM: e-posta, sıfır baytı, ardından tek baytlık puan
+        ItemValue::Binary(data) => {
+            let rating = *data.iter().skip_while(|&&b| b != 0).nth(1)?;
+            match rating {
+                0 => 0,
+                1..=31 => 1,
+                32..=95 => 2,
+                96..=159 => 3,
+                160..=223 => 4,
+                _ => 5,
+            }
+        }
+        ItemValue::Text(text) | ItemValue::Locator(text) => {
+            let text = text.trim();
+            let number: f64 = text.parse().ok()?;
+            if text.contains('.') && (0.0..=1.0).contains(&number) {
+                (number * 5.0).round() as u8
+            } else if (0.0..=5.0).contains(&number) {
+                number.round() as u8
+            } else if (0.0..=100.0).contains(&number) {
+                (number / 20.0).round() as u8
+            } else {
+                return None;
+            }
+        }
+    };
+    Some(stars).filter(|&stars| stars > 0)
+}
+
+/// Etiketler arasında boş olmayan ilk değeri bul
+fn first_string<F>(tags: &[&Tag], get: F) -> Option<String>
+where
+    F: Fn(&Tag) -> Option<String>,
+{
+    tags.iter()
+        .filter_map(|tag| get(tag))
+        .map(|value| value.trim().to_string())
+        .find(|value| !value.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn from_name(path: &str) -> Track {
+        Track::from_file_name(Path::new(path))
+    }
+
+    #[test]
+    fn file_name_splits_number_artist_and_title() {
+        let track = from_name("/music/Kind of Blue/01 - Miles Davis - So What.mp3");
+        assert_eq!(track.track_number, Some(1));
+        assert_eq!(track.artist.as_deref(), Some("Miles Davis"));
+        assert_eq!(track.title, "So What");
+        assert_eq!(track.album.as_deref(), Some("Kind of Blue"));
+
+        let track = from_name("/music/Miles Davis - Blue in Green.flac");
+        assert_eq!(track.track_number, None);
+        assert_eq!(track.artist.as_deref(), Some("Miles Davis"));
+        assert_eq!(track.title, "Blue in Green");
+        // "music" klasörü albüm sayılmaz
+        assert_eq!(track.album, None);
+
+        // Yalnızca ilk " - " ayırır
+        let track = from_name("a/07_AC - DC - Back in Black.ogg");
+        assert_eq!(track.track_number, Some(7));
+        assert_eq!(track.artist.as_deref(), Some("AC"));
+        assert_eq!(track.title, "DC - Back in Black");
+    }
+
+    #[test]
+    fn file_name_without_separators_is_the_title() {
+        let track = from_name("x/02. Freddie Freeloader.mp3");
+        assert_eq!(track.track_number, Some(2));
+        assert_eq!(track.artist, None);
+        assert_eq!(track.title, "Freddie Freeloader");
+
+        // Boşluksuz tire sanatçı ayırmaz, alt çizgiler boşluk olur
+        let track = from_name("x/Miles_Davis-So_What.mp3");
+        assert_eq!(track.artist, None);
+        assert_eq!(track.title, "Miles Davis-So What");
+
+        // Dört haneli sayı yıl ya da addır, parça numarası değil
+        let track = from_name("x/1999 - Prince - 1999.mp3");
+        assert_eq!(track.track_number, None);
+        assert_eq!(track.artist.as_deref(), Some("1999"));
+        assert_eq!(track.title, "Prince - 1999");
+
+        // Yalnızca sayıdan oluşan ad başlık olarak kalır
+        let track = from_name("x/2001.wav");
+        assert_eq!(track.track_number, None);
+        assert_eq!(track.title, "2001");
+
+        // Boş taraflı ayırıcı sanatçı sayılmaz
+        let track = from_name("x/ - Untitled.mp3");
+        assert_eq!(track.artist, None);
+        assert_eq!(track.title, "- Untitled");
+    }
+
+    fn text_rating(text: &str) -> Option<u8> {
+        parse_rating(&ItemValue::Text(text.to_string()))
+    }
+
+    /// POPM çerçevesi: e-posta, sıfır baytı, puan, sayaç
+    fn popm_rating(rating: u8) -> Option<u8> {
+        let mut data = b"user@example.com\0".to_vec();
+        data.extend([rating, 0, 0, 0, 7]);
+        parse_rating(&ItemValue::Binary(data))
+    }
+
+    #[test]
+    fn rating_scales() {
+        // 1-5 yıldız; 0 puansız
+        assert_eq!(text_rating("0"), None);
+        assert_eq!(text_rating("1"), Some(1));
+        assert_eq!(text_rating(" 5 "), Some(5));
+        // 0-100
+        assert_eq!(text_rating("60"), Some(3));
+        assert_eq!(text_rating("100"), Some(5));
+        assert_eq!(text_rating("9"), None);
+        // FMPS 0.0-1.0
+        assert_eq!(text_rating("0.8"), Some(4));
+        assert_eq!(text_rating("1.0"), Some(5));
+        assert_eq!(text_rating("0.0"), None);
+        // Ölçek dışı veya sayı olmayan değerler
+        assert_eq!(text_rating("150"), None);
+        assert_eq!(text_rating("-1"), None);
+        assert_eq!(text_rating("five"), None);
+    }
+
+    #[test]
+    fn popm_rating_scale() {
+        assert_eq!(popm_rating(0), None);
+        assert_eq!(popm_rating(1), Some(1));
+        assert_eq!(popm_rating(64), Some(2));
+        assert_eq!(popm_rating(128), Some(3));
+        assert_eq!(popm_rating(196), Some(4));
+        assert_eq!(popm_rating(255), Some(5));
+        // Puan baytı olmayan çerçeve
+        assert_eq!(
+            parse_rating(&ItemValue::Binary(b"user@example.com".to_vec())),
+            None
+        );
+        assert_eq!(parse_rating(&ItemValue::Binary(b"user\0".to_vec())), None);
+    }
+
+    #[test]
+    fn itunsmpb_gives_delay_padding_and_frames() {
+        // iTunes'un AAC kodlayıcısının tipik değeri: 2112 gecikme, 458 dolgu
+        let text = " 00000000 00000840 000001CA 00000000000E1A76 00000000 00000000 \
+                    00000000 00000000 00000000 00000000 00000000 00000000";
+        assert_eq!(
+            EncoderGap::parse(text),
+            Some(EncoderGap {
+                delay: 2112,
+                padding: 458,
+                frames: Some(924_278),
+            })
+        );
+        // Kare sayısı sıfırsa bilinmiyor sayılır
+        assert_eq!(
+            EncoderGap::parse("00000000 00000840 00000000 0000000000000000"),
+            Some(EncoderGap {
+                delay: 2112,
+                padding: 0,
+                frames: None,
+            })
+        );
+    }
+
+    #[test]
+    fn malformed_itunsmpb_is_ignored() {
+        for text in [
+            "",
+            "00000000 00000840 000001CA",
+            "00000000 0000084G 000001CA 00000000000E1A76",
+            "not a gap at all",
+            // Sessizlik yoksa atlanacak bir şey de yok
+            "00000000 00000000 00000000 00000000000E1A76",
+        ] {
+            assert_eq!(EncoderGap::parse(text), None, "{:?}", text);
+        }
+    }
+}
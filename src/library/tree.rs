@@ -0,0 +1,146 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use super::Track;
+
+/// Klasör ağacındaki bir klasör
+#[derive(Debug, Clone)]
+pub struct FolderNode {
+    pub path: PathBuf,
+    pub name: String,
+    /// Üst klasör (köklerde yok)
+    pub parent: Option<usize>,
+    /// Alt klasörler, ada göre sıralı
+    pub folders: Vec<usize>,
+    /// Doğrudan bu klasördeki parçalar (parça listesindeki sıralar), dosya adına göre sıralı
+    pub tracks: Vec<usize>,
+    /// Alt klasörlerle birlikte toplam parça sayısı
+    pub track_count: usize,
+}
+
+/// Kütüphane köklerinden başlayan klasör ağacı
+#[derive(Debug, Clone, Default)]
+pub struct FolderTree {
+    pub nodes: Vec<FolderNode>,
+    /// Kök klasörler, config'deki sırayla
+    pub roots: Vec<usize>,
+}
+
+impl FolderTree {
+    /// Parçaları köklerin altındaki klasörlere yerleştir; hiçbir kökün altında olmayan
+    /// parçalar (ör. `meloic play` ile verilenler) kendi klasörleri kök sayılarak gösterilir
+    pub fn build(roots: &[PathBuf], tracks: &[Track]) -> FolderTree {
+        let mut tree = FolderTree::default();
+        let mut by_path: HashMap<PathBuf, usize> = HashMap::new();
+        // Parça yolları tarayıcıda tam yola çevrilir; kökler de öyle eşleştirilir,
+        // ama config'de yazıldığı gibi gösterilir
+        let roots: Vec<PathBuf> = roots
+            .iter()
+            .map(|root| {
+                let resolved = root.canonicalize().unwrap_or_else(|_| root.clone());
+                if !by_path.contains_key(&resolved) {
+                    let node = tree.add_node(resolved.clone(), root.display().to_string(), None);
+                    by_path.insert(resolved.clone(), node);
+                    tree.roots.push(node);
+                }
+                resolved
+            })
+            .collect();
+
+        for (index, track) in tracks.iter().enumerate() {
+            let Some(folder) = track.path.parent() else {
+                continue;
+            };
+            // İç içe köklerde en yakın kök seçilir
+            let root = roots
+                .iter()
+                .filter(|root| folder.starts_with(root))
+                .max_by_key(|root| root.components().count())
+                .map(PathBuf::as_path)
+                .unwrap_or(folder);
+            let node = tree.folder_node(&mut by_path, root, folder);
+            tree.nodes[node].tracks.push(index);
+        }
+
+        tree.sort(tracks);
+        for root in tree.roots.clone() {
+            tree.count_tracks(root);
+        }
+        tree
+    }
+
+    fn add_node(&mut self, path: PathBuf, name: String, parent: Option<usize>) -> usize {
+        self.nodes.push(FolderNode {
+            path,
+            name,
+            parent,
+            folders: Vec::new(),
+            tracks: Vec::new(),
+            track_count: 0,
+        });
+        self.nodes.len() - 1
+    }
+
+    /// Klasörün düğümünü (gerekirse kökten itibaren ara klasörlerle birlikte) oluştur
+    fn folder_node(
+        &mut self,
+        by_path: &mut HashMap<PathBuf, usize>,
+        root: &Path,
+        folder: &Path,
+    ) -> usize {
+        if let Some(&node) = by_path.get(folder) {
+            return node;
+        }
+        let node = if folder == root {
+            let node = self.add_node(root.to_path_buf(), root.display().to_string(), None);
+            self.roots.push(node);
+            node
+        } else {
+            let parent_path = folder.parent().unwrap_or(root);
+            let parent = self.folder_node(by_path, root, parent_path);
+            let name = folder
+                .file_name()
+                .map(|name| name.to_string_lossy().into_owned())
+                .unwrap_or_else(|| folder.display().to_string());
+            let node = self.add_node(folder.to_path_buf(), name, Some(parent));
+            self.nodes[parent].folders.push(node);
+            node
+        };
+        by_path.insert(folder.to_path_buf(), node);
+        node
+    }
+
+    /// Alt klasörleri ve parçaları ada göre sırala
+    fn sort(&mut self, tracks: &[Track]) {
+        let names: Vec<String> = self.nodes.iter().map(|n| n.name.to_lowercase()).collect();
+        for node in &mut self.nodes {
+            node.folders.sort_by(|&a, &b| names[a].cmp(&names[b]));
+            node.tracks
+                .sort_by(|&a, &b| tracks[a].path.cmp(&tracks[b].path));
+        }
+    }
+
+    /// Klasörün ve alt klasörlerinin parça sayısını hesapla
+    fn count_tracks(&mut self, node: usize) -> usize {
+        let mut count = self.nodes[node].tracks.len();
+        for child in self.nodes[node].folders.clone() {
+            count += self.count_tracks(child);
+        }
+        self.nodes[node].track_count = count;
+        count
+    }
+
+    /// Klasördeki tüm parçalar, alt klasörler dahil; önce alt klasörler, sonra klasörün kendi parçaları
+    pub fn tracks(&self, node: usize) -> Vec<usize> {
+        let mut tracks = Vec::with_capacity(self.nodes[node].track_count);
+        self.collect_tracks(node, &mut tracks);
+        tracks
+    }
+
+    fn collect_tracks(&self, node: usize, tracks: &mut Vec<usize>) {
+        for &child in &self.nodes[node].folders {
+            self.collect_tracks(child, tracks);
+        }
+        tracks.extend_from_slice(&self.nodes[node].tracks);
+    }
+}
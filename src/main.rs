@@ -1,31 +1,479 @@
+mod cli;
+mod config;
 mod library;
+mod player;
+mod playlist;
+mod state;
 mod ui;
 
+use clap::Parser;
+use cli::{Cli, Command, ListFormat};
+use config::Config;
+use library::loudness;
+use library::track::ReplayGain;
+use library::{Library, Track};
+use player::equalizer::BAND_COUNT;
+use player::{EffectConfig, Player, Queue};
+use serde::Serialize;
+use state::{ConfigSettings, SavedEqualizer, SavedState};
+use std::error::Error;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
 fn main() {
-    // Önce "music" klasörünü tara, bulamazsa mevcut dizini tara
-    let mut tracks = library::scan_music("music");
+    let cli = Cli::parse();
 
-    if tracks.is_empty() {
-        // "music" klasöründe dosya yoksa mevcut dizini tara
-        tracks = library::scan_music(".");
+    // Config dosyasını yükle; komut satırı argümanları config'deki değerleri geçersiz kılar
+    let config = match Config::load(cli.config.as_deref()) {
+        Ok(config) => config,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            std::process::exit(1);
+        }
+    };
+
+    let result = match &cli.command {
+        Some(Command::Scan { roots }) => {
+            run_scan(&config, library_roots(&config, roots, &cli.roots))
+        }
+        Some(Command::List { roots, format }) => {
+            run_list(&config, library_roots(&config, roots, &cli.roots), *format)
+        }
+        Some(Command::Loudness {
+            roots,
+            write_tags,
+            force,
+        }) => run_loudness(
+            &config,
+            library_roots(&config, roots, &cli.roots),
+            *write_tags,
+            *force,
+        ),
+        Some(Command::Play { paths }) => run_play(&cli, &config, paths),
+        None => run_library(&cli, &config),
+    };
+
+    if let Err(e) = result {
+        eprintln!("Error: {}", e);
+        std::process::exit(1);
+    }
+}
+
+/// Taranacak kökleri belirle: alt komutun, sonra genel argümanların, sonra config'in kökleri;
+/// hiçbiri yoksa "music" klasörü varsa onu, yoksa mevcut dizini kullan
+fn library_roots(config: &Config, primary: &[PathBuf], fallback: &[PathBuf]) -> Vec<PathBuf> {
+    if !primary.is_empty() {
+        primary.to_vec()
+    } else if !fallback.is_empty() {
+        fallback.to_vec()
+    } else if !config.library.roots.is_empty() {
+        config.library.roots.clone()
+    } else if Path::new("music").is_dir() {
+        vec![PathBuf::from("music")]
+    } else {
+        vec![PathBuf::from(".")]
+    }
+}
+
+/// Ses seviyesi (yüzde): önce komut satırı, sonra kaydedilmiş seviye, sonra config;
+/// 0.0 - 1.0 aralığına çevrilir
+fn volume_fraction(cli: &Cli, config: &Config, state: &SavedState) -> f32 {
+    cli.volume
+        .or(state.volume)
+        .unwrap_or(config.playback.volume)
+        .min(100) as f32
+        / 100.0
+}
+
+/// Config'deki ekolayzır ayarı ve efekt zinciri
+fn config_settings(config: &Config) -> ConfigSettings {
+    ConfigSettings {
+        equalizer: SavedEqualizer {
+            enabled: config.equalizer.enabled,
+            gains: config
+                .equalizer
+                .startup_preset()
+                .map_or([0.0; BAND_COUNT], |preset| preset.gains),
+        },
+        effects: config.effects.clone(),
+    }
+}
+
+/// Açılıştaki ekolayzır ayarı: config kayıttan sonra değişmediyse kaydedilmiş ayar,
+/// yoksa config'deki hazır ayar
+fn equalizer_settings(config: &Config, state: &SavedState) -> SavedEqualizer {
+    let from_config = config_settings(config).equalizer;
+    match &state.equalizer {
+        Some(saved)
+            if state
+                .config
+                .as_ref()
+                .is_none_or(|base| base.equalizer == from_config) =>
+        {
+            saved.clone()
+        }
+        _ => from_config,
+    }
+}
+
+/// Açılıştaki efekt zinciri: config kayıttan sonra değişmediyse kaydedilmiş zincir,
+/// yoksa config'deki [[effects]]
+fn effect_settings(config: &Config, state: &SavedState) -> Vec<EffectConfig> {
+    match &state.effects {
+        Some(saved)
+            if state
+                .config
+                .as_ref()
+                .is_none_or(|base| base.effects == config.effects) =>
+        {
+            saved.clone()
+        }
+        _ => config.effects.clone(),
+    }
+}
+
+/// Config ve komut satırından UI seçeneklerini oluştur
+fn ui_options(cli: &Cli, config: &Config) -> ui::UiOptions {
+    let state = SavedState::load();
+    // Config yüklenirken doğrulandığı için bu dönüşümler hata vermez
+    ui::UiOptions {
+        scan_library: false,
+        autoplay: false,
+        enqueue_all: false,
+        shuffle: cli.shuffle,
+        volume: volume_fraction(cli, config, &state),
+        // Komut satırında seviye verildiyse sessiz mod geri yüklenmez
+        muted: state.muted && cli.volume.is_none(),
+        volume_step: config.playback.volume_step,
+        volume_curve: config.playback.volume_curve,
+        crossfade: config.playback.crossfade(),
+        normalization: config.playback.normalization(),
+        equalizer: equalizer_settings(config, &state),
+        eq_presets: config.equalizer.presets(),
+        effects: effect_settings(config, &state),
+        config_settings: config_settings(config),
+        page_size: config.ui.page_size,
+        seek_step: config.playback.seek_step_secs,
+        seek_long_step: config.playback.seek_long_step_secs,
+        poll_interval: Duration::from_millis(config.ui.poll_interval_ms),
+        theme: ui::theme::Theme::from_config(&config.theme).unwrap_or_default(),
+        keymap: ui::events::KeyMap::from_config(&config.keys).unwrap_or_default(),
+        smart_playlists: config.smart_playlists.clone(),
+    }
+}
+
+/// `meloic scan`: kütüphaneyi tara ve indeksi güncelle
+fn run_scan(config: &Config, roots: Vec<PathBuf>) -> Result<(), Box<dyn Error>> {
+    let mut library = Library::open(roots, config.scan_filter());
+    let (tracks, progress) = library.scan();
+    library.save()?;
+
+    println!(
+        "📀 {} tracks ({} files seen, {} parsed, {} errors)",
+        tracks.len(),
+        progress.seen,
+        progress.parsed,
+        progress.errors
+    );
+    Ok(())
+}
+
+/// `meloic loudness`: parçaların ses yüksekliğini ölç, indekse ve istenirse etiketlere yaz
+fn run_loudness(
+    config: &Config,
+    roots: Vec<PathBuf>,
+    write_tags: bool,
+    force: bool,
+) -> Result<(), Box<dyn Error>> {
+    let mut library = Library::open(roots, config.scan_filter());
+    let (tracks, _) = library.scan();
+    let files = if force {
+        loudness::files(&tracks)
+    } else {
+        loudness::pending(&tracks)
+    };
+    if files.is_empty() {
+        println!("🔊 All tracks already have ReplayGain values (use --force to measure again)");
+        return Ok(());
+    }
+
+    let total = files.len();
+    let measurements = loudness::analyze(&files, |done, path, result| match result {
+        Ok(lufs) => println!("[{}/{}] {:.1} LUFS\t{}", done, total, lufs, path.display()),
+        Err(e) => eprintln!("[{}/{}] ⚠️  {}: {}", done, total, path.display(), e),
+    });
+
+    // Ölçüm ve etiket yazma ayrı sayılır; etiketi yazılamayan dosyanın ölçümü indekste kalır
+    let mut measured = 0;
+    let mut errors = 0;
+    let mut tagged = 0;
+    let mut tag_errors = 0;
+    for measurement in &measurements {
+        let Ok(replay_gain) = &measurement.result else {
+            errors += 1;
+            continue;
+        };
+        if write_tags {
+            match loudness::write_tags(&measurement.path, replay_gain) {
+                Ok(()) => tagged += 1,
+                Err(e) => {
+                    eprintln!("⚠️  {}: {}", measurement.path.display(), e);
+                    tag_errors += 1;
+                }
+            }
+        }
+        library
+            .index
+            .set_replay_gain(&measurement.path, *replay_gain);
+        measured += 1;
     }
+    library.save()?;
 
+    println!("🔊 {} files measured ({} errors)", measured, errors);
+    if write_tags {
+        println!("🏷️  Tags written to {} files ({} failed)", tagged, tag_errors);
+    }
+    Ok(())
+}
+
+/// JSON çıktısında kullanılan parça görünümü
+#[derive(Serialize)]
+struct TrackJson<'a> {
+    path: &'a Path,
+    title: &'a str,
+    artist: Option<&'a str>,
+    album: Option<&'a str>,
+    album_artist: Option<&'a str>,
+    track_number: Option<u32>,
+    disc_number: Option<u32>,
+    year: Option<u32>,
+    genre: Option<&'a str>,
+    duration: Option<f64>,
+    rating: Option<u8>,
+    added: Option<u64>,
+    #[serde(skip_serializing_if = "ReplayGain::is_empty")]
+    replay_gain: ReplayGain,
+    /// CUE sayfasındaki parçanın dosyadaki başlangıcı ve bitişi (saniye)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    start: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    end: Option<f64>,
+}
+
+impl<'a> From<&'a Track> for TrackJson<'a> {
+    fn from(track: &'a Track) -> Self {
+        TrackJson {
+            path: &track.path,
+            title: &track.title,
+            artist: track.artist.as_deref(),
+            album: track.album.as_deref(),
+            album_artist: track.album_artist.as_deref(),
+            track_number: track.track_number,
+            disc_number: track.disc_number,
+            year: track.year,
+            genre: track.genre.as_deref(),
+            duration: track.duration.map(|d| d.as_secs_f64()),
+            rating: track.rating,
+            added: track.added,
+            replay_gain: track.replay_gain,
+            start: track.cue.map(|cue| cue.start.as_secs_f64()),
+            end: track
+                .cue
+                .and_then(|cue| cue.end)
+                .map(|end| end.as_secs_f64()),
+        }
+    }
+}
+
+/// `meloic list`: kütüphanedeki parçaları yazdır
+fn run_list(
+    config: &Config,
+    roots: Vec<PathBuf>,
+    format: ListFormat,
+) -> Result<(), Box<dyn Error>> {
+    let mut library = Library::open(roots, config.scan_filter());
+    let (tracks, _) = library.scan();
+    library.save()?;
+
+    match format {
+        ListFormat::Plain => {
+            for track in &tracks {
+                println!("{}\t{}", track.display_name(), track.path.display());
+            }
+        }
+        ListFormat::Json => {
+            let json: Vec<TrackJson> = tracks.iter().map(TrackJson::from).collect();
+            println!("{}", serde_json::to_string_pretty(&json)?);
+        }
+    }
+    Ok(())
+}
+
+/// `meloic play`: verilen dosya ve klasörleri çal
+fn run_play(cli: &Cli, config: &Config, paths: &[PathBuf]) -> Result<(), Box<dyn Error>> {
+    let mut library = Library::open(Vec::new(), config.scan_filter());
+    let (tracks, warnings) = library.load_paths(paths);
+    for warning in &warnings {
+        eprintln!("⚠️  {}", warning);
+    }
     if tracks.is_empty() {
-        println!("🎵 No music found!");
-        println!("💡 Try:");
-        println!("   - Put music files in a 'music' folder");
-        println!("   - Or run meloic from a folder containing music files");
-        std::thread::sleep(std::time::Duration::from_secs(3));
-        return;
+        return Err("no playable files found".into());
+    }
+
+    if cli.no_ui {
+        return play_headless(&tracks, cli, config);
     }
 
-    // UI'ı çalıştır
-    match ui::run_ui(&tracks) {
-        Ok(_) => {
-            println!("👋 Exiting meloic. Goodbye!");
+    // Verilen parçaların hepsi kuyruğa eklenip sırayla çalınır
+    let options = ui::UiOptions {
+        autoplay: true,
+        enqueue_all: true,
+        ..ui_options(cli, config)
+    };
+    run_ui(library, tracks, options)
+}
+
+/// Argümansız çalıştırma: kütüphaneyi UI ile (veya `--no-ui` ile doğrudan) çal
+fn run_library(cli: &Cli, config: &Config) -> Result<(), Box<dyn Error>> {
+    let roots = library_roots(config, &cli.roots, &[]);
+    let mut library = Library::open(roots, config.scan_filter());
+
+    if cli.no_ui {
+        let (tracks, _) = library.scan();
+        library.save()?;
+        if tracks.is_empty() {
+            return Err("no music found".into());
         }
-        Err(e) => {
-            eprintln!("Error: {}", e);
+        return play_headless(&tracks, cli, config);
+    }
+
+    // İndekste kayıtlı parçalar hemen gösterilir, tarama UI içinde arka planda yapılır;
+    // --shuffle ile açılışta rastgele bir parça çalınır
+    let tracks = library.cached_tracks();
+    let options = ui::UiOptions {
+        scan_library: true,
+        autoplay: cli.shuffle,
+        ..ui_options(cli, config)
+    };
+    run_ui(library, tracks, options)
+}
+
+/// UI'ı çalıştır
+fn run_ui(
+    library: Library,
+    tracks: Vec<Track>,
+    options: ui::UiOptions,
+) -> Result<(), Box<dyn Error>> {
+    ui::run_ui(library, tracks, options)?;
+    println!("👋 Exiting meloic. Goodbye!");
+    Ok(())
+}
+
+/// Terminal arayüzü olmadan parçaları sırayla çal
+fn play_headless(tracks: &[Track], cli: &Cli, config: &Config) -> Result<(), Box<dyn Error>> {
+    let state = SavedState::load();
+    let mut player = Player::new(volume_fraction(cli, config, &state));
+    player.curve = config.playback.volume_curve;
+    player.crossfade = config.playback.crossfade();
+    player.normalization = config.playback.normalization();
+    player.shuffled = cli.shuffle;
+    let equalizer = equalizer_settings(config, &state);
+    player.equalizer.set_gains(&equalizer.gains);
+    player.equalizer.set_enabled(equalizer.enabled);
+    player.effects.configure(&effect_settings(config, &state));
+    let mut queue = Queue::default();
+    for track in tracks {
+        queue.push(track.clone());
+    }
+    queue.set_shuffle(cli.shuffle);
+
+    while let Some(track) = queue.advance().cloned() {
+        if let Err(e) = player.play(track.clone()) {
+            eprintln!("⚠️  {}: {}", track.path.display(), e);
+            continue;
         }
+        println!("▶️  {}", track.display_name());
+        // Sıradaki parça önceden açılır, böylece parçalar arasında boşluk kalmaz
+        while player.take_finished().is_none() {
+            player.set_following(queue.peek_on_finish());
+            std::thread::sleep(Duration::from_millis(100));
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(text: &str) -> Config {
+        toml::from_str(text).unwrap()
+    }
+
+    /// Çıkışta kaydedilen durumu diske yazılıp okunmuş gibi döndür
+    fn saved(config: &Config, equalizer: SavedEqualizer, effects: Vec<EffectConfig>) -> SavedState {
+        let state = SavedState {
+            equalizer: Some(equalizer),
+            effects: Some(effects),
+            config: Some(config_settings(config)),
+            ..SavedState::default()
+        };
+        toml::from_str(&toml::to_string(&state).unwrap()).unwrap()
+    }
+
+    fn boosted() -> SavedEqualizer {
+        SavedEqualizer {
+            enabled: true,
+            gains: [4.5; BAND_COUNT],
+        }
+    }
+
+    #[test]
+    fn saved_equalizer_is_kept_until_the_config_changes() {
+        let before = config("[equalizer]\nenabled = false\npreset = \"rock\"");
+        let state = saved(&before, boosted(), Vec::new());
+        assert_eq!(equalizer_settings(&before, &state), boosted());
+
+        // Config düzenlenince kaydedilen ayar yerine config'deki ayar kullanılır
+        for text in [
+            "[equalizer]\nenabled = true\npreset = \"rock\"",
+            "[equalizer]\nenabled = false\npreset = \"jazz\"",
+        ] {
+            let after = config(text);
+            assert_eq!(
+                equalizer_settings(&after, &state),
+                config_settings(&after).equalizer
+            );
+        }
+
+        // Hiç kayıt yoksa config; config bilgisi olmayan eski kayıt korunur
+        assert_eq!(
+            equalizer_settings(&before, &SavedState::default()),
+            config_settings(&before).equalizer
+        );
+        let old = SavedState {
+            config: None,
+            ..state
+        };
+        assert_eq!(equalizer_settings(&config(""), &old), boosted());
+    }
+
+    #[test]
+    fn saved_effects_are_kept_until_the_config_changes() {
+        let before = config("[[effects]]\nname = \"tone\"\nbass_db = 3.3");
+        let chain = vec![EffectConfig {
+            name: "limiter".to_string(),
+            enabled: true,
+            params: [("ceiling_db".to_string(), -1.0)].into(),
+        }];
+        let state = saved(&before, boosted(), chain.clone());
+        assert_eq!(effect_settings(&before, &state), chain);
+
+        let after = config("[[effects]]\nname = \"tone\"\nbass_db = 2");
+        assert_eq!(effect_settings(&after, &state), after.effects);
+        assert_eq!(
+            effect_settings(&before, &SavedState::default()),
+            before.effects
+        );
     }
 }
@@ -0,0 +1,242 @@
+use rodio::Source;
+use rodio::source::SeekError;
+use serde::Deserialize;
+use std::f32::consts::FRAC_PI_2;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+
+use crate::library::Track;
+
+/// Geçişte sesin açılıp kısılma eğrisi
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum FadeCurve {
+    /// Kazanç doğrusal değişir; ortada ses biraz düşer
+    #[default]
+    Linear,
+    /// Toplam güç sabit kalır; geçiş boyunca ses seviyesi eşit duyulur
+    EqualPower,
+}
+
+impl FadeCurve {
+    /// Geçişin `t` (0.0 - 1.0) anındaki kazanç; `rising` açılan parça için
+    pub fn gain(self, t: f32, rising: bool) -> f32 {
+        let t = t.clamp(0.0, 1.0);
+        let t = if rising { t } else { 1.0 - t };
+        match self {
+            FadeCurve::Linear => t,
+            FadeCurve::EqualPower => (t * FRAC_PI_2).sin(),
+        }
+    }
+}
+
+/// Parçalar arası geçiş ayarları
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Crossfade {
+    /// Geçiş süresi; sıfırsa parçalar boşluksuz art arda çalar
+    pub duration: Duration,
+    pub curve: FadeCurve,
+    /// Aynı albümdeki parçalar arasında geçiş yapılmaz, boşluksuz çalınır
+    pub skip_same_album: bool,
+}
+
+impl Crossfade {
+    /// İki parça arasında geçiş yapılacak mı?
+    pub fn applies(&self, current: &Track, next: &Track) -> bool {
+        if self.duration.is_zero() {
+            return false;
+        }
+        let same_album = album_key(current).is_some_and(|album| Some(album) == album_key(next));
+        !(self.skip_same_album && same_album)
+    }
+}
+
+/// Albüm ve albüm sanatçısı (yoksa sanatçı), küçük harfle
+fn album_key(track: &Track) -> Option<(String, Option<String>)> {
+    let artist = track.album_artist.as_ref().or(track.artist.as_ref());
+    let album = track.album.as_ref()?;
+    Some((album.to_lowercase(), artist.map(|a| a.to_lowercase())))
+}
+
+/// Çalan kaynağa sesin kısılmaya başlamasını bildiren paylaşılan denetim
+#[derive(Debug)]
+pub struct FadeControl {
+    /// İstenen kısma süresi (örnek); sıfırsa kısma yok
+    fade_out: AtomicU64,
+    sample_rate: u64,
+    channels: u64,
+}
+
+impl FadeControl {
+    /// Sesi şu andan itibaren verilen sürede kıs; kaynak kısma bitince sona erer
+    pub fn fade_out(&self, length: Duration) {
+        let frames = (length.as_secs_f64() * self.sample_rate as f64) as u64;
+        self.fade_out
+            .store(frames.max(1) * self.channels, Ordering::Relaxed);
+    }
+}
+
+/// Başında sesi açılan, istenince sesi kısılıp biten kaynak
+pub struct Fade<S> {
+    inner: S,
+    curve: FadeCurve,
+    control: Arc<FadeControl>,
+    played: u64,
+    /// Açılma süresi (örnek)
+    fade_in: u64,
+    /// Kısmanın ilerlediği örnek sayısı ve kısma süresi; atlamada kaldığı yerden sürer
+    fade_out: Option<(u64, u64)>,
+}
+
+impl<S: Source> Fade<S>
+where
+    S::Item: rodio::Sample,
+{
+    pub fn new(inner: S, curve: FadeCurve, fade_in: Duration) -> Fade<S> {
+        let sample_rate = inner.sample_rate() as u64;
+        let channels = inner.channels().max(1) as u64;
+        Fade {
+            curve,
+            control: Arc::new(FadeControl {
+                fade_out: AtomicU64::new(0),
+                sample_rate,
+                channels,
+            }),
+            played: 0,
+            fade_in: (fade_in.as_secs_f64() * sample_rate as f64) as u64 * channels,
+            fade_out: None,
+            inner,
+        }
+    }
+
+    /// Kısmayı başlatmak için paylaşılan denetim
+    pub fn control(&self) -> Arc<FadeControl> {
+        Arc::clone(&self.control)
+    }
+}
+
+impl<S: Source> Iterator for Fade<S>
+where
+    S::Item: rodio::Sample,
+{
+    type Item = S::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<S::Item> {
+        // Kısma kare sınırında başlar, böylece kaynak kare ortasında bitmez
+        if self.fade_out.is_none() && self.played.is_multiple_of(self.control.channels) {
+            let length = self.control.fade_out.load(Ordering::Relaxed);
+            if length > 0 {
+                self.fade_out = Some((0, length));
+            }
+        }
+
+        let mut gain = 1.0;
+        if self.played < self.fade_in {
+            gain *= self
+                .curve
+                .gain(self.played as f32 / self.fade_in as f32, true);
+        }
+        if let Some((elapsed, length)) = self.fade_out {
+            if elapsed >= length {
+                return None;
+            }
+            gain *= self.curve.gain(elapsed as f32 / length as f32, false);
+        }
+
+        let sample = self.inner.next()?;
+        self.played += 1;
+        if let Some((elapsed, _)) = &mut self.fade_out {
+            *elapsed += 1;
+        }
+        Some(if gain < 1.0 {
+            rodio::Sample::amplify(sample, gain)
+        } else {
+            sample
+        })
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.inner.size_hint().1)
+    }
+}
+
+impl<S: Source> Source for Fade<S>
+where
+    S::Item: rodio::Sample,
+{
+    fn current_frame_len(&self) -> Option<usize> {
+        self.inner.current_frame_len()
+    }
+
+    fn channels(&self) -> u16 {
+        self.inner.channels()
+    }
+
+    fn sample_rate(&self) -> u32 {
+        self.inner.sample_rate()
+    }
+
+    fn total_duration(&self) -> Option<Duration> {
+        self.inner.total_duration()
+    }
+
+    fn try_seek(&mut self, pos: Duration) -> Result<(), SeekError> {
+        self.inner.try_seek(pos)?;
+        // Açılma yeni konuma göre sürer; kaynak kanal sırasını koruduğu için kare içindeki yer de korunur
+        let channels = self.control.channels;
+        let frames = (pos.as_secs_f64() * self.control.sample_rate as f64) as u64;
+        self.played = frames * channels + self.played % channels;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rodio::buffer::SamplesBuffer;
+
+    /// Saniyede 100 örneklik, 2 saniyelik sabit mono kaynak
+    fn fade(fade_in: Duration) -> Fade<SamplesBuffer<f32>> {
+        Fade::new(
+            SamplesBuffer::new(1, 100, vec![1.0; 200]),
+            FadeCurve::Linear,
+            fade_in,
+        )
+    }
+
+    fn next_gain(fade: &mut Fade<SamplesBuffer<f32>>) -> f32 {
+        fade.next().unwrap()
+    }
+
+    #[test]
+    fn seek_recomputes_fade_in() {
+        let mut fade = fade(Duration::from_secs(1));
+        assert_eq!(next_gain(&mut fade), 0.0);
+        fade.nth(8);
+        assert!((next_gain(&mut fade) - 0.1).abs() < 1e-6);
+
+        // Açılma bittikten sonrasına atlanınca ses tam açıktır
+        fade.try_seek(Duration::from_millis(1500)).unwrap();
+        assert_eq!(next_gain(&mut fade), 1.0);
+
+        // Açılmanın ortasına dönülünce kazanç konuma göre hesaplanır
+        fade.try_seek(Duration::from_millis(500)).unwrap();
+        assert!((next_gain(&mut fade) - 0.5).abs() < 1e-6);
+    }
+
+    #[test]
+    fn seek_keeps_fade_out_progress() {
+        let mut fade = fade(Duration::ZERO);
+        fade.control().fade_out(Duration::from_secs(1));
+        fade.nth(49);
+        assert!((next_gain(&mut fade) - 0.5).abs() < 1e-6);
+
+        // Kısma baştan başlamaz, kaldığı yerden sürer ve zamanında biter
+        fade.try_seek(Duration::ZERO).unwrap();
+        assert!((next_gain(&mut fade) - 0.49).abs() < 1e-6);
+        assert_eq!(fade.count(), 48);
+    }
+}
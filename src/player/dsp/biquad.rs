@@ -0,0 +1,101 @@
+use std::f32::consts::{PI, SQRT_2};
+
+/// İkinci dereceden süzgecin katsayıları; a0 ile bölünmüş
+#[derive(Debug, Clone, Copy)]
+pub struct Coefficients {
+    b0: f32,
+    b1: f32,
+    b2: f32,
+    a1: f32,
+    a2: f32,
+}
+
+impl Coefficients {
+    /// Merkez frekansta verilen kazancı uygulayan tepe (peaking) süzgeci; kazanç sıfırsa
+    /// veya frekans örnekleme hızının yarısını aşıyorsa `None`
+    pub fn peaking(freq: f32, gain_db: f32, q: f32, sample_rate: f32) -> Option<Coefficients> {
+        if gain_db == 0.0 || freq >= sample_rate / 2.0 {
+            return None;
+        }
+        let a = 10f32.powf(gain_db / 40.0);
+        let w0 = 2.0 * PI * freq / sample_rate;
+        let alpha = w0.sin() / (2.0 * q);
+        let cos = w0.cos();
+        Some(Coefficients::normalized(
+            [1.0 + alpha * a, -2.0 * cos, 1.0 - alpha * a],
+            [1.0 + alpha / a, -2.0 * cos, 1.0 - alpha / a],
+        ))
+    }
+
+    /// Köşe frekansının altını yükselten/kısan raf (low shelf) süzgeci
+    pub fn low_shelf(freq: f32, gain_db: f32, sample_rate: f32) -> Option<Coefficients> {
+        let (a, cos, beta) = shelf_terms(freq, gain_db, sample_rate)?;
+        Some(Coefficients::normalized(
+            [
+                a * ((a + 1.0) - (a - 1.0) * cos + beta),
+                2.0 * a * ((a - 1.0) - (a + 1.0) * cos),
+                a * ((a + 1.0) - (a - 1.0) * cos - beta),
+            ],
+            [
+                (a + 1.0) + (a - 1.0) * cos + beta,
+                -2.0 * ((a - 1.0) + (a + 1.0) * cos),
+                (a + 1.0) + (a - 1.0) * cos - beta,
+            ],
+        ))
+    }
+
+    /// Köşe frekansının üstünü yükselten/kısan raf (high shelf) süzgeci
+    pub fn high_shelf(freq: f32, gain_db: f32, sample_rate: f32) -> Option<Coefficients> {
+        let (a, cos, beta) = shelf_terms(freq, gain_db, sample_rate)?;
+        Some(Coefficients::normalized(
+            [
+                a * ((a + 1.0) + (a - 1.0) * cos + beta),
+                -2.0 * a * ((a - 1.0) + (a + 1.0) * cos),
+                a * ((a + 1.0) + (a - 1.0) * cos - beta),
+            ],
+            [
+                (a + 1.0) - (a - 1.0) * cos + beta,
+                2.0 * ((a - 1.0) - (a + 1.0) * cos),
+                (a + 1.0) - (a - 1.0) * cos - beta,
+            ],
+        ))
+    }
+
+    fn normalized(b: [f32; 3], a: [f32; 3]) -> Coefficients {
+        Coefficients {
+            b0: b[0] / a[0],
+            b1: b[1] / a[0],
+            b2: b[2] / a[0],
+            a1: a[1] / a[0],
+            a2: a[2] / a[0],
+        }
+    }
+}
+
+/// Raf süzgeçlerinin ortak terimleri (eğim 1): A, cos(w0) ve 2·√A·alpha
+fn shelf_terms(freq: f32, gain_db: f32, sample_rate: f32) -> Option<(f32, f32, f32)> {
+    if gain_db == 0.0 || freq >= sample_rate / 2.0 {
+        return None;
+    }
+    let a = 10f32.powf(gain_db / 40.0);
+    let w0 = 2.0 * PI * freq / sample_rate;
+    let alpha = w0.sin() / 2.0 * SQRT_2;
+    Some((a, w0.cos(), 2.0 * a.sqrt() * alpha))
+}
+
+/// Süzgecin kanal başına geçmişi (transposed direct form II)
+#[derive(Debug, Clone, Copy, Default)]
+pub struct FilterState {
+    z1: f32,
+    z2: f32,
+}
+
+impl FilterState {
+    #[inline]
+    pub fn process(&mut self, c: &Coefficients, x: f32) -> f32 {
+        let y = c.b0 * x + self.z1;
+        self.z1 = c.b1 * x - c.a1 * y + self.z2;
+        self.z2 = c.b2 * x - c.a2 * y;
+        y
+    }
+}
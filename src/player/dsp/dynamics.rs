@@ -0,0 +1,293 @@
+use super::{EffectSpec, ParamSpec, Processor};
+
+/// Eşiği aşan seviyeyi orana göre bastıran kompresör
+pub const COMPRESSOR: EffectSpec = EffectSpec {
+    name: "compressor",
+    label: "Compressor",
+    params: &[
+        ParamSpec {
+            name: "threshold_db",
+            label: "Threshold",
+            min: -60.0,
+            max: 0.0,
+            step: 1.0,
+            default: -18.0,
+            unit: "dB",
+        },
+        ParamSpec {
+            name: "ratio",
+            label: "Ratio",
+            min: 1.0,
+            max: 20.0,
+            step: 0.5,
+            default: 4.0,
+            unit: ":1",
+        },
+        ParamSpec {
+            name: "attack_ms",
+            label: "Attack",
+            min: 1.0,
+            max: 200.0,
+            step: 1.0,
+            default: 10.0,
+            unit: "ms",
+        },
+        ParamSpec {
+            name: "release_ms",
+            label: "Release",
+            min: 10.0,
+            max: 2000.0,
+            step: 10.0,
+            default: 200.0,
+            unit: "ms",
+        },
+        ParamSpec {
+            name: "makeup_db",
+            label: "Makeup gain",
+            min: 0.0,
+            max: 24.0,
+            step: 1.0,
+            default: 0.0,
+            unit: "dB",
+        },
+    ],
+    build: || Box::new(Compressor::default()),
+};
+
+/// Tepeleri tavanın altında tutan limitleyici (anında devreye girer)
+pub const LIMITER: EffectSpec = EffectSpec {
+    name: "limiter",
+    label: "Limiter",
+    params: &[
+        ParamSpec {
+            name: "ceiling_db",
+            label: "Ceiling",
+            min: -24.0,
+            max: 0.0,
+            step: 0.5,
+            default: -1.0,
+            unit: "dB",
+        },
+        ParamSpec {
+            name: "release_ms",
+            label: "Release",
+            min: 10.0,
+            max: 2000.0,
+            step: 10.0,
+            default: 100.0,
+            unit: "ms",
+        },
+    ],
+    build: || Box::new(Limiter::default()),
+};
+
+/// Zarfın bir örnekte hedefe yaklaşma katsayısı
+fn smoothing(time_ms: f32, sample_rate: f32) -> f32 {
+    (-1000.0 / (time_ms * sample_rate)).exp()
+}
+
+fn db_to_linear(db: f32) -> f32 {
+    10f32.powf(db / 20.0)
+}
+
+/// Kanalların en yüksek tepesini izleyen zarf; kanallar aynı kazancı alır
+#[derive(Debug, Default)]
+struct Envelope {
+    level: f32,
+    attack: f32,
+    release: f32,
+}
+
+impl Envelope {
+    #[inline]
+    fn follow(&mut self, frame: &[f32]) -> f32 {
+        let peak = frame
+            .iter()
+            .fold(0.0f32, |peak, sample| peak.max(sample.abs()));
+        let coefficient = if peak > self.level {
+            self.attack
+        } else {
+            self.release
+        };
+        self.level = coefficient * self.level + (1.0 - coefficient) * peak;
+        self.level
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct Compressor {
+    sample_rate: f32,
+    channels: usize,
+    /// Zaman katsayılarının hesaplandığı süreler
+    times: Option<(f32, f32)>,
+    envelope: Envelope,
+}
+
+impl Processor for Compressor {
+    fn prepare(&mut self, sample_rate: u32, channels: usize) {
+        self.sample_rate = sample_rate as f32;
+        self.channels = channels;
+        self.times = None;
+    }
+
+    fn reset(&mut self) {
+        self.envelope.level = 0.0;
+    }
+
+    fn process(&mut self, params: &[f32], buffer: &mut [f32]) {
+        let [threshold, ratio, attack, release, makeup] = params else {
+            return;
+        };
+        if self.channels == 0 {
+            return;
+        }
+        if self.times != Some((*attack, *release)) {
+            self.times = Some((*attack, *release));
+            self.envelope.attack = smoothing(*attack, self.sample_rate);
+            self.envelope.release = smoothing(*release, self.sample_rate);
+        }
+
+        let slope = 1.0 - 1.0 / ratio;
+        for frame in buffer.chunks_exact_mut(self.channels) {
+            let level = self.envelope.follow(frame);
+            let over = 20.0 * level.max(1e-9).log10() - threshold;
+            let reduction = if over > 0.0 { over * slope } else { 0.0 };
+            let gain = db_to_linear(makeup - reduction);
+            for sample in frame {
+                *sample *= gain;
+            }
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct Limiter {
+    sample_rate: f32,
+    channels: usize,
+    release: Option<f32>,
+    envelope: Envelope,
+}
+
+impl Processor for Limiter {
+    fn prepare(&mut self, sample_rate: u32, channels: usize) {
+        self.sample_rate = sample_rate as f32;
+        self.channels = channels;
+        self.release = None;
+    }
+
+    fn reset(&mut self) {
+        self.envelope.level = 0.0;
+    }
+
+    fn process(&mut self, params: &[f32], buffer: &mut [f32]) {
+        let [ceiling, release] = params else {
+            return;
+        };
+        if self.channels == 0 {
+            return;
+        }
+        if self.release != Some(*release) {
+            self.release = Some(*release);
+            self.envelope.release = smoothing(*release, self.sample_rate);
+        }
+
+        // Atak sıfır: zarf tepeye anında çıkar, böylece çıkış tavanı aşmaz
+        let ceiling = db_to_linear(*ceiling);
+        for frame in buffer.chunks_exact_mut(self.channels) {
+            let level = self.envelope.follow(frame);
+            if level > ceiling {
+                let gain = ceiling / level;
+                for sample in frame {
+                    *sample *= gain;
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RATE: u32 = 44_100;
+
+    fn linear_to_db(linear: f32) -> f32 {
+        20.0 * linear.log10()
+    }
+
+    fn peak(buffer: &[f32]) -> f32 {
+        buffer
+            .iter()
+            .fold(0.0f32, |peak, sample| peak.max(sample.abs()))
+    }
+
+    /// Verilen genlikte, yarım saniyelik stereo kare dalga
+    fn square(amplitude: f32) -> Vec<f32> {
+        (0..RATE as usize / 2)
+            .flat_map(|i| {
+                let sample = if i % 50 < 25 { amplitude } else { -amplitude };
+                [sample, sample * 0.5]
+            })
+            .collect()
+    }
+
+    #[test]
+    fn compressor_pulls_peaks_towards_threshold() {
+        let mut compressor = Compressor::default();
+        compressor.prepare(RATE, 2);
+        let (threshold, ratio) = (-18.0, 20.0);
+        let mut buffer = square(0.5);
+        compressor.process(&[threshold, ratio, 10.0, 200.0, 0.0], &mut buffer);
+
+        // Atak geçtikten sonra tepeler eşiği en çok aşımın oranda biri kadar geçer
+        let over = linear_to_db(0.5) - threshold;
+        let settled = peak(&buffer[RATE as usize / 5..]);
+        assert!(linear_to_db(settled) <= threshold + over / ratio + 0.1);
+        assert!(linear_to_db(settled) >= threshold);
+        // Kazanç hiçbir yerde artmaz
+        assert!(peak(&buffer) <= 0.5);
+    }
+
+    #[test]
+    fn compressor_leaves_quiet_signal_alone() {
+        let mut compressor = Compressor::default();
+        compressor.prepare(RATE, 2);
+        let mut buffer = square(0.1);
+        compressor.process(&[-18.0, 4.0, 10.0, 200.0, 0.0], &mut buffer);
+        assert_eq!(buffer, square(0.1));
+
+        // Kazanç telafisi eşiğin altında da uygulanır
+        let mut buffer = square(0.1);
+        compressor.process(&[-18.0, 4.0, 10.0, 200.0, 6.0], &mut buffer);
+        assert!((peak(&buffer) - 0.1 * db_to_linear(6.0)).abs() < 1e-6);
+    }
+
+    #[test]
+    fn limiter_never_exceeds_ceiling() {
+        let mut limiter = Limiter::default();
+        limiter.prepare(RATE, 2);
+        let ceiling_db = -1.0;
+        let ceiling = db_to_linear(ceiling_db);
+
+        // Sessizlikle ayrılmış, giderek yükselen patlamalar: her biri zarf sönmüşken gelir
+        let mut buffer: Vec<f32> = (0..8)
+            .flat_map(|burst| {
+                let amplitude = 0.5 + burst as f32 * 0.5;
+                let mut block = square(amplitude);
+                block.truncate(2_000);
+                block.extend(std::iter::repeat_n(0.0, 20_000));
+                block
+            })
+            .collect();
+        let input_peak = peak(&buffer);
+        limiter.process(&[ceiling_db, 100.0], &mut buffer);
+        assert!(input_peak > 1.0);
+        assert!(peak(&buffer) <= ceiling * (1.0 + 1e-6));
+
+        // Tavanın altındaki sinyal değişmez
+        limiter.reset();
+        let mut buffer = square(0.5);
+        limiter.process(&[ceiling_db, 100.0], &mut buffer);
+        assert_eq!(buffer, square(0.5));
+    }
+}
@@ -0,0 +1,579 @@
+pub mod biquad;
+pub mod dynamics;
+pub mod stereo;
+pub mod tone;
+
+use rodio::Source;
+use rodio::source::SeekError;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::sync::atomic::{AtomicBool, AtomicU32, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+/// Zincirin tek seferde işlediği en çok kare sayısı
+const BLOCK_FRAMES: usize = 512;
+
+/// Çözülmüş sesi işleyen efekt; ses cihazı olmadan örnek tamponlarıyla da çalıştırılabilir
+pub trait Processor: Send {
+    /// İşlemeden önce ve örnekleme hızı veya kanal sayısı değişince çağrılır
+    fn prepare(&mut self, _sample_rate: u32, _channels: usize) {}
+
+    /// Geçmişi (süzgeç durumu, zarf) sıfırla; sarmada ve efekt yeniden açılınca çağrılır
+    fn reset(&mut self) {}
+
+    /// Kanalları iç içe (interleaved) tam karelerden oluşan tamponu yerinde işle;
+    /// `params` efektin parametreleriyle aynı sıradadır
+    fn process(&mut self, params: &[f32], buffer: &mut [f32]);
+}
+
+/// Efekt parametresinin tanımı
+#[derive(Debug)]
+pub struct ParamSpec {
+    /// Config'deki anahtar
+    pub name: &'static str,
+    pub label: &'static str,
+    pub min: f32,
+    pub max: f32,
+    /// Arayüzdeki ayar adımı
+    pub step: f32,
+    pub default: f32,
+    pub unit: &'static str,
+}
+
+impl ParamSpec {
+    /// Değeri birimiyle yaz ("-18 dB", "4:1")
+    pub fn format(&self, value: f32) -> String {
+        let value = if self.step.fract() == 0.0 {
+            format!("{:.0}", value)
+        } else {
+            format!("{:.1}", value)
+        };
+        match self.unit {
+            "" => value,
+            ":1" | "%" => format!("{}{}", value, self.unit),
+            unit => format!("{} {}", value, unit),
+        }
+    }
+}
+
+/// Zincire eklenebilecek efektin tanımı
+pub struct EffectSpec {
+    /// Config'deki ad
+    pub name: &'static str,
+    pub label: &'static str,
+    pub params: &'static [ParamSpec],
+    /// Çalan her kaynak için ayrı bir işlemci oluşturur
+    pub build: fn() -> Box<dyn Processor>,
+}
+
+impl std::fmt::Debug for EffectSpec {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("EffectSpec")
+            .field("name", &self.name)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Desteklenen efektler; zincirin varsayılan sırası
+pub const EFFECTS: &[EffectSpec] = &[
+    tone::TONE,
+    stereo::BALANCE,
+    stereo::WIDTH,
+    stereo::MONO,
+    dynamics::COMPRESSOR,
+    dynamics::LIMITER,
+];
+
+/// Adına göre efekt tanımı
+pub fn spec(name: &str) -> Option<&'static EffectSpec> {
+    EFFECTS.iter().find(|spec| spec.name == name)
+}
+
+/// Config'de ([[effects]]) veya kayıtlı durumda zincirdeki bir efekt
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EffectConfig {
+    pub name: String,
+    #[serde(default = "enabled_by_default")]
+    pub enabled: bool,
+    /// Parametre değerleri; verilmeyenler varsayılan değeri alır
+    #[serde(flatten)]
+    pub params: BTreeMap<String, f32>,
+}
+
+fn enabled_by_default() -> bool {
+    true
+}
+
+impl EffectConfig {
+    /// Efekt ve parametreleri tanımlı ve sınırlar içinde mi?
+    pub fn validate(&self) -> Result<(), String> {
+        let spec = spec(&self.name).ok_or_else(|| {
+            let names: Vec<&str> = EFFECTS.iter().map(|spec| spec.name).collect();
+            format!(
+                "unknown effect '{}' (expected one of: {})",
+                self.name,
+                names.join(", ")
+            )
+        })?;
+        for (name, &value) in &self.params {
+            let param = spec
+                .params
+                .iter()
+                .find(|param| param.name == name)
+                .ok_or_else(|| format!("'{}' has no parameter '{}'", self.name, name))?;
+            if !(param.min..=param.max).contains(&value) {
+                return Err(format!(
+                    "{} must be between {} and {} (got {})",
+                    name, param.min, param.max, value
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Zincirdeki efektin çalan kaynaklarla paylaşılan ayarları
+#[derive(Debug)]
+pub struct Effect {
+    spec: &'static EffectSpec,
+    enabled: AtomicBool,
+    /// f32 bitleri olarak parametreler
+    params: Vec<AtomicU32>,
+}
+
+impl Effect {
+    fn new(spec: &'static EffectSpec) -> Effect {
+        Effect {
+            spec,
+            enabled: AtomicBool::new(false),
+            params: spec
+                .params
+                .iter()
+                .map(|param| AtomicU32::new(param.default.to_bits()))
+                .collect(),
+        }
+    }
+
+    pub fn spec(&self) -> &'static EffectSpec {
+        self.spec
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.enabled.load(Ordering::Relaxed)
+    }
+
+    pub fn set_enabled(&self, enabled: bool) {
+        self.enabled.store(enabled, Ordering::Relaxed);
+    }
+
+    pub fn param(&self, index: usize) -> f32 {
+        f32::from_bits(self.params[index].load(Ordering::Relaxed))
+    }
+
+    /// Parametreyi ayarla (sınırlar içine çekilir)
+    pub fn set_param(&self, index: usize, value: f32) {
+        let spec = &self.spec.params[index];
+        let value = value.clamp(spec.min, spec.max);
+        self.params[index].store(value.to_bits(), Ordering::Relaxed);
+    }
+
+    /// Tüm parametreleri varsayılana döndür
+    pub fn reset_params(&self) {
+        for (index, param) in self.spec.params.iter().enumerate() {
+            self.set_param(index, param.default);
+        }
+    }
+
+    /// Parametreleri tampona oku (ses thread'inde bellek ayırmadan)
+    fn load_params(&self, values: &mut [f32]) {
+        for (value, param) in values.iter_mut().zip(&self.params) {
+            *value = f32::from_bits(param.load(Ordering::Relaxed));
+        }
+    }
+
+    fn config(&self) -> EffectConfig {
+        EffectConfig {
+            name: self.spec.name.to_string(),
+            enabled: self.is_enabled(),
+            params: self
+                .spec
+                .params
+                .iter()
+                .enumerate()
+                .map(|(index, param)| (param.name.to_string(), self.param(index)))
+                .collect(),
+        }
+    }
+}
+
+/// Çalan kaynaklarla paylaşılan efekt zinciri; açma/kapama ve parametreler çalan parçada
+/// hemen duyulur
+#[derive(Debug)]
+pub struct Effects {
+    chain: Mutex<Vec<Arc<Effect>>>,
+    /// Sıra her değiştiğinde artar; kaynaklar zinciri yalnızca değişince yeniden kurar
+    version: AtomicU64,
+}
+
+impl Default for Effects {
+    fn default() -> Self {
+        Effects {
+            chain: Mutex::new(
+                EFFECTS
+                    .iter()
+                    .map(|spec| Arc::new(Effect::new(spec)))
+                    .collect(),
+            ),
+            version: AtomicU64::new(0),
+        }
+    }
+}
+
+impl Effects {
+    /// Zincirdeki efektler, sırasıyla
+    pub fn chain(&self) -> Vec<Arc<Effect>> {
+        self.chain.lock().unwrap().clone()
+    }
+
+    /// Zinciri verilen sıra ve ayarlarla kur; listede olmayan efektler kapalı ve varsayılan
+    /// ayarlarla sona eklenir, tanınmayan efektler ve parametreler atlanır
+    pub fn configure(&self, configs: &[EffectConfig]) {
+        let mut chain: Vec<Arc<Effect>> = Vec::new();
+        for config in configs {
+            let Some(spec) = spec(&config.name) else {
+                continue;
+            };
+            if chain.iter().any(|effect| effect.spec.name == spec.name) {
+                continue;
+            }
+            let effect = Effect::new(spec);
+            effect.set_enabled(config.enabled);
+            for (index, param) in spec.params.iter().enumerate() {
+                if let Some(&value) = config.params.get(param.name) {
+                    effect.set_param(index, value);
+                }
+            }
+            chain.push(Arc::new(effect));
+        }
+        for spec in EFFECTS {
+            if !chain.iter().any(|effect| effect.spec.name == spec.name) {
+                chain.push(Arc::new(Effect::new(spec)));
+            }
+        }
+        *self.chain.lock().unwrap() = chain;
+        self.changed();
+    }
+
+    /// Zincirin kaydedilebilir hali
+    pub fn configs(&self) -> Vec<EffectConfig> {
+        self.chain
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|effect| effect.config())
+            .collect()
+    }
+
+    /// Efekti zincirde yukarı (-1) veya aşağı (+1) taşı
+    pub fn move_effect(&self, index: usize, offset: isize) -> bool {
+        let mut chain = self.chain.lock().unwrap();
+        let target = index.saturating_add_signed(offset);
+        if index >= chain.len() || target >= chain.len() || target == index {
+            return false;
+        }
+        let effect = chain.remove(index);
+        chain.insert(target, effect);
+        drop(chain);
+        self.changed();
+        true
+    }
+
+    fn changed(&self) {
+        self.version.fetch_add(1, Ordering::Release);
+    }
+}
+
+/// Zincirdeki bir efektin kaynağa özel işlemcisi
+struct Stage {
+    effect: Arc<Effect>,
+    processor: Box<dyn Processor>,
+    params: Vec<f32>,
+    /// Önceki blokta açık mıydı? (yeniden açılınca geçmiş sıfırlanır)
+    enabled: bool,
+}
+
+/// Örnekleri efekt zincirinden bloklar halinde geçiren kaynak
+pub struct Processed<S> {
+    inner: S,
+    effects: Arc<Effects>,
+    /// Aşamaların kurulduğu zincir sürümü
+    version: u64,
+    stages: Vec<Stage>,
+    buffer: Vec<f32>,
+    position: usize,
+    /// Tampondaki örneklerin biçimi; işlemciler bu biçime göre hazırlanmıştır
+    channels: u16,
+    sample_rate: u32,
+}
+
+impl<S: Source<Item = f32>> Processed<S> {
+    pub fn new(inner: S, effects: Arc<Effects>) -> Processed<S> {
+        let channels = inner.channels().max(1);
+        let sample_rate = inner.sample_rate();
+        let mut source = Processed {
+            inner,
+            effects,
+            version: 0,
+            stages: Vec::new(),
+            buffer: Vec::with_capacity(BLOCK_FRAMES * channels as usize),
+            position: 0,
+            channels,
+            sample_rate,
+        };
+        source.sync();
+        source
+    }
+
+    /// Aşamaları zincirin güncel sırasına getir; var olan işlemciler korunur.
+    /// Zincir o an değiştiriliyorsa bir sonraki bloğa bırakılır
+    fn sync(&mut self) {
+        let version = self.effects.version.load(Ordering::Acquire);
+        let Ok(chain) = self.effects.chain.try_lock() else {
+            return;
+        };
+        let mut stages = std::mem::take(&mut self.stages);
+        self.stages = chain
+            .iter()
+            .map(|effect| {
+                match stages
+                    .iter()
+                    .position(|stage| Arc::ptr_eq(&stage.effect, effect))
+                {
+                    Some(i) => stages.swap_remove(i),
+                    None => {
+                        let mut processor = (effect.spec.build)();
+                        processor.prepare(self.sample_rate, self.channels as usize);
+                        Stage {
+                            effect: Arc::clone(effect),
+                            processor,
+                            params: vec![0.0; effect.params.len()],
+                            enabled: false,
+                        }
+                    }
+                }
+            })
+            .collect();
+        self.version = version;
+    }
+
+    /// Sonraki bloğu oku ve açık efektlerden geçir; kaynak bittiyse `false`
+    fn fill(&mut self) -> bool {
+        if self.effects.version.load(Ordering::Acquire) != self.version {
+            self.sync();
+        }
+
+        let channels = self.inner.channels().max(1);
+        let sample_rate = self.inner.sample_rate();
+        if channels != self.channels || sample_rate != self.sample_rate {
+            self.channels = channels;
+            self.sample_rate = sample_rate;
+            for stage in &mut self.stages {
+                stage.processor.prepare(sample_rate, channels as usize);
+                stage.processor.reset();
+            }
+        }
+
+        // Blok, kaynağın biçimi değişebilecek yeri aşmaz
+        let mut len = BLOCK_FRAMES * channels as usize;
+        if let Some(frame_len) = self.inner.current_frame_len()
+            && frame_len > 0
+        {
+            len = len.min(frame_len);
+        }
+        self.buffer.clear();
+        self.buffer.extend(self.inner.by_ref().take(len));
+        self.position = 0;
+        if self.buffer.is_empty() {
+            return false;
+        }
+
+        // Kaynağın sonundaki yarım kare işlenmez
+        let whole = self.buffer.len() - self.buffer.len() % channels as usize;
+        let buffer = &mut self.buffer[..whole];
+        for stage in &mut self.stages {
+            if !stage.effect.is_enabled() {
+                stage.enabled = false;
+                continue;
+            }
+            // Kapalıyken kalan geçmiş yeniden açılınca tıkırtı yapmasın
+            if !stage.enabled {
+                stage.processor.reset();
+                stage.enabled = true;
+            }
+            stage.effect.load_params(&mut stage.params);
+            stage.processor.process(&stage.params, buffer);
+        }
+        true
+    }
+}
+
+impl<S: Source<Item = f32>> Iterator for Processed<S> {
+    type Item = f32;
+
+    #[inline]
+    fn next(&mut self) -> Option<f32> {
+        if self.position == self.buffer.len() && !self.fill() {
+            return None;
+        }
+        let sample = self.buffer[self.position];
+        self.position += 1;
+        Some(sample)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let buffered = self.buffer.len() - self.position;
+        let (lower, upper) = self.inner.size_hint();
+        (
+            lower.saturating_add(buffered),
+            upper.and_then(|upper| upper.checked_add(buffered)),
+        )
+    }
+}
+
+impl<S: Source<Item = f32>> Source for Processed<S> {
+    fn current_frame_len(&self) -> Option<usize> {
+        match self.buffer.len() - self.position {
+            0 => self.inner.current_frame_len(),
+            buffered => Some(buffered),
+        }
+    }
+
+    fn channels(&self) -> u16 {
+        if self.position < self.buffer.len() {
+            self.channels
+        } else {
+            self.inner.channels()
+        }
+    }
+
+    fn sample_rate(&self) -> u32 {
+        if self.position < self.buffer.len() {
+            self.sample_rate
+        } else {
+            self.inner.sample_rate()
+        }
+    }
+
+    fn total_duration(&self) -> Option<Duration> {
+        self.inner.total_duration()
+    }
+
+    fn try_seek(&mut self, pos: Duration) -> Result<(), SeekError> {
+        self.inner.try_seek(pos)?;
+        self.buffer.clear();
+        self.position = 0;
+        for stage in &mut self.stages {
+            stage.processor.reset();
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rodio::buffer::SamplesBuffer;
+
+    /// Dört blokluk, her karesi (0.8, 0.2) olan stereo kaynak
+    fn source() -> SamplesBuffer<f32> {
+        SamplesBuffer::new(2, 44_100, [0.8, 0.2].repeat(4 * BLOCK_FRAMES))
+    }
+
+    fn config(name: &str, enabled: bool, params: &[(&str, f32)]) -> EffectConfig {
+        EffectConfig {
+            name: name.to_string(),
+            enabled,
+            params: params
+                .iter()
+                .map(|&(name, value)| (name.to_string(), value))
+                .collect(),
+        }
+    }
+
+    fn effects(configs: &[EffectConfig]) -> Arc<Effects> {
+        let effects = Arc::new(Effects::default());
+        effects.configure(configs);
+        effects
+    }
+
+    /// Tüm karelerin aynı olduğunu doğrula ve ilk kareyi döndür
+    fn only_frame(samples: &[f32]) -> [f32; 2] {
+        let first = [samples[0], samples[1]];
+        for frame in samples.chunks_exact(2) {
+            assert_eq!(frame, first);
+        }
+        first
+    }
+
+    #[test]
+    fn chain_runs_in_order() {
+        let balance = config("balance", true, &[("pan", 100.0)]);
+        let mono = config("mono", true, &[]);
+
+        // Önce sol kanal kısılır, sonra kanallar ortalanır
+        let processed = Processed::new(source(), effects(&[balance.clone(), mono.clone()]));
+        let samples: Vec<f32> = processed.collect();
+        assert_eq!(samples.len(), 8 * BLOCK_FRAMES);
+        assert_eq!(only_frame(&samples), [0.1, 0.1]);
+
+        // Önce ortalanır, sonra sol kanal kısılır
+        let processed = Processed::new(source(), effects(&[mono, balance]));
+        let samples: Vec<f32> = processed.collect();
+        assert_eq!(only_frame(&samples), [0.0, 0.5]);
+    }
+
+    #[test]
+    fn reordering_applies_from_next_block() {
+        let effects = effects(&[
+            config("balance", true, &[("pan", 100.0)]),
+            config("mono", true, &[]),
+        ]);
+        let mut processed = Processed::new(source(), Arc::clone(&effects));
+        let first: Vec<f32> = processed.by_ref().take(2 * BLOCK_FRAMES).collect();
+        assert_eq!(only_frame(&first), [0.1, 0.1]);
+
+        assert!(effects.move_effect(0, 1));
+        assert_eq!(effects.chain()[0].spec().name, "mono");
+        let rest: Vec<f32> = processed.collect();
+        assert_eq!(rest.len(), 6 * BLOCK_FRAMES);
+        assert_eq!(only_frame(&rest), [0.0, 0.5]);
+    }
+
+    #[test]
+    fn disabled_effects_are_skipped() {
+        // Listede olmayan efektler kapalı olarak sona eklenir
+        let effects = effects(&[
+            config("balance", false, &[("pan", 100.0)]),
+            config("width", true, &[("width", 0.0)]),
+        ]);
+        let chain = effects.chain();
+        assert_eq!(chain.len(), EFFECTS.len());
+        assert!(chain[2..].iter().all(|effect| !effect.is_enabled()));
+
+        let mut processed = Processed::new(source(), Arc::clone(&effects));
+        let first: Vec<f32> = processed.by_ref().take(2 * BLOCK_FRAMES).collect();
+        assert_eq!(only_frame(&first), [0.5, 0.5]);
+
+        // Açılan efekt sonraki blokta devreye girer
+        chain[0].set_enabled(true);
+        let second: Vec<f32> = processed.by_ref().take(2 * BLOCK_FRAMES).collect();
+        assert_eq!(only_frame(&second), [0.1, 0.1]);
+
+        chain[0].set_enabled(false);
+        chain[1].set_enabled(false);
+        let rest: Vec<f32> = processed.collect();
+        assert_eq!(only_frame(&rest), [0.8, 0.2]);
+    }
+}
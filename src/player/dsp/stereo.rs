@@ -0,0 +1,198 @@
+use super::{EffectSpec, ParamSpec, Processor};
+
+/// Sol/sağ denge; karşı kanal kısılarak ses bir yana kaydırılır (yalnızca stereo)
+pub const BALANCE: EffectSpec = EffectSpec {
+    name: "balance",
+    label: "Balance",
+    params: &[ParamSpec {
+        name: "pan",
+        label: "Pan (L-/R+)",
+        min: -100.0,
+        max: 100.0,
+        step: 10.0,
+        default: 0.0,
+        unit: "%",
+    }],
+    build: || Box::new(Balance::default()),
+};
+
+/// Stereo genişliği: 0 mono, 100 özgün, 200 iki kat geniş (yalnızca stereo); 100'ün üstünde
+/// kanallar taşmasın diye ses kısılır
+pub const WIDTH: EffectSpec = EffectSpec {
+    name: "width",
+    label: "Stereo width",
+    params: &[ParamSpec {
+        name: "width",
+        label: "Width",
+        min: 0.0,
+        max: 200.0,
+        step: 10.0,
+        default: 100.0,
+        unit: "%",
+    }],
+    build: || Box::new(Width::default()),
+};
+
+/// Tüm kanalları ortalayarak tek kanala indir
+pub const MONO: EffectSpec = EffectSpec {
+    name: "mono",
+    label: "Mono downmix",
+    params: &[],
+    build: || Box::new(Mono::default()),
+};
+
+#[derive(Debug, Default)]
+pub struct Balance {
+    channels: usize,
+}
+
+impl Processor for Balance {
+    fn prepare(&mut self, _sample_rate: u32, channels: usize) {
+        self.channels = channels;
+    }
+
+    fn process(&mut self, params: &[f32], buffer: &mut [f32]) {
+        let pan = params[0] / 100.0;
+        if self.channels != 2 || pan == 0.0 {
+            return;
+        }
+        let left = (1.0 - pan).min(1.0);
+        let right = (1.0 + pan).min(1.0);
+        for frame in buffer.chunks_exact_mut(2) {
+            frame[0] *= left;
+            frame[1] *= right;
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct Width {
+    channels: usize,
+}
+
+impl Processor for Width {
+    fn prepare(&mut self, _sample_rate: u32, channels: usize) {
+        self.channels = channels;
+    }
+
+    fn process(&mut self, params: &[f32], buffer: &mut [f32]) {
+        let width = params[0] / 100.0;
+        if self.channels != 2 || width == 1.0 {
+            return;
+        }
+        // Orta (mid) ve yan (side) sinyaller; genişlik yan sinyali ölçekler. Genişletirken
+        // bir kanal en çok `width` katına çıkabilir, bu yüzden her ikisi de o kadar kısılır
+        let gain = 1.0 / width.max(1.0);
+        for frame in buffer.chunks_exact_mut(2) {
+            let mid = (frame[0] + frame[1]) / 2.0 * gain;
+            let side = (frame[0] - frame[1]) / 2.0 * width * gain;
+            frame[0] = mid + side;
+            frame[1] = mid - side;
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct Mono {
+    channels: usize,
+}
+
+impl Processor for Mono {
+    fn prepare(&mut self, _sample_rate: u32, channels: usize) {
+        self.channels = channels;
+    }
+
+    fn process(&mut self, _params: &[f32], buffer: &mut [f32]) {
+        if self.channels < 2 {
+            return;
+        }
+        for frame in buffer.chunks_exact_mut(self.channels) {
+            let mean = frame.iter().sum::<f32>() / self.channels as f32;
+            frame.fill(mean);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Tam ölçekli, kanalları birbirinden farklı stereo kareler
+    fn frames() -> Vec<f32> {
+        vec![
+            1.0, 1.0, 1.0, -1.0, -1.0, 1.0, 1.0, 0.0, 0.0, -1.0, 0.5, -0.25, -0.8, 0.3,
+        ]
+    }
+
+    fn run(processor: &mut dyn Processor, channels: usize, params: &[f32], buffer: &mut [f32]) {
+        processor.prepare(44_100, channels);
+        processor.process(params, buffer);
+    }
+
+    fn assert_in_range(buffer: &[f32], what: &str) {
+        for &sample in buffer {
+            assert!(sample.abs() <= 1.0 + 1e-6, "{}: {}", what, sample);
+        }
+    }
+
+    #[test]
+    fn balance_keeps_channels_in_range() {
+        for pan in (-100..=100).step_by(10) {
+            let mut buffer = frames();
+            run(&mut Balance::default(), 2, &[pan as f32], &mut buffer);
+            assert_in_range(&buffer, &format!("pan {}", pan));
+        }
+
+        // Yalnızca karşı kanal kısılır
+        let mut buffer = vec![0.8, -0.6];
+        run(&mut Balance::default(), 2, &[50.0], &mut buffer);
+        assert_eq!(buffer, vec![0.4, -0.6]);
+        run(&mut Balance::default(), 2, &[-100.0], &mut buffer);
+        assert_eq!(buffer, vec![0.4, 0.0]);
+
+        // Stereo olmayan kaynaklar değişmez
+        let mut buffer = frames();
+        run(&mut Balance::default(), 1, &[100.0], &mut buffer);
+        assert_eq!(buffer, frames());
+    }
+
+    #[test]
+    fn width_keeps_channels_in_range() {
+        for width in (0..=200).step_by(10) {
+            let mut buffer = frames();
+            run(&mut Width::default(), 2, &[width as f32], &mut buffer);
+            assert_in_range(&buffer, &format!("width {}", width));
+        }
+
+        // 0 mono, 100 özgün
+        let mut buffer = vec![1.0, 0.0];
+        run(&mut Width::default(), 2, &[0.0], &mut buffer);
+        assert_eq!(buffer, vec![0.5, 0.5]);
+        let mut buffer = frames();
+        run(&mut Width::default(), 2, &[100.0], &mut buffer);
+        assert_eq!(buffer, frames());
+
+        // Genişletmek yan sinyali ortaya göre büyütür
+        let mut buffer = vec![1.0, 0.0];
+        run(&mut Width::default(), 2, &[200.0], &mut buffer);
+        assert_eq!(buffer, vec![0.75, -0.25]);
+    }
+
+    #[test]
+    fn mono_makes_channels_equal() {
+        let mut buffer = frames();
+        run(&mut Mono::default(), 2, &[], &mut buffer);
+        for (frame, original) in buffer.chunks_exact(2).zip(frames().chunks_exact(2)) {
+            assert_eq!(frame[0], frame[1]);
+            assert_eq!(frame[0], (original[0] + original[1]) / 2.0);
+        }
+
+        let mut buffer = vec![0.9, 0.0, -0.3, 0.3, 0.6, 0.0];
+        run(&mut Mono::default(), 3, &[], &mut buffer);
+        for frame in buffer.chunks_exact(3) {
+            assert!(frame.iter().all(|&sample| sample == frame[0]));
+        }
+        assert!((buffer[0] - 0.2).abs() < 1e-6);
+        assert!((buffer[3] - 0.3).abs() < 1e-6);
+    }
+}
@@ -0,0 +1,150 @@
+use super::biquad::{Coefficients, FilterState};
+use super::{EffectSpec, ParamSpec, Processor};
+
+/// Bas rafının köşe frekansı (Hz)
+const BASS_FREQ: f32 = 100.0;
+/// Tiz rafının köşe frekansı (Hz)
+const TREBLE_FREQ: f32 = 10000.0;
+
+/// Bas ve tiz raf süzgeçleri
+pub const TONE: EffectSpec = EffectSpec {
+    name: "tone",
+    label: "Bass / treble",
+    params: &[
+        ParamSpec {
+            name: "bass_db",
+            label: "Bass",
+            min: -12.0,
+            max: 12.0,
+            step: 1.0,
+            default: 0.0,
+            unit: "dB",
+        },
+        ParamSpec {
+            name: "treble_db",
+            label: "Treble",
+            min: -12.0,
+            max: 12.0,
+            step: 1.0,
+            default: 0.0,
+            unit: "dB",
+        },
+    ],
+    build: || Box::new(Tone::default()),
+};
+
+#[derive(Debug, Default)]
+pub struct Tone {
+    sample_rate: f32,
+    /// Katsayıların hesaplandığı kazançlar; örnekleme hızı değişince sıfırlanır
+    gains: Option<(f32, f32)>,
+    bass: Option<Coefficients>,
+    treble: Option<Coefficients>,
+    /// Kanal başına bas ve tiz süzgeçleri
+    states: Vec<[FilterState; 2]>,
+}
+
+impl Processor for Tone {
+    fn prepare(&mut self, sample_rate: u32, channels: usize) {
+        self.sample_rate = sample_rate as f32;
+        self.gains = None;
+        self.states = vec![[FilterState::default(); 2]; channels];
+    }
+
+    fn reset(&mut self) {
+        self.states.fill([FilterState::default(); 2]);
+    }
+
+    fn process(&mut self, params: &[f32], buffer: &mut [f32]) {
+        let gains = (params[0], params[1]);
+        if self.gains != Some(gains) {
+            self.gains = Some(gains);
+            self.bass = Coefficients::low_shelf(BASS_FREQ, gains.0, self.sample_rate);
+            self.treble = Coefficients::high_shelf(TREBLE_FREQ, gains.1, self.sample_rate);
+        }
+        if (self.bass.is_none() && self.treble.is_none()) || self.states.is_empty() {
+            return;
+        }
+
+        for frame in buffer.chunks_exact_mut(self.states.len()) {
+            for (sample, [bass, treble]) in frame.iter_mut().zip(&mut self.states) {
+                if let Some(c) = &self.bass {
+                    *sample = bass.process(c, *sample);
+                }
+                if let Some(c) = &self.treble {
+                    *sample = treble.process(c, *sample);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RATE: u32 = 44_100;
+
+    /// Süzgeçler oturduktan sonraki kazanç (dB); girişlerin genliği 0.5'tir
+    fn settled_gain_db(bass_db: f32, treble_db: f32, input: &[f32]) -> f32 {
+        let mut tone = Tone::default();
+        tone.prepare(RATE, 1);
+        let mut buffer = input.to_vec();
+        tone.process(&[bass_db, treble_db], &mut buffer);
+        let peak = buffer[buffer.len() / 2..]
+            .iter()
+            .fold(0.0f32, |peak, sample| peak.max(sample.abs()));
+        20.0 * (peak / 0.5).log10()
+    }
+
+    /// Sabit (0 Hz) sinyal
+    fn dc() -> Vec<f32> {
+        vec![0.5; RATE as usize / 2]
+    }
+
+    /// Örnekleme hızının yarısındaki sinyal
+    fn nyquist() -> Vec<f32> {
+        (0..RATE as usize / 2)
+            .map(|i| if i % 2 == 0 { 0.5 } else { -0.5 })
+            .collect()
+    }
+
+    #[test]
+    fn zero_gain_is_unity() {
+        let input: Vec<f32> = (0..4_000).map(|i| (i as f32 * 0.37).sin() * 0.8).collect();
+        let mut tone = Tone::default();
+        tone.prepare(RATE, 2);
+        let mut buffer = input.clone();
+        tone.process(&[0.0, 0.0], &mut buffer);
+        assert_eq!(buffer, input);
+
+        // Açılıp yeniden sıfırlanan raflar da sinyali değiştirmez
+        tone.process(&[6.0, -6.0], &mut buffer.clone());
+        tone.process(&[0.0, 0.0], &mut buffer);
+        assert_eq!(buffer, input);
+    }
+
+    #[test]
+    fn shelves_change_only_their_band() {
+        let cases = [
+            // Bas rafı sabit sinyali yükseltir, en tize dokunmaz
+            (6.0, 0.0, dc(), 6.0),
+            (6.0, 0.0, nyquist(), 0.0),
+            (-6.0, 0.0, dc(), -6.0),
+            // Tiz rafı tersini yapar
+            (0.0, 6.0, dc(), 0.0),
+            (0.0, 6.0, nyquist(), 6.0),
+            (0.0, -6.0, nyquist(), -6.0),
+        ];
+        for (bass, treble, input, expected) in cases {
+            let gain = settled_gain_db(bass, treble, &input);
+            assert!(
+                (gain - expected).abs() < 0.05,
+                "bass {} treble {}: {} dB",
+                bass,
+                treble,
+                gain
+            );
+        }
+    }
+}
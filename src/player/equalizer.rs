@@ -0,0 +1,371 @@
+use rodio::Source;
+use rodio::source::SeekError;
+use serde::Deserialize;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicU32, AtomicU64, Ordering};
+use std::time::Duration;
+
+use super::dsp::biquad::{Coefficients, FilterState};
+
+/// Bant sayısı
+pub const BAND_COUNT: usize = 10;
+/// Bantların merkez frekansları (Hz), birer oktav arayla
+pub const BANDS: [f32; BAND_COUNT] = [
+    31.0, 62.0, 125.0, 250.0, 500.0, 1000.0, 2000.0, 4000.0, 8000.0, 16000.0,
+];
+/// Bant kazancının sınırı (dB)
+pub const MAX_GAIN_DB: f32 = 12.0;
+/// Bant genişliği: yaklaşık bir oktav
+const Q: f32 = 1.41;
+
+/// Bantların kazançları (dB)
+pub type Gains = [f32; BAND_COUNT];
+
+/// Adlandırılmış bant kazançları
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct EqPreset {
+    pub name: String,
+    pub gains: Gains,
+}
+
+impl EqPreset {
+    /// Hazır ayarlar
+    pub fn builtin() -> Vec<EqPreset> {
+        [
+            ("flat", [0.0; BAND_COUNT]),
+            (
+                "bass-boost",
+                [6.0, 5.0, 4.0, 2.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0],
+            ),
+            (
+                "treble-boost",
+                [0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 2.0, 4.0, 5.0, 6.0],
+            ),
+            (
+                "vocal",
+                [-2.0, -2.0, -1.0, 1.0, 3.0, 4.0, 3.0, 1.0, 0.0, -1.0],
+            ),
+            (
+                "rock",
+                [4.0, 3.0, -1.0, -2.0, -1.0, 1.0, 3.0, 4.0, 4.0, 4.0],
+            ),
+            ("pop", [-1.0, 1.0, 3.0, 4.0, 3.0, 0.0, -1.0, -1.0, 1.0, 1.0]),
+            ("jazz", [3.0, 2.0, 1.0, 2.0, -1.0, -1.0, 0.0, 1.0, 2.0, 3.0]),
+            (
+                "classical",
+                [4.0, 3.0, 2.0, 1.0, -1.0, -1.0, 0.0, 2.0, 3.0, 4.0],
+            ),
+            (
+                "electronic",
+                [5.0, 4.0, 1.0, 0.0, -2.0, 2.0, 1.0, 2.0, 4.0, 5.0],
+            ),
+            (
+                "loudness",
+                [5.0, 3.0, 0.0, 0.0, -1.0, 0.0, -1.0, 0.0, 3.0, 4.0],
+            ),
+        ]
+        .into_iter()
+        .map(|(name, gains)| EqPreset {
+            name: name.to_string(),
+            gains,
+        })
+        .collect()
+    }
+
+    /// Kazançlar sınırlar içinde mi?
+    pub fn validate(&self) -> Result<(), String> {
+        if self.name.trim().is_empty() {
+            return Err("preset name must not be empty".to_string());
+        }
+        match self
+            .gains
+            .iter()
+            .find(|gain| !(-MAX_GAIN_DB..=MAX_GAIN_DB).contains(*gain))
+        {
+            Some(gain) => Err(format!(
+                "gains must be between -{} and {} dB (got {})",
+                MAX_GAIN_DB, MAX_GAIN_DB, gain
+            )),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Bantın kısa adı ("31", "1k", "16k")
+pub fn band_label(band: usize) -> String {
+    let freq = BANDS[band];
+    if freq >= 1000.0 {
+        format!("{}k", freq / 1000.0)
+    } else {
+        format!("{}", freq)
+    }
+}
+
+/// Çalan kaynaklarla paylaşılan ekolayzır ayarları; değişiklikler çalan parçada hemen duyulur
+#[derive(Debug, Default)]
+pub struct Equalizer {
+    enabled: AtomicBool,
+    /// f32 bitleri olarak kazançlar
+    gains: [AtomicU32; BAND_COUNT],
+    /// Her değişiklikte artar; kaynaklar katsayıları yalnızca değişince yeniden hesaplar
+    version: AtomicU64,
+}
+
+impl Equalizer {
+    pub fn is_enabled(&self) -> bool {
+        self.enabled.load(Ordering::Relaxed)
+    }
+
+    pub fn set_enabled(&self, enabled: bool) {
+        self.enabled.store(enabled, Ordering::Relaxed);
+        self.changed();
+    }
+
+    /// Bantların kazançları (dB)
+    pub fn gains(&self) -> Gains {
+        std::array::from_fn(|band| self.gain(band))
+    }
+
+    pub fn gain(&self, band: usize) -> f32 {
+        f32::from_bits(self.gains[band].load(Ordering::Relaxed))
+    }
+
+    /// Bantın kazancını ayarla (sınırlar içine çekilir)
+    pub fn set_gain(&self, band: usize, gain: f32) {
+        let gain = gain.clamp(-MAX_GAIN_DB, MAX_GAIN_DB);
+        self.gains[band].store(gain.to_bits(), Ordering::Relaxed);
+        self.changed();
+    }
+
+    pub fn set_gains(&self, gains: &Gains) {
+        for (band, &gain) in gains.iter().enumerate() {
+            let gain = gain.clamp(-MAX_GAIN_DB, MAX_GAIN_DB);
+            self.gains[band].store(gain.to_bits(), Ordering::Relaxed);
+        }
+        self.changed();
+    }
+
+    fn changed(&self) {
+        self.version.fetch_add(1, Ordering::Release);
+    }
+}
+
+/// Örnekleri ekolayzır bantlarından geçiren kaynak
+pub struct Equalized<S> {
+    inner: S,
+    settings: Arc<Equalizer>,
+    /// Katsayıların hesaplandığı ayar sürümü
+    version: u64,
+    coefficients: [Option<Coefficients>; BAND_COUNT],
+    /// Kanal başına bant süzgeçleri
+    states: Vec<[FilterState; BAND_COUNT]>,
+    channel: usize,
+    active: bool,
+}
+
+impl<S: Source<Item = f32>> Equalized<S> {
+    pub fn new(inner: S, settings: Arc<Equalizer>) -> Equalized<S> {
+        let channels = inner.channels().max(1) as usize;
+        let mut source = Equalized {
+            inner,
+            settings,
+            version: 0,
+            coefficients: [None; BAND_COUNT],
+            states: vec![[FilterState::default(); BAND_COUNT]; channels],
+            channel: 0,
+            active: false,
+        };
+        source.update();
+        source
+    }
+
+    /// Katsayıları güncel ayarlardan yeniden hesapla
+    fn update(&mut self) {
+        self.version = self.settings.version.load(Ordering::Acquire);
+        let sample_rate = self.inner.sample_rate() as f32;
+        let enabled = self.settings.is_enabled();
+        for (band, coefficients) in self.coefficients.iter_mut().enumerate() {
+            let gain = if enabled {
+                self.settings.gain(band)
+            } else {
+                0.0
+            };
+            *coefficients = Coefficients::peaking(BANDS[band], gain, Q, sample_rate);
+        }
+        let active = self.coefficients.iter().any(Option::is_some);
+        // Kapalıyken kalan geçmiş yeniden açılınca tıkırtı yapmasın
+        if active && !self.active {
+            self.reset();
+        }
+        self.active = active;
+    }
+
+    fn reset(&mut self) {
+        for state in &mut self.states {
+            *state = [FilterState::default(); BAND_COUNT];
+        }
+    }
+}
+
+impl<S: Source<Item = f32>> Iterator for Equalized<S> {
+    type Item = f32;
+
+    #[inline]
+    fn next(&mut self) -> Option<f32> {
+        // Ayarlar yalnızca kare sınırında değişir, böylece kanallar aynı süzgeci görür
+        if self.channel == 0 && self.settings.version.load(Ordering::Acquire) != self.version {
+            self.update();
+        }
+
+        let mut sample = self.inner.next()?;
+        if self.active {
+            let states = &mut self.states[self.channel];
+            for (state, coefficients) in states.iter_mut().zip(&self.coefficients) {
+                if let Some(coefficients) = coefficients {
+                    sample = state.process(coefficients, sample);
+                }
+            }
+        }
+        self.channel = (self.channel + 1) % self.states.len();
+        Some(sample)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<S: Source<Item = f32>> Source for Equalized<S> {
+    fn current_frame_len(&self) -> Option<usize> {
+        self.inner.current_frame_len()
+    }
+
+    fn channels(&self) -> u16 {
+        self.inner.channels()
+    }
+
+    fn sample_rate(&self) -> u32 {
+        self.inner.sample_rate()
+    }
+
+    fn total_duration(&self) -> Option<Duration> {
+        self.inner.total_duration()
+    }
+
+    fn try_seek(&mut self, pos: Duration) -> Result<(), SeekError> {
+        // Sarma kanal sırasını korur; yalnızca süzgeç geçmişi silinir
+        self.inner.try_seek(pos)?;
+        self.reset();
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rodio::buffer::SamplesBuffer;
+
+    const RATE: u32 = 44_100;
+
+    fn sine(freq: f32, frames: usize) -> Vec<f32> {
+        (0..frames)
+            .map(|i| (2.0 * std::f32::consts::PI * freq * i as f32 / RATE as f32).sin() * 0.5)
+            .collect()
+    }
+
+    /// Aynı sinyal iki kanalda
+    fn stereo(mono: &[f32]) -> Vec<f32> {
+        mono.iter().flat_map(|&sample| [sample, sample]).collect()
+    }
+
+    fn equalizer(enabled: bool, gains: &Gains) -> Arc<Equalizer> {
+        let equalizer = Arc::new(Equalizer::default());
+        equalizer.set_gains(gains);
+        equalizer.set_enabled(enabled);
+        equalizer
+    }
+
+    fn equalize(settings: Arc<Equalizer>, channels: u16, input: &[f32]) -> Vec<f32> {
+        let source = SamplesBuffer::new(channels, RATE, input.to_vec());
+        Equalized::new(source, settings).collect()
+    }
+
+    /// Süzgeçler oturduktan sonraki kazanç (dB), RMS oranından
+    fn settled_gain_db(gains: &Gains, freq: f32) -> f32 {
+        let input = sine(freq, RATE as usize / 2);
+        let output = equalize(equalizer(true, gains), 1, &input);
+        let rms = |samples: &[f32]| {
+            let tail = &samples[samples.len() / 2..];
+            (tail.iter().map(|sample| sample * sample).sum::<f32>() / tail.len() as f32).sqrt()
+        };
+        20.0 * (rms(&output) / rms(&input)).log10()
+    }
+
+    #[test]
+    fn flat_or_disabled_equalizer_is_identity() {
+        let input = stereo(&sine(440.0, 4_000));
+        let flat = EqPreset::builtin()
+            .into_iter()
+            .find(|preset| preset.name == "flat")
+            .unwrap();
+        assert_eq!(equalize(equalizer(true, &flat.gains), 2, &input), input);
+
+        let rock = EqPreset::builtin()
+            .into_iter()
+            .find(|preset| preset.name == "rock")
+            .unwrap();
+        assert_eq!(equalize(equalizer(false, &rock.gains), 2, &input), input);
+    }
+
+    #[test]
+    fn peaking_is_skipped_at_zero_gain_or_above_nyquist() {
+        assert!(Coefficients::peaking(1000.0, 0.0, Q, RATE as f32).is_none());
+        assert!(Coefficients::peaking(16_000.0, 6.0, Q, 22_050.0).is_none());
+        assert!(Coefficients::peaking(1000.0, 6.0, Q, RATE as f32).is_some());
+    }
+
+    #[test]
+    fn band_gain_applies_at_its_center_frequency() {
+        for gain in [6.0, -6.0, MAX_GAIN_DB] {
+            let mut gains = [0.0; BAND_COUNT];
+            gains[5] = gain; // 1 kHz
+            let center = settled_gain_db(&gains, 1000.0);
+            assert!(
+                (center - gain).abs() < 0.1,
+                "{} dB at 1 kHz: {}",
+                gain,
+                center
+            );
+            // Birkaç oktav uzaktaki frekanslar neredeyse etkilenmez
+            for freq in [62.0, 16_000.0] {
+                let far = settled_gain_db(&gains, freq);
+                assert!(far.abs() < 0.3, "{} dB at {} Hz: {}", gain, freq, far);
+            }
+        }
+    }
+
+    #[test]
+    fn seek_in_the_middle_of_a_frame_keeps_channels_in_step() {
+        let mut gains = [0.0; BAND_COUNT];
+        gains[0] = 9.0;
+        gains[9] = -9.0;
+        let settings = equalizer(false, &gains);
+        let input = stereo(&sine(3000.0, 4_000));
+        let mut source = Equalized::new(SamplesBuffer::new(2, RATE, input), settings.clone());
+
+        // Sol, sağ, sol: sarma sağ kanaldan devam eder
+        source.by_ref().take(3).for_each(drop);
+        source.try_seek(Duration::from_millis(10)).unwrap();
+        settings.set_enabled(true);
+        assert!(source.next().is_some());
+
+        // Ayar kare sınırında uygulandığı için iki kanal aynı çıktıyı verir
+        let output: Vec<f32> = source.collect();
+        assert!(!output.is_empty());
+        for frame in output.chunks(2) {
+            assert_eq!(frame[0], frame[1]);
+        }
+    }
+}
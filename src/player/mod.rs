@@ -0,0 +1,537 @@
+pub mod crossfade;
+pub mod dsp;
+pub mod equalizer;
+pub mod normalize;
+pub mod queue;
+pub mod source;
+pub mod volume;
+
+use rodio::decoder::DecoderError;
+use rodio::source::{Amplify, SeekError};
+use rodio::{Decoder, OutputStream, OutputStreamHandle, Sink, Source};
+use std::error::Error;
+use std::fmt;
+use std::fs::File;
+use std::io::{self, BufReader};
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::library::Track;
+use crate::library::track::EncoderGap;
+
+pub use crossfade::{Crossfade, FadeCurve};
+use crossfade::{Fade, FadeControl};
+use dsp::Processed;
+pub use dsp::{EffectConfig, Effects};
+use equalizer::Equalized;
+pub use equalizer::{EqPreset, Equalizer};
+pub use normalize::{Normalization, ReplayGainMode};
+pub use queue::{Queue, RepeatMode};
+pub use source::PlaybackClock;
+use source::{Segment, SegmentEnd};
+pub use volume::VolumeCurve;
+
+/// Müzik çalma durumu
+#[derive(Debug, Clone, PartialEq)]
+pub enum PlaybackState {
+    Stopped,
+    Playing,
+    Paused,
+}
+
+/// Parça çalınamadığında dönen hata
+#[derive(Debug)]
+pub enum PlayError {
+    /// Ses cihazı açılamadı; parçayla ilgisi yok
+    Device(Box<dyn Error>),
+    /// Dosya açılamadı
+    Open(io::Error),
+    /// Dosya çözülemedi (bozuk veya desteklenmeyen biçim)
+    Decode(DecoderError),
+}
+
+impl PlayError {
+    /// Hata parçanın kendisinden mi kaynaklanıyor?
+    pub fn is_track_error(&self) -> bool {
+        !matches!(self, PlayError::Device(_))
+    }
+}
+
+impl fmt::Display for PlayError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PlayError::Device(e) => write!(f, "audio device unavailable: {}", e),
+            PlayError::Open(e) => write!(f, "cannot open file: {}", e),
+            PlayError::Decode(e) => write!(f, "cannot decode file: {}", e),
+        }
+    }
+}
+
+impl Error for PlayError {}
+
+/// Dosyadan açılıp çalınacak aralığa sarılmış, ses yüksekliği düzeltilmiş kaynak
+type FileSource = Amplify<Segment<Decoder<BufReader<File>>>>;
+
+/// Çalan parçanın arkasından çalacak parça
+enum Following {
+    /// Aynı dosyada hemen arkasından gelen CUE parçası; kaynak durmadan devam eder
+    Continued(Track),
+    /// Önceden açılıp sink'e eklenmiş ayrı kaynak
+    Queued(Upcoming),
+    /// Önceden açılmış, çalan parçanın sonuna doğru ayrı bir sink'te geçişle başlayacak kaynak
+    Crossfade(Box<Pending>),
+    /// Açılamayan parça; sırası geldiğinde yeniden denenir ve hata o zaman bildirilir
+    Unplayable(Track),
+}
+
+impl Following {
+    fn track(&self) -> &Track {
+        match self {
+            Following::Continued(track) | Following::Unplayable(track) => track,
+            Following::Queued(upcoming) => &upcoming.track,
+            Following::Crossfade(pending) => &pending.track,
+        }
+    }
+}
+
+/// Sink'e eklenmiş, sırası bekleyen kaynak
+struct Upcoming {
+    track: Track,
+    clock: Arc<PlaybackClock>,
+    end: Arc<SegmentEnd>,
+    start: Duration,
+    duration: Option<Duration>,
+    fade: Arc<FadeControl>,
+}
+
+/// Açılmış ama henüz çalmaya başlamamış kaynak
+struct Pending {
+    track: Track,
+    source: FileSource,
+    duration: Option<Duration>,
+}
+
+/// Ses çıkışını ve çalan parçayı yöneten oynatıcı
+pub struct Player {
+    pub state: PlaybackState,
+    pub current: Option<Track>,
+    pub volume: f32, // 0.0 - 1.0
+    pub muted: bool,
+    pub curve: VolumeCurve,
+    pub crossfade: Crossfade,
+    pub normalization: Normalization,
+    /// Çalan kaynaklarla paylaşılan ekolayzır ayarları
+    pub equalizer: Arc<Equalizer>,
+    /// Ekolayzırdan sonra uygulanan efekt zinciri
+    pub effects: Arc<Effects>,
+    /// Karışık çalma açık mı? (otomatik ReplayGain kipi için)
+    pub shuffled: bool,
+    sink: Option<Sink>,
+    /// Geçişte sesi kısılarak biten önceki parçanın sink'i
+    fading: Option<Sink>,
+    /// Çalan kaynağın sesini kısmak için denetim
+    fade: Option<Arc<FadeControl>>,
+    clock: Arc<PlaybackClock>,
+    /// Çalan parçanın toplam süresi (etiketten veya çözücüden)
+    duration: Option<Duration>,
+    /// Çalan kaynağın bitişi; CUE parçaları arasında kesintisiz geçiş için uzatılır
+    end: Option<Arc<SegmentEnd>>,
+    /// Kaynağın dosyada başladığı yer ve çalan parçanın kaynak içindeki başlangıcı
+    segment_start: Duration,
+    offset: Duration,
+    /// Çalan parçanın arkasından kesintisiz çalacak parça
+    following: Option<Following>,
+    /// Çalan parça bir öncekinin arkasından kendiliğinden başladı; `play` onu yeniden başlatmaz
+    continued: bool,
+    _stream: Option<OutputStream>, // Kullanmıyoruz ama ses çıkışı için yaşatmamız gerekiyor
+    stream_handle: Option<OutputStreamHandle>,
+}
+
+impl Player {
+    /// Yeni bir oynatıcı oluştur; ses cihazı ilk çalmada açılır
+    pub fn new(volume: f32) -> Player {
+        Player {
+            state: PlaybackState::Stopped,
+            current: None,
+            volume,
+            muted: false,
+            curve: VolumeCurve::default(),
+            crossfade: Crossfade::default(),
+            normalization: Normalization::default(),
+            equalizer: Arc::new(Equalizer::default()),
+            effects: Arc::new(Effects::default()),
+            shuffled: false,
+            sink: None,
+            fading: None,
+            fade: None,
+            clock: Arc::new(PlaybackClock::default()),
+            duration: None,
+            end: None,
+            segment_start: Duration::ZERO,
+            offset: Duration::ZERO,
+            following: None,
+            continued: false,
+            _stream: None,
+            stream_handle: None,
+        }
+    }
+
+    /// Parçayı çalmaya başla (çalan parça durdurulur)
+    pub fn play(&mut self, track: Track) -> Result<(), PlayError> {
+        // Parça öncekinin arkasından kesintisiz olarak zaten çalıyorsa yeniden başlatma
+        if std::mem::take(&mut self.continued) && self.current.as_ref() == Some(&track) {
+            return Ok(());
+        }
+
+        // Eğer zaten bir şarkı çalıyorsa durdur; yeni parça açılamazsa oynatıcı durmuş kalır
+        self.stop();
+
+        // Önce dosyayı çöz, böylece bozuk dosyalar ses cihazından bağımsız olarak tespit edilir
+        let (source, duration) = self.open(&track)?;
+
+        // Stream'i ilk kez oluştur veya mevcut olanı kullan
+        if self._stream.is_none() {
+            let (stream, handle) =
+                OutputStream::try_default().map_err(|e| PlayError::Device(e.into()))?;
+            self._stream = Some(stream);
+            self.stream_handle = Some(handle);
+        }
+
+        if let Some(ref handle) = self.stream_handle {
+            let sink = Sink::try_new(handle).map_err(|e| PlayError::Device(e.into()))?;
+            sink.set_volume(self.gain());
+
+            self.duration = duration;
+            self.end = Some(source.inner().end());
+            self.segment_start = source.inner().start();
+            self.offset = Duration::ZERO;
+            self.clock = Arc::new(PlaybackClock::default());
+            let fade = self.append(&sink, source, Duration::ZERO, Arc::clone(&self.clock));
+            self.fade = Some(fade);
+
+            self.sink = Some(sink);
+            self.current = Some(track);
+            self.state = PlaybackState::Playing;
+        }
+
+        Ok(())
+    }
+
+    /// Parçanın dosyasını çöz, çalınacak aralığa sar ve ReplayGain kazancını uygula;
+    /// parçanın süresiyle birlikte döndürür
+    fn open(&self, track: &Track) -> Result<(FileSource, Option<Duration>), PlayError> {
+        let file = File::open(&track.path).map_err(PlayError::Open)?;
+        let source = Decoder::new(BufReader::new(file)).map_err(PlayError::Decode)?;
+        let total = source.total_duration();
+
+        // CUE sayfasındaki parçalar dosyanın yalnızca kendi aralığını çalar; diğerlerinde
+        // kodlayıcının başa ve sona eklediği sessizlik atlanır
+        let (start, end) = match track.cue {
+            Some(cue) => (cue.start, cue.end),
+            None => match EncoderGap::read(&track.path) {
+                Some(gap) => {
+                    let frames = |count: u64| {
+                        Duration::from_secs_f64(count as f64 / source.sample_rate() as f64)
+                    };
+                    let end = match gap.frames {
+                        Some(count) => Some(frames(gap.delay + count)),
+                        None => total.map(|total| total.saturating_sub(frames(gap.padding))),
+                    };
+                    (frames(gap.delay), end)
+                }
+                None => (Duration::ZERO, None),
+            },
+        };
+        let duration = match (track.cue, end) {
+            (None, Some(end)) => Some(end.saturating_sub(start)),
+            _ => track
+                .duration
+                .or_else(|| total.map(|total| total.saturating_sub(start))),
+        };
+        let source = Segment::new(source, start, end.map(|end| end.saturating_sub(start)));
+        let factor = self.normalization.factor(&track.replay_gain, self.shuffled);
+        Ok((source.amplify(factor), duration))
+    }
+
+    /// Kaynağı geçiş, ekolayzır ve efekt aşamalarından geçirip konum sayacıyla sink'e ekle;
+    /// kaynağın sesini kısmak için denetimi döndürür
+    fn append(
+        &self,
+        sink: &Sink,
+        source: FileSource,
+        fade_in: Duration,
+        clock: Arc<PlaybackClock>,
+    ) -> Arc<FadeControl> {
+        let source = Fade::new(source, self.crossfade.curve, fade_in);
+        let fade = source.control();
+        let source = Equalized::new(source.convert_samples(), Arc::clone(&self.equalizer));
+        let source = Processed::new(source, Arc::clone(&self.effects));
+        sink.append(source::Tracked::new(source, clock));
+        fade
+    }
+
+    /// Sink'e uygulanan kazanç (sessizde sıfır)
+    fn gain(&self) -> f32 {
+        if self.muted {
+            0.0
+        } else {
+            self.curve.gain(self.volume)
+        }
+    }
+
+    /// Ses seviyesini ayarla (0.0 - 1.0); sessiz mod kapanır
+    pub fn set_volume(&mut self, volume: f32) {
+        self.volume = volume.clamp(0.0, 1.0);
+        self.muted = false;
+        self.apply_volume();
+    }
+
+    /// Ses seviyesini yüzde olarak artır (pozitif) veya azalt (negatif)
+    pub fn change_volume(&mut self, percent: i32) {
+        // Adımlar yüzde birimine yuvarlanır, böylece seviye hep tam yüzde kalır
+        let current = (self.volume * 100.0).round() as i32;
+        self.set_volume((current + percent).clamp(0, 100) as f32 / 100.0);
+    }
+
+    /// Sesi kapat/aç
+    pub fn toggle_mute(&mut self) {
+        self.muted = !self.muted;
+        self.apply_volume();
+    }
+
+    fn apply_volume(&self) {
+        for sink in self.sinks() {
+            sink.set_volume(self.gain());
+        }
+    }
+
+    /// Çalan parçanın ve geçişte sesi kısılan parçanın sink'leri
+    fn sinks(&self) -> impl Iterator<Item = &Sink> {
+        self.sink.iter().chain(&self.fading)
+    }
+
+    /// Müziği duraklat/devam ettir
+    pub fn toggle_pause(&mut self) {
+        if self.sink.is_some() {
+            match self.state {
+                PlaybackState::Playing => {
+                    self.sinks().for_each(Sink::pause);
+                    self.state = PlaybackState::Paused;
+                }
+                PlaybackState::Paused => {
+                    self.sinks().for_each(Sink::play);
+                    self.state = PlaybackState::Playing;
+                }
+                PlaybackState::Stopped => {
+                    // Şarkı durduysa hiçbir şey yapma
+                }
+            }
+        }
+    }
+
+    /// Müziği tamamen durdur
+    pub fn stop(&mut self) {
+        for sink in self.sink.take().into_iter().chain(self.fading.take()) {
+            sink.stop();
+        }
+        self.current = None;
+        self.duration = None;
+        self.end = None;
+        self.fade = None;
+        self.following = None;
+        self.continued = false;
+        self.state = PlaybackState::Stopped;
+        // Stream'i kapatmıyoruz, çünkü tekrar kullanabiliriz
+    }
+
+    /// Çalan parça bittiyse biten parçayı döndür; arkasından kesintisiz çalacak parça
+    /// başladıysa o parça çalan parça olur, yoksa oynatıcı durur
+    pub fn take_finished(&mut self) -> Option<Track> {
+        if self.fading.as_ref().is_some_and(Sink::empty) {
+            self.fading = None;
+        }
+        match self.sink {
+            Some(ref sink) if sink.empty() => {
+                self.sink = None;
+                self.duration = None;
+                self.end = None;
+                self.fade = None;
+                self.following = None;
+                self.state = PlaybackState::Stopped;
+                self.current.take()
+            }
+            Some(_) => self.move_to_following(),
+            None => None,
+        }
+    }
+
+    /// Arkasından çalacak parça başladıysa onu çalan parça yap ve biteni döndür
+    fn move_to_following(&mut self) -> Option<Track> {
+        let started = match self.following {
+            Some(Following::Continued(_)) => {
+                self.duration.is_some_and(|total| self.position() >= total)
+            }
+            Some(Following::Queued(ref upcoming)) => upcoming.clock.position() > Duration::ZERO,
+            // Geçiş yalnızca çalarken, parçanın sonundan geçiş süresi kadar önce başlar
+            Some(Following::Crossfade(_)) => {
+                self.state == PlaybackState::Playing
+                    && self
+                        .duration
+                        .is_some_and(|total| self.position() + self.crossfade.duration >= total)
+            }
+            _ => false,
+        };
+        if !started {
+            return None;
+        }
+        let next = match self.following.take()? {
+            Following::Continued(next) => {
+                let start = next.cue.map_or(Duration::ZERO, |cue| cue.start);
+                self.offset = start.saturating_sub(self.segment_start);
+                self.duration = next.duration;
+                next
+            }
+            Following::Queued(upcoming) => {
+                self.clock = upcoming.clock;
+                self.end = Some(upcoming.end);
+                self.fade = Some(upcoming.fade);
+                self.segment_start = upcoming.start;
+                self.offset = Duration::ZERO;
+                self.duration = upcoming.duration;
+                upcoming.track
+            }
+            Following::Crossfade(pending) => self.start_crossfade(*pending)?,
+            Following::Unplayable(_) => return None,
+        };
+        self.continued = true;
+        self.current.replace(next)
+    }
+
+    /// Sıradaki parçayı yeni bir sink'te sesini açarak başlat, çalan parçanın sesini kısarak
+    /// bitir; sink açılamazsa çalan parça kendi sonunda biter
+    fn start_crossfade(&mut self, pending: Pending) -> Option<Track> {
+        let sink = Sink::try_new(self.stream_handle.as_ref()?).ok()?;
+        sink.set_volume(self.gain());
+
+        let remaining = self.duration.map_or(Duration::ZERO, |total| {
+            total.saturating_sub(self.position())
+        });
+        let length = remaining.min(self.crossfade.duration);
+        if let Some(ref fade) = self.fade {
+            fade.fade_out(length);
+        }
+
+        self.end = Some(pending.source.inner().end());
+        self.segment_start = pending.source.inner().start();
+        self.offset = Duration::ZERO;
+        self.duration = pending.duration;
+        self.clock = Arc::new(PlaybackClock::default());
+        let fade = self.append(&sink, pending.source, length, Arc::clone(&self.clock));
+        self.fade = Some(fade);
+        self.fading = self.sink.replace(sink);
+        Some(pending.track)
+    }
+
+    /// Çalan parçadan sonra çalınacak parçayı bildir; önceden açılıp aynı sink'e eklenir,
+    /// böylece parçalar arasında boşluk kalmaz. Aynı dosyada hemen arkasından gelen CUE
+    /// parçasında kaynak durmadan devam eder; geçiş açıksa parça ayrı bir sink'te sesi
+    /// açılarak başlar
+    pub fn set_following(&mut self, next: Option<&Track>) {
+        if self.following.as_ref().map(Following::track) == next {
+            return;
+        }
+        // Önceki seçim artık geçerli değil: eklenmiş kaynak hiç çalmadan biter,
+        // uzatılmış kaynak çalan parçanın sonuna geri çekilir
+        match self.following.take() {
+            Some(Following::Queued(upcoming)) => upcoming.end.set(Some(Duration::ZERO)),
+            Some(Following::Continued(_)) => self.limit_to(self.current.clone().as_ref()),
+            _ => {}
+        }
+        let (Some(current), Some(next), Some(sink)) = (&self.current, next, &self.sink) else {
+            return;
+        };
+
+        let continues = current.path == next.path
+            && matches!((current.cue, next.cue), (Some(a), Some(b)) if a.end == Some(b.start));
+        if continues {
+            self.following = Some(Following::Continued(next.clone()));
+            self.limit_to(Some(next));
+            return;
+        }
+
+        let crossfade = self.crossfade.applies(current, next) && self.duration.is_some();
+        self.following = Some(match self.open(next) {
+            Ok((source, duration)) if crossfade => Following::Crossfade(Box::new(Pending {
+                track: next.clone(),
+                source,
+                duration,
+            })),
+            Ok((source, duration)) => {
+                let clock = Arc::new(PlaybackClock::default());
+                let (end, start) = (source.inner().end(), source.inner().start());
+                let fade = self.append(sink, source, Duration::ZERO, Arc::clone(&clock));
+                Following::Queued(Upcoming {
+                    track: next.clone(),
+                    clock,
+                    end,
+                    start,
+                    duration,
+                    fade,
+                })
+            }
+            Err(_) => Following::Unplayable(next.clone()),
+        });
+    }
+
+    /// Çalan kaynağı verilen CUE parçasının sonunda bitir
+    fn limit_to(&self, track: Option<&Track>) {
+        if let Some(ref end) = self.end {
+            let until = track.and_then(|track| track.cue).and_then(|cue| cue.end);
+            end.set(until.map(|until| until.saturating_sub(self.segment_start)));
+        }
+    }
+
+    /// Çalan parçadaki konum
+    pub fn position(&self) -> Duration {
+        match self.sink {
+            Some(_) => self.clock.position().saturating_sub(self.offset),
+            None => Duration::ZERO,
+        }
+    }
+
+    /// Çalan parçanın toplam süresi (biliniyorsa)
+    pub fn duration(&self) -> Option<Duration> {
+        self.duration
+    }
+
+    /// Parçada verilen konuma atla (süreyi aşarsa sona yakın bir yere)
+    pub fn seek_to(&mut self, position: Duration) -> Result<(), SeekError> {
+        let Some(ref sink) = self.sink else {
+            return Ok(());
+        };
+        let position = match self.duration {
+            Some(total) => position.min(total.saturating_sub(Duration::from_millis(500))),
+            None => position,
+        };
+        sink.try_seek(self.offset + position)
+    }
+
+    /// Geçerli konumdan ileri (pozitif) veya geri (negatif) sar
+    pub fn seek_by(&mut self, offset_secs: i64) -> Result<(), SeekError> {
+        let step = Duration::from_secs(offset_secs.unsigned_abs());
+        let position = if offset_secs < 0 {
+            self.position().saturating_sub(step)
+        } else {
+            self.position() + step
+        };
+        self.seek_to(position)
+    }
+
+    /// Parçanın verilen yüzdesine atla
+    pub fn seek_to_percent(&mut self, percent: u8) -> Result<(), SeekError> {
+        match self.duration {
+            Some(total) => self.seek_to(total.mul_f64(percent.min(100) as f64 / 100.0)),
+            None => Ok(()),
+        }
+    }
+}
@@ -0,0 +1,203 @@
+use serde::Deserialize;
+
+use crate::library::track::ReplayGain;
+
+/// Hangi ReplayGain değerinin uygulanacağı
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ReplayGainMode {
+    /// Ses yüksekliği düzeltilmez
+    #[default]
+    Off,
+    /// Her parça kendi kazancıyla çalınır
+    Track,
+    /// Albümdeki parçalar arasındaki fark korunur
+    Album,
+    /// Karışık çalmada parça, sırayla çalmada albüm kazancı
+    Auto,
+}
+
+/// Çalma sırasında uygulanan ses yüksekliği düzeltmesi
+#[derive(Debug, Clone, Copy)]
+pub struct Normalization {
+    pub mode: ReplayGainMode,
+    /// Etiketteki kazanca eklenen değer (dB)
+    pub preamp_db: f32,
+    /// Tepe değeri biliniyorsa kazanç, parça kırpılmayacak kadar sınırlanır
+    pub prevent_clipping: bool,
+}
+
+impl Default for Normalization {
+    fn default() -> Self {
+        Normalization {
+            mode: ReplayGainMode::Off,
+            preamp_db: 0.0,
+            prevent_clipping: true,
+        }
+    }
+}
+
+impl Normalization {
+    /// Parçaya uygulanacak çarpan; kazanç etiketi yoksa 1.0
+    pub fn factor(&self, replay_gain: &ReplayGain, shuffled: bool) -> f32 {
+        let track = replay_gain
+            .track_gain
+            .map(|gain| (gain, replay_gain.track_peak));
+        let album = replay_gain
+            .album_gain
+            .map(|gain| (gain, replay_gain.album_peak));
+        let chosen = match self.mode {
+            ReplayGainMode::Off => None,
+            ReplayGainMode::Track => track.or(album),
+            ReplayGainMode::Album => album.or(track),
+            ReplayGainMode::Auto if shuffled => track.or(album),
+            ReplayGainMode::Auto => album.or(track),
+        };
+        let Some((gain, peak)) = chosen else {
+            return 1.0;
+        };
+
+        let factor = 10f32.powf((gain + self.preamp_db) / 20.0);
+        match peak {
+            Some(peak) if self.prevent_clipping && peak > 0.0 => factor.min(1.0 / peak),
+            _ => factor,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Parça -6 dB, albüm -3 dB; tepeler verilmez
+    const GAINS: ReplayGain = ReplayGain {
+        track_gain: Some(-6.0),
+        track_peak: None,
+        album_gain: Some(-3.0),
+        album_peak: None,
+    };
+
+    fn normalization(mode: ReplayGainMode) -> Normalization {
+        Normalization {
+            mode,
+            ..Normalization::default()
+        }
+    }
+
+    fn db(gain: f32) -> f32 {
+        10f32.powf(gain / 20.0)
+    }
+
+    fn assert_close(actual: f32, expected: f32) {
+        assert!(
+            (actual - expected).abs() < 1e-6,
+            "{} != {}",
+            actual,
+            expected
+        );
+    }
+
+    #[test]
+    fn mode_chooses_track_or_album_gain() {
+        for shuffled in [false, true] {
+            assert_eq!(
+                normalization(ReplayGainMode::Off).factor(&GAINS, shuffled),
+                1.0
+            );
+            assert_close(
+                normalization(ReplayGainMode::Track).factor(&GAINS, shuffled),
+                db(-6.0),
+            );
+            assert_close(
+                normalization(ReplayGainMode::Album).factor(&GAINS, shuffled),
+                db(-3.0),
+            );
+        }
+        // Karışık çalmada parça, sırayla çalmada albüm kazancı
+        let auto = normalization(ReplayGainMode::Auto);
+        assert_close(auto.factor(&GAINS, true), db(-6.0));
+        assert_close(auto.factor(&GAINS, false), db(-3.0));
+    }
+
+    #[test]
+    fn missing_gain_falls_back_to_the_other_or_unity() {
+        let track_only = ReplayGain {
+            album_gain: None,
+            ..GAINS
+        };
+        let album_only = ReplayGain {
+            track_gain: None,
+            ..GAINS
+        };
+        assert_close(
+            normalization(ReplayGainMode::Album).factor(&track_only, false),
+            db(-6.0),
+        );
+        assert_close(
+            normalization(ReplayGainMode::Track).factor(&album_only, false),
+            db(-3.0),
+        );
+        // Etiketsiz parça olduğu gibi çalınır; yalnızca tepe değeri de bir şey değiştirmez
+        let peak_only = ReplayGain {
+            track_peak: Some(0.5),
+            ..ReplayGain::default()
+        };
+        for mode in [
+            ReplayGainMode::Track,
+            ReplayGainMode::Album,
+            ReplayGainMode::Auto,
+        ] {
+            let normalization = Normalization {
+                preamp_db: 6.0,
+                ..normalization(mode)
+            };
+            assert_eq!(normalization.factor(&ReplayGain::default(), true), 1.0);
+            assert_eq!(normalization.factor(&peak_only, false), 1.0);
+        }
+    }
+
+    #[test]
+    fn preamp_is_added_and_peak_limits_gain() {
+        let loud = Normalization {
+            mode: ReplayGainMode::Track,
+            preamp_db: 9.0,
+            prevent_clipping: true,
+        };
+        // -6 + 9 = +3 dB, tepesi bilinmeyen parçada sınırlanmaz
+        assert_close(loud.factor(&GAINS, false), db(3.0));
+
+        // Tepe 0.8 ise kazanç 1/0.8'i aşmaz
+        let peaked = ReplayGain {
+            track_peak: Some(0.8),
+            ..GAINS
+        };
+        assert_close(loud.factor(&peaked, false), 1.0 / 0.8);
+        // Kazanç zaten düşükse tepe değiştirmez
+        assert_close(
+            normalization(ReplayGainMode::Track).factor(&peaked, false),
+            db(-6.0),
+        );
+        // Sınırlama kapalıysa veya tepe geçersizse kazanç olduğu gibi uygulanır
+        let unlimited = Normalization {
+            prevent_clipping: false,
+            ..loud
+        };
+        assert_close(unlimited.factor(&peaked, false), db(3.0));
+        let zero_peak = ReplayGain {
+            track_peak: Some(0.0),
+            ..GAINS
+        };
+        assert_close(loud.factor(&zero_peak, false), db(3.0));
+
+        // Albüm kipinde albümün tepesi kullanılır
+        let album = ReplayGain {
+            album_gain: Some(2.0),
+            album_peak: Some(0.9),
+            ..peaked
+        };
+        assert_close(
+            normalization(ReplayGainMode::Album).factor(&album, false),
+            1.0 / 0.9,
+        );
+    }
+}
@@ -0,0 +1,488 @@
+use rand::Rng;
+use rand::seq::SliceRandom;
+
+use crate::library::Track;
+
+/// Tekrar modu
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum RepeatMode {
+    #[default]
+    Off,
+    One,
+    All,
+}
+
+impl RepeatMode {
+    /// Sıradaki moda geç (Off -> All -> One -> Off)
+    pub fn cycle(self) -> RepeatMode {
+        match self {
+            RepeatMode::Off => RepeatMode::All,
+            RepeatMode::All => RepeatMode::One,
+            RepeatMode::One => RepeatMode::Off,
+        }
+    }
+}
+
+/// Kuyruktaki bir kayıt; aynı parça birden fazla kez eklenebildiği için kimlikle ayırt edilir
+#[derive(Debug)]
+struct Entry {
+    id: u64,
+    track: Track,
+}
+
+/// Çalma kuyruğu: çalınan ve sıradaki parçalar, çalan parçayla birlikte
+#[derive(Debug, Default)]
+pub struct Queue {
+    entries: Vec<Entry>,
+    /// Çalan kaydın kimliği; None ise henüz hiçbir parça çalınmadı
+    current: Option<u64>,
+    next_id: u64,
+    pub repeat: RepeatMode,
+    shuffle: bool,
+    /// Karışık çalma sırası (kayıt kimlikleri): çalınanlar, çalan parça, sonra sıradakiler
+    shuffle_order: Vec<u64>,
+    /// "Sıradaki" olarak eklenmiş, henüz çalınmamış kayıtlar; karışık sırada çalan parçanın
+    /// hemen arkasında kalırlar
+    pinned: Vec<u64>,
+}
+
+impl Queue {
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn get(&self, index: usize) -> Option<&Track> {
+        self.entries.get(index).map(|entry| &entry.track)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Track> {
+        self.entries.iter().map(|entry| &entry.track)
+    }
+
+    /// Çalan parçanın kuyruktaki konumu
+    pub fn current_index(&self) -> Option<usize> {
+        self.current.and_then(|id| self.index_of(id))
+    }
+
+    pub fn is_shuffled(&self) -> bool {
+        self.shuffle
+    }
+
+    /// Çalan parçadan sonra gelecek parçalar, çalınacakları sırayla
+    pub fn upcoming(&self) -> Vec<&Track> {
+        if self.shuffle {
+            self.shuffle_order[self.shuffle_position().map_or(0, |p| p + 1)..]
+                .iter()
+                .filter_map(|id| self.index_of(*id))
+                .map(|i| &self.entries[i].track)
+                .collect()
+        } else {
+            let start = self.current_index().map_or(0, |i| i + 1);
+            self.entries[start..]
+                .iter()
+                .map(|entry| &entry.track)
+                .collect()
+        }
+    }
+
+    /// Parçayı sonuna ekle; karışık modda sıradakiler arasında rastgele bir yere girer
+    /// (sıradaki olarak eklenenlerin arkasına, diğerlerinin sırası değişmez)
+    pub fn push(&mut self, track: Track) {
+        let id = self.add_entry(self.entries.len(), track);
+        if self.shuffle {
+            let position =
+                rand::thread_rng().gen_range(self.pinned_end()..=self.shuffle_order.len());
+            self.shuffle_order.insert(position, id);
+        }
+    }
+
+    /// Parçayı çalan parçanın hemen arkasına ekle (karışık modda da ilk sırada çalınır)
+    pub fn play_next(&mut self, track: Track) {
+        let index = self.current_index().map_or(0, |i| i + 1);
+        let id = self.add_entry(index, track);
+        self.pinned.push(id);
+        if self.shuffle {
+            self.insert_after_current(id);
+        }
+    }
+
+    /// Parçayı çalanın arkasına ekle ve çalan parça yap
+    pub fn play_now(&mut self, track: Track) -> &Track {
+        self.play_next(track);
+        let index = self.current_index().map_or(0, |i| i + 1);
+        self.set_current(index);
+        &self.entries[index].track
+    }
+
+    /// Verilen konumdaki parçaya geç
+    pub fn jump(&mut self, index: usize) -> Option<&Track> {
+        if index >= self.entries.len() {
+            return None;
+        }
+        self.set_current(index);
+        Some(&self.entries[index].track)
+    }
+
+    /// Parça kendiliğinden bittiğinde çalınacak parçaya geç (tekrar modunu dikkate alır)
+    pub fn advance_on_finish(&mut self) -> Option<&Track> {
+        match (self.repeat, self.current_index()) {
+            (RepeatMode::One, Some(current)) => Some(&self.entries[current].track),
+            _ => self.advance(),
+        }
+    }
+
+    /// Sıradaki parçaya geç; kuyruk bittiyse (ve tekrar kapalıysa) None
+    pub fn advance(&mut self) -> Option<&Track> {
+        if self.entries.is_empty() {
+            return None;
+        }
+
+        let next = match self.next_index() {
+            Some(next) => next,
+            None if self.shuffle && self.repeat == RepeatMode::All => {
+                // Tur bitti: yeni bir karışık sıra oluştur, az önce çalan parça başa gelmesin
+                let last = self.current.take();
+                self.shuffle_order.shuffle(&mut rand::thread_rng());
+                if self.shuffle_order.len() > 1 && self.shuffle_order.first() == last.as_ref() {
+                    let end = self.shuffle_order.len() - 1;
+                    self.shuffle_order.swap(0, end);
+                }
+                self.index_of(self.shuffle_order[0])?
+            }
+            None => return None,
+        };
+
+        self.current = Some(self.entries[next].id);
+        self.unpin_current();
+        Some(&self.entries[next].track)
+    }
+
+    /// Parça kendiliğinden bittiğinde çalınacak parça (kuyruk değişmez); karışık sıranın
+    /// yeniden oluşturulacağı durumda sıradaki parça bilinmediğinden None
+    pub fn peek_on_finish(&self) -> Option<&Track> {
+        let index = match (self.repeat, self.current_index()) {
+            (RepeatMode::One, Some(current)) => current,
+            _ => self.next_index()?,
+        };
+        Some(&self.entries[index].track)
+    }
+
+    /// Çalan parçadan sonraki kaydın konumu; karışık sıranın sonunda None
+    fn next_index(&self) -> Option<usize> {
+        if self.shuffle {
+            let position = self.shuffle_position().map_or(0, |p| p + 1);
+            self.index_of(*self.shuffle_order.get(position)?)
+        } else {
+            match self.current_index().map_or(0, |i| i + 1) {
+                next if next < self.entries.len() => Some(next),
+                _ if self.repeat == RepeatMode::All && !self.entries.is_empty() => Some(0),
+                _ => None,
+            }
+        }
+    }
+
+    /// Önceki parçaya dön; karışık modda bir önce çalınan parçaya döner
+    pub fn back(&mut self) -> Option<&Track> {
+        let previous = if self.shuffle {
+            let position = self.shuffle_position()?.checked_sub(1)?;
+            self.index_of(self.shuffle_order[position])?
+        } else {
+            match self.current_index()?.checked_sub(1) {
+                Some(previous) => previous,
+                None if self.repeat == RepeatMode::All => self.entries.len() - 1,
+                None => return None,
+            }
+        };
+
+        // Karışık sıra değişmez; ileri gidince aynı parçalara tekrar gelinir
+        self.current = Some(self.entries[previous].id);
+        Some(&self.entries[previous].track)
+    }
+
+    /// Karışık modu aç/kapat; açılınca çalınmamış parçalar için yeni bir sıra oluşturulur
+    pub fn set_shuffle(&mut self, shuffle: bool) {
+        self.shuffle = shuffle;
+        self.shuffle_order.clear();
+        if shuffle {
+            // Çalan parça sıranın başına, ardından sıradaki olarak eklenenler, geri kalanlar karışık
+            self.shuffle_order.extend(self.current);
+            let pinned = self
+                .entries
+                .iter()
+                .map(|e| e.id)
+                .filter(|id| self.pinned.contains(id));
+            self.shuffle_order.extend(pinned);
+            self.shuffle_order.extend(
+                self.entries
+                    .iter()
+                    .map(|e| e.id)
+                    .filter(|id| Some(*id) != self.current && !self.pinned.contains(id)),
+            );
+            self.reshuffle_upcoming();
+        }
+    }
+
+    /// Parçayı kuyruktan çıkar; çalan parça çıkarılırsa sıradaki parça onun yerine geçer
+    pub fn remove(&mut self, index: usize) -> Option<Track> {
+        if index >= self.entries.len() {
+            return None;
+        }
+        let id = self.entries[index].id;
+
+        // Çalan parça çıkarılıyorsa bir önceki parça "çalan" sayılır, böylece ilerleyince
+        // çıkarılan parçanın yerine gelen parça çalınır
+        if self.current == Some(id) {
+            self.current = if self.shuffle {
+                self.shuffle_position()
+                    .and_then(|p| p.checked_sub(1))
+                    .map(|p| self.shuffle_order[p])
+            } else {
+                index.checked_sub(1).map(|i| self.entries[i].id)
+            };
+        }
+
+        // Karışık sıra yeniden karıştırılmaz: çalınanlar geri dönülebilir kalır, çalan parça
+        // sıranın başındaysa sıradaki parça başa geçer
+        self.shuffle_order.retain(|other| *other != id);
+        self.pinned.retain(|other| *other != id);
+        let entry = self.entries.remove(index);
+        Some(entry.track)
+    }
+
+    /// Parçayı başka bir konuma taşı (karışık sıra değişmez)
+    pub fn move_item(&mut self, from: usize, to: usize) -> bool {
+        if from >= self.entries.len() || to >= self.entries.len() || from == to {
+            return false;
+        }
+        let entry = self.entries.remove(from);
+        self.entries.insert(to, entry);
+        true
+    }
+
+    /// Kuyruğu temizle
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.shuffle_order.clear();
+        self.pinned.clear();
+        self.current = None;
+    }
+
+    /// Kayıt oluştur ve verilen konuma ekle
+    fn add_entry(&mut self, index: usize, track: Track) -> u64 {
+        self.next_id += 1;
+        let id = self.next_id;
+        self.entries.insert(index, Entry { id, track });
+        id
+    }
+
+    fn index_of(&self, id: u64) -> Option<usize> {
+        self.entries.iter().position(|entry| entry.id == id)
+    }
+
+    /// Çalan parçanın karışık sıradaki konumu
+    fn shuffle_position(&self) -> Option<usize> {
+        let id = self.current?;
+        self.shuffle_order.iter().position(|other| *other == id)
+    }
+
+    /// Karışık sırada kaydı çalan parçanın hemen arkasına taşı
+    fn insert_after_current(&mut self, id: u64) {
+        self.shuffle_order.retain(|other| *other != id);
+        let position = self.shuffle_position().map_or(0, |p| p + 1);
+        self.shuffle_order.insert(position, id);
+    }
+
+    /// Çalan parçayı değiştir; karışık modda parça çalınanların arkasına taşınır
+    fn set_current(&mut self, index: usize) {
+        let id = self.entries[index].id;
+        if self.shuffle {
+            self.insert_after_current(id);
+        }
+        self.current = Some(id);
+        self.unpin_current();
+    }
+
+    /// Çalmaya başlayan parça artık "sıradaki" değildir
+    fn unpin_current(&mut self) {
+        let current = self.current;
+        self.pinned.retain(|id| Some(*id) != current);
+    }
+
+    /// Karışık sırada, çalan parçanın arkasındaki "sıradaki" kayıtlardan sonraki konum
+    fn pinned_end(&self) -> usize {
+        let start = self.shuffle_position().map_or(0, |p| p + 1);
+        let pinned = self.shuffle_order[start..]
+            .iter()
+            .take_while(|id| self.pinned.contains(id))
+            .count();
+        start + pinned
+    }
+
+    /// Karışık modda: çalınmış parçaları, çalan parçayı ve sıradaki olarak eklenenleri koru,
+    /// geri kalanları yeniden karıştır
+    fn reshuffle_upcoming(&mut self) {
+        if !self.shuffle {
+            return;
+        }
+        let start = self.pinned_end();
+        self.shuffle_order[start..].shuffle(&mut rand::thread_rng());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+    use std::path::Path;
+
+    fn track(name: &str) -> Track {
+        Track::from_file_name(Path::new(&format!("/queue/{}.flac", name)))
+    }
+
+    /// "0".."n-1" adlı parçalardan kuyruk
+    fn filled(n: usize, shuffle: bool) -> Queue {
+        let mut queue = Queue::default();
+        for i in 0..n {
+            queue.push(track(&i.to_string()));
+        }
+        queue.set_shuffle(shuffle);
+        queue
+    }
+
+    fn title(track: Option<&Track>) -> Option<String> {
+        track.map(|track| track.title.clone())
+    }
+
+    fn upcoming(queue: &Queue) -> Vec<String> {
+        queue
+            .upcoming()
+            .iter()
+            .map(|track| track.title.clone())
+            .collect()
+    }
+
+    #[test]
+    fn shuffle_plays_every_track_once() {
+        let mut queue = filled(30, true);
+        // Karışık modda eklenenler de turda bir kez çalınır
+        for i in 30..40 {
+            queue.push(track(&i.to_string()));
+        }
+        let mut played = Vec::new();
+        while let Some(title) = title(queue.advance()) {
+            played.push(title);
+        }
+        assert_eq!(played.len(), 40);
+        assert_eq!(played.iter().collect::<HashSet<_>>().len(), 40);
+        assert!(queue.advance().is_none());
+    }
+
+    #[test]
+    fn back_returns_to_previous_track() {
+        for shuffle in [false, true] {
+            let mut queue = filled(10, shuffle);
+            let played: Vec<_> = (0..4).map(|_| title(queue.advance())).collect();
+            assert_eq!(title(queue.back()), played[2]);
+            assert_eq!(title(queue.back()), played[1]);
+            // İleri gidince aynı parçalara gelinir
+            assert_eq!(title(queue.advance()), played[2]);
+            assert_eq!(title(queue.advance()), played[3]);
+        }
+        let mut queue = filled(3, false);
+        queue.advance();
+        assert!(queue.back().is_none());
+    }
+
+    #[test]
+    fn repeat_one_and_all_wrap() {
+        let mut queue = filled(3, false);
+        queue.repeat = RepeatMode::One;
+        assert_eq!(title(queue.advance_on_finish()), Some("0".to_string()));
+        assert_eq!(title(queue.advance_on_finish()), Some("0".to_string()));
+        // Elle geçişte tekrar-bir uygulanmaz
+        assert_eq!(title(queue.advance()), Some("1".to_string()));
+
+        queue.repeat = RepeatMode::All;
+        queue.advance();
+        assert_eq!(title(queue.peek_on_finish()), Some("0".to_string()));
+        assert_eq!(title(queue.advance_on_finish()), Some("0".to_string()));
+        assert_eq!(title(queue.back()), Some("2".to_string()));
+
+        // Karışık modda tur bitince tüm parçalarla yeni tur başlar
+        let mut queue = filled(5, true);
+        queue.repeat = RepeatMode::All;
+        let first: Vec<_> = (0..5).map(|_| title(queue.advance()).unwrap()).collect();
+        assert_eq!(queue.peek_on_finish(), None);
+        let second: Vec<_> = (0..5)
+            .map(|_| title(queue.advance_on_finish()).unwrap())
+            .collect();
+        assert_eq!(second.iter().collect::<HashSet<_>>().len(), 5);
+        assert_ne!(second[0], first[4]);
+    }
+
+    #[test]
+    fn play_next_survives_later_changes() {
+        let mut queue = filled(10, true);
+        queue.advance();
+        queue.play_next(track("second"));
+        queue.play_next(track("first"));
+        let before = upcoming(&queue);
+        assert_eq!(before[..2], ["first", "second"]);
+
+        for i in 10..30 {
+            queue.push(track(&i.to_string()));
+        }
+        assert!(queue.move_item(0, 5));
+        let after = upcoming(&queue);
+        assert_eq!(after[..2], ["first", "second"]);
+        // Eklenenler var olan sırayı bozmaz
+        let kept: Vec<_> = after
+            .iter()
+            .filter(|title| before.contains(title))
+            .collect();
+        assert_eq!(kept, before.iter().collect::<Vec<_>>());
+
+        assert_eq!(title(queue.advance()), Some("first".to_string()));
+        assert_eq!(title(queue.advance()), Some("second".to_string()));
+    }
+
+    #[test]
+    fn play_next_stays_first_when_shuffle_is_turned_on() {
+        let mut queue = filled(10, false);
+        queue.advance();
+        queue.play_next(track("next"));
+        queue.set_shuffle(true);
+        assert_eq!(upcoming(&queue)[0], "next");
+        assert_eq!(title(queue.advance()), Some("next".to_string()));
+    }
+
+    #[test]
+    fn removing_current_while_shuffled_keeps_history() {
+        let mut queue = filled(10, true);
+        let played: Vec<_> = (0..3).map(|_| title(queue.advance())).collect();
+        let rest = upcoming(&queue);
+
+        queue.remove(queue.current_index().unwrap());
+        // Çıkarılanın yerine sıradaki parça gelir, geçmiş korunur
+        assert_eq!(upcoming(&queue), rest);
+        assert_eq!(title(queue.advance()), Some(rest[0].clone()));
+        assert_eq!(title(queue.back()), played[1]);
+        assert_eq!(title(queue.back()), played[0]);
+    }
+
+    #[test]
+    fn removing_first_shuffled_track_keeps_order() {
+        let mut queue = filled(10, true);
+        queue.advance();
+        let rest = upcoming(&queue);
+
+        queue.remove(queue.current_index().unwrap());
+        assert_eq!(queue.current_index(), None);
+        assert_eq!(upcoming(&queue), rest);
+        assert_eq!(title(queue.advance()), Some(rest[0].clone()));
+    }
+}
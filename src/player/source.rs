@@ -0,0 +1,331 @@
+use rodio::Source;
+use rodio::source::SeekError;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+
+/// Çalan kaynağın ne kadarının okunduğunu tutan sayaç (ses thread'i ile paylaşılır)
+#[derive(Debug, Default)]
+pub struct PlaybackClock {
+    samples: AtomicU64,
+    /// sample_rate * channels
+    samples_per_second: AtomicU64,
+}
+
+impl PlaybackClock {
+    /// Şu ana kadar çalınan süre
+    pub fn position(&self) -> Duration {
+        let per_second = self.samples_per_second.load(Ordering::Relaxed);
+        if per_second == 0 {
+            return Duration::ZERO;
+        }
+        let samples = self.samples.load(Ordering::Relaxed);
+        Duration::from_secs_f64(samples as f64 / per_second as f64)
+    }
+
+    fn set_position(&self, position: Duration) {
+        let per_second = self.samples_per_second.load(Ordering::Relaxed);
+        let samples = (position.as_secs_f64() * per_second as f64) as u64;
+        self.samples.store(samples, Ordering::Relaxed);
+    }
+}
+
+/// Okunan örnekleri sayarak çalma konumunu takip eden kaynak sarmalayıcı
+pub struct Tracked<S> {
+    inner: S,
+    clock: Arc<PlaybackClock>,
+}
+
+impl<S: Source> Tracked<S>
+where
+    S::Item: rodio::Sample,
+{
+    pub fn new(inner: S, clock: Arc<PlaybackClock>) -> Tracked<S> {
+        clock.samples.store(0, Ordering::Relaxed);
+        clock.samples_per_second.store(
+            inner.sample_rate() as u64 * inner.channels() as u64,
+            Ordering::Relaxed,
+        );
+        Tracked { inner, clock }
+    }
+}
+
+impl<S: Source> Iterator for Tracked<S>
+where
+    S::Item: rodio::Sample,
+{
+    type Item = S::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<S::Item> {
+        let sample = self.inner.next()?;
+        self.clock.samples.fetch_add(1, Ordering::Relaxed);
+        Some(sample)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<S: Source> Source for Tracked<S>
+where
+    S::Item: rodio::Sample,
+{
+    fn current_frame_len(&self) -> Option<usize> {
+        self.inner.current_frame_len()
+    }
+
+    fn channels(&self) -> u16 {
+        self.inner.channels()
+    }
+
+    fn sample_rate(&self) -> u32 {
+        self.inner.sample_rate()
+    }
+
+    fn total_duration(&self) -> Option<Duration> {
+        self.inner.total_duration()
+    }
+
+    fn try_seek(&mut self, pos: Duration) -> Result<(), SeekError> {
+        self.inner.try_seek(pos)?;
+        self.clock.set_position(pos);
+        Ok(())
+    }
+}
+
+/// Kaynağın nerede biteceği; çalarken uzatılabilmesi için ses thread'i ile paylaşılır
+#[derive(Debug)]
+pub struct SegmentEnd {
+    /// Başlangıçtan itibaren örnek sayısı; `u64::MAX` dosyanın sonu demek
+    samples: AtomicU64,
+    sample_rate: u64,
+    channels: u64,
+}
+
+impl SegmentEnd {
+    /// Bitişi başlangıca göre ayarla; `None` dosyanın sonuna kadar çalar
+    pub fn set(&self, length: Option<Duration>) {
+        let samples = match length {
+            Some(length) => frames(length, self.sample_rate) * self.channels,
+            None => u64::MAX,
+        };
+        self.samples.store(samples, Ordering::Relaxed);
+    }
+}
+
+/// Süredeki örnek karesi sayısı (en yakın kareye yuvarlanır)
+fn frames(duration: Duration, sample_rate: u64) -> u64 {
+    (duration.as_secs_f64() * sample_rate as f64).round() as u64
+}
+
+/// Dosyanın yalnızca bir aralığını çalan kaynak (CUE sayfasındaki parçalar, kodlayıcı
+/// sessizliği atlanmış dosyalar)
+pub struct Segment<S> {
+    inner: S,
+    start: Duration,
+    end: Arc<SegmentEnd>,
+    /// Başlangıçtan beri okunan örnek sayısı
+    played: u64,
+    channels: u64,
+}
+
+impl<S: Source> Segment<S>
+where
+    S::Item: rodio::Sample,
+{
+    /// Kaynağı başlangıca sar; çözücü atlayamıyorsa aradaki örnekler okunup atılır
+    pub fn new(mut inner: S, start: Duration, length: Option<Duration>) -> Segment<S> {
+        let sample_rate = inner.sample_rate() as u64;
+        let channels = inner.channels().max(1) as u64;
+        if !start.is_zero() && inner.try_seek(start).is_err() {
+            for _ in 0..frames(start, sample_rate) * channels {
+                if inner.next().is_none() {
+                    break;
+                }
+            }
+        }
+        let end = Arc::new(SegmentEnd {
+            samples: AtomicU64::new(u64::MAX),
+            sample_rate,
+            channels,
+        });
+        end.set(length);
+        Segment {
+            inner,
+            start,
+            end,
+            played: 0,
+            channels,
+        }
+    }
+
+    /// Çalarken bitişi değiştirmek için paylaşılan bitiş
+    pub fn end(&self) -> Arc<SegmentEnd> {
+        Arc::clone(&self.end)
+    }
+
+    /// Aralığın dosyadaki başlangıcı
+    pub fn start(&self) -> Duration {
+        self.start
+    }
+}
+
+impl<S: Source> Iterator for Segment<S>
+where
+    S::Item: rodio::Sample,
+{
+    type Item = S::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<S::Item> {
+        // Kare ortasında kesilmesin diye bitiş yalnızca kare sınırında denetlenir
+        if self.played.is_multiple_of(self.channels)
+            && self.played >= self.end.samples.load(Ordering::Relaxed)
+        {
+            return None;
+        }
+        let sample = self.inner.next()?;
+        self.played += 1;
+        Some(sample)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.inner.size_hint().1)
+    }
+}
+
+impl<S: Source> Source for Segment<S>
+where
+    S::Item: rodio::Sample,
+{
+    fn current_frame_len(&self) -> Option<usize> {
+        self.inner.current_frame_len()
+    }
+
+    fn channels(&self) -> u16 {
+        self.inner.channels()
+    }
+
+    fn sample_rate(&self) -> u32 {
+        self.inner.sample_rate()
+    }
+
+    fn total_duration(&self) -> Option<Duration> {
+        None
+    }
+
+    fn try_seek(&mut self, pos: Duration) -> Result<(), SeekError> {
+        self.inner.try_seek(self.start + pos)?;
+        self.played = frames(pos, self.end.sample_rate) * self.channels;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rodio::buffer::SamplesBuffer;
+
+    const RATE: u32 = 1000;
+
+    /// Bir saniyelik stereo kaynak; her karenin sol örneği kare numarası, sağı eksisi
+    fn numbered() -> SamplesBuffer<f32> {
+        let samples = (0..RATE)
+            .flat_map(|i| [i as f32, -(i as f32)])
+            .collect::<Vec<_>>();
+        SamplesBuffer::new(2, RATE, samples)
+    }
+
+    /// Sarılamayan kaynak: başlangıca örnekler okunarak gelinir
+    struct Unseekable(SamplesBuffer<f32>);
+
+    impl Iterator for Unseekable {
+        type Item = f32;
+
+        fn next(&mut self) -> Option<f32> {
+            self.0.next()
+        }
+    }
+
+    impl Source for Unseekable {
+        fn current_frame_len(&self) -> Option<usize> {
+            None
+        }
+
+        fn channels(&self) -> u16 {
+            self.0.channels()
+        }
+
+        fn sample_rate(&self) -> u32 {
+            self.0.sample_rate()
+        }
+
+        fn total_duration(&self) -> Option<Duration> {
+            None
+        }
+
+        fn try_seek(&mut self, _pos: Duration) -> Result<(), SeekError> {
+            Err(SeekError::NotSupported {
+                underlying_source: "test",
+            })
+        }
+    }
+
+    /// Okunan karelerin numaraları; kanalların karışmadığı da doğrulanır
+    fn frame_numbers(source: impl Iterator<Item = f32>) -> Vec<u32> {
+        let samples: Vec<f32> = source.collect();
+        assert!(samples.len().is_multiple_of(2));
+        samples
+            .chunks_exact(2)
+            .map(|frame| {
+                assert_eq!(frame[0], -frame[1]);
+                frame[0] as u32
+            })
+            .collect()
+    }
+
+    #[test]
+    fn segment_stops_exactly_at_end() {
+        let start = Duration::from_millis(100);
+        let length = Some(Duration::from_millis(200));
+        let seeked = frame_numbers(Segment::new(numbered(), start, length));
+        assert_eq!(seeked, (100..300).collect::<Vec<_>>());
+
+        // Çözücü sarılamıyorsa aynı aralık okunarak bulunur
+        let skipped = frame_numbers(Segment::new(Unseekable(numbered()), start, length));
+        assert_eq!(skipped, seeked);
+
+        // Bitişi olmayan aralık dosyanın sonuna kadar sürer
+        let rest = frame_numbers(Segment::new(numbered(), Duration::from_millis(900), None));
+        assert_eq!(rest, (900..1000).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn segment_end_can_change_while_playing() {
+        let mut segment = Segment::new(numbered(), Duration::ZERO, Some(Duration::from_millis(50)));
+        let end = segment.end();
+        let first = frame_numbers(segment.by_ref().take(20));
+        assert_eq!(first, (0..10).collect::<Vec<_>>());
+
+        // Sonraki parça aynı dosyada devam ediyorsa bitiş uzatılır
+        end.set(Some(Duration::from_millis(120)));
+        let rest = frame_numbers(segment);
+        assert_eq!(rest, (10..120).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn segment_seek_is_relative_to_start() {
+        let mut segment = Segment::new(
+            numbered(),
+            Duration::from_millis(100),
+            Some(Duration::from_millis(200)),
+        );
+        segment.try_seek(Duration::from_millis(150)).unwrap();
+        let frames = frame_numbers(segment);
+        assert_eq!(frames, (250..300).collect::<Vec<_>>());
+    }
+}
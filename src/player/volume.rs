@@ -0,0 +1,27 @@
+use serde::Deserialize;
+
+/// Logaritmik eğride sıfırın hemen üstündeki seviyenin zayıflatması (dB)
+const LOG_RANGE_DB: f32 = 50.0;
+
+/// Ses seviyesinin (0.0 - 1.0) çıkış kazancına nasıl çevrileceği
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum VolumeCurve {
+    /// Seviye doğrudan kazanç olarak kullanılır
+    #[default]
+    Linear,
+    /// Seviye desibel ölçeğinde dağıtılır; kulağa daha eşit adımlar verir
+    Logarithmic,
+}
+
+impl VolumeCurve {
+    /// Seviyeye karşılık gelen kazanç
+    pub fn gain(self, level: f32) -> f32 {
+        let level = level.clamp(0.0, 1.0);
+        match self {
+            VolumeCurve::Linear => level,
+            VolumeCurve::Logarithmic if level <= 0.0 => 0.0,
+            VolumeCurve::Logarithmic => 10f32.powf(LOG_RANGE_DB * (level - 1.0) / 20.0),
+        }
+    }
+}
@@ -0,0 +1,158 @@
+use std::fmt::Write as _;
+use std::io;
+use std::path::Path;
+use std::time::Duration;
+
+use super::{PlaylistEntry, PlaylistFormat, relative_path, resolve_path};
+
+/// M3U ve M3U8 (UTF-8) çalma listeleri
+pub struct M3u;
+
+impl PlaylistFormat for M3u {
+    fn extensions(&self) -> &'static [&'static str] {
+        &["m3u8", "m3u"]
+    }
+
+    fn parse(&self, text: &str, base: &Path) -> io::Result<Vec<PlaylistEntry>> {
+        Ok(parse(text, base))
+    }
+
+    fn format(&self, _name: &str, entries: &[PlaylistEntry], base: &Path) -> String {
+        format(entries, base)
+    }
+}
+
+/// M3U metnini ayrıştır; `#EXTINF` bilgisi sonraki satırdaki dosyaya uygulanır
+fn parse(text: &str, base: &Path) -> Vec<PlaylistEntry> {
+    let mut entries = Vec::new();
+    let mut info: Option<(Option<Duration>, Option<String>)> = None;
+    for line in text.lines() {
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        if let Some(extinf) = line.strip_prefix("#EXTINF:") {
+            info = Some(parse_extinf(extinf));
+            continue;
+        }
+        if line.starts_with('#') {
+            continue;
+        }
+        let (duration, title) = info.take().unwrap_or_default();
+        entries.push(PlaylistEntry {
+            path: resolve_path(base, line),
+            title,
+            duration,
+        });
+    }
+    entries
+}
+
+/// `#EXTINF:123,Sanatçı - Başlık` satırını ayrıştır; -1 süre bilinmiyor demektir
+fn parse_extinf(extinf: &str) -> (Option<Duration>, Option<String>) {
+    let (head, title) = extinf.split_once(',').unwrap_or((extinf, ""));
+    // Süreden sonra `tvg-id="..."` gibi nitelikler gelebilir
+    let duration = head
+        .split_whitespace()
+        .next()
+        .and_then(|secs| secs.parse::<f64>().ok())
+        .filter(|secs| *secs >= 0.0 && secs.is_finite())
+        .map(Duration::from_secs_f64);
+    let title = Some(title.trim().to_string()).filter(|title| !title.is_empty());
+    (duration, title)
+}
+
+/// Satırları M3U8 metnine çevir; yollar listenin klasörüne göre göreli yazılır.
+/// Süreler tam saniyeye yuvarlanır: çoğu oynatıcı `#EXTINF` süresini tamsayı okur
+fn format(entries: &[PlaylistEntry], base: &Path) -> String {
+    let mut text = String::from("#EXTM3U\n");
+    for entry in entries {
+        if entry.title.is_some() || entry.duration.is_some() {
+            let secs = entry
+                .duration
+                .map_or(-1, |d| d.as_secs_f64().round() as i64);
+            let _ = writeln!(
+                text,
+                "#EXTINF:{},{}",
+                secs,
+                entry.title.as_deref().unwrap_or("")
+            );
+        }
+        let _ = writeln!(text, "{}", relative_path(&entry.path, base).display());
+    }
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::playlist::tests::{temp_dir, touch};
+
+    #[test]
+    fn round_trip_keeps_title_duration_and_relative_paths() {
+        let dir = temp_dir("m3u");
+        let base = dir.join("lists");
+        let entries = vec![
+            PlaylistEntry {
+                path: dir.join("Miles Davis/01 So What.flac"),
+                title: Some("Miles Davis - So What".to_string()),
+                duration: Some(Duration::from_millis(562_400)),
+            },
+            PlaylistEntry {
+                path: base.join("local.mp3"),
+                title: None,
+                duration: None,
+            },
+        ];
+        for entry in &entries {
+            touch(&entry.path);
+        }
+
+        let text = format(&entries, &base);
+        assert_eq!(
+            text,
+            "#EXTM3U\n#EXTINF:562,Miles Davis - So What\n../Miles Davis/01 So What.flac\nlocal.mp3\n"
+        );
+
+        let parsed = parse(&text, &base);
+        assert_eq!(parsed.len(), 2);
+        assert_eq!(parsed[0].path, entries[0].path);
+        assert_eq!(parsed[0].title, entries[0].title);
+        // Süre tam saniyeye yuvarlanır
+        let lost = parsed[0]
+            .duration
+            .unwrap()
+            .abs_diff(entries[0].duration.unwrap());
+        assert!(lost <= Duration::from_millis(500));
+        assert_eq!(parsed[1], entries[1]);
+    }
+
+    #[test]
+    fn extinf_attributes_and_unknown_duration() {
+        let base = Path::new("/music");
+        let text = "#EXTM3U\n\
+                    #EXTINF:-1 tvg-id=\"x\",Radio\n\
+                    http://stream\n\
+                    #EXTINF:12.5,\n\
+                    # yorum\n\
+                    a.flac\n\
+                    b.flac\n";
+        let parsed = parse(text, base);
+        assert_eq!(parsed.len(), 3);
+        assert_eq!(parsed[0].title.as_deref(), Some("Radio"));
+        assert_eq!(parsed[0].duration, None);
+        assert_eq!(parsed[1].title, None);
+        assert_eq!(parsed[1].duration, Some(Duration::from_millis(12_500)));
+        // `#EXTINF` yalnızca hemen sonraki dosyaya uygulanır
+        assert_eq!(parsed[2].path, Path::new("/music/b.flac"));
+        assert_eq!(parsed[2].duration, None);
+    }
+
+    #[test]
+    fn missing_file_is_kept() {
+        let parsed = parse("#EXTINF:10,Gone\nnowhere/gone.flac\n", Path::new("/music"));
+        assert_eq!(parsed.len(), 1);
+        assert_eq!(parsed[0].path, Path::new("/music/nowhere/gone.flac"));
+        assert_eq!(parsed[0].describe(), "Gone");
+    }
+}
@@ -0,0 +1,292 @@
+pub mod m3u;
+pub mod pls;
+pub mod smart;
+pub mod store;
+pub mod xspf;
+
+use std::fs;
+use std::io;
+use std::path::{Component, Path, PathBuf};
+use std::time::Duration;
+
+use crate::library::Track;
+
+/// Çalma listesindeki tek bir satır
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlaylistEntry {
+    /// Tam yol (göreli yollar listenin klasörüne göre çözülür)
+    pub path: PathBuf,
+    /// Listede yazan başlık (ör. `#EXTINF`)
+    pub title: Option<String>,
+    pub duration: Option<Duration>,
+}
+
+impl PlaylistEntry {
+    /// Parçadan liste satırı oluştur
+    pub fn from_track(track: &Track) -> PlaylistEntry {
+        PlaylistEntry {
+            path: track.path.clone(),
+            title: Some(track.display_name()),
+            duration: track.duration,
+        }
+    }
+
+    /// Satırın günlükte gösterilen adı: başlık, yoksa yol
+    pub fn describe(&self) -> String {
+        match &self.title {
+            Some(title) => title.clone(),
+            None if self.path.as_os_str().is_empty() => "(no location)".to_string(),
+            None => self.path.display().to_string(),
+        }
+    }
+}
+
+/// Diskten okunan bir çalma listesi
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Playlist {
+    pub name: String,
+    pub entries: Vec<PlaylistEntry>,
+}
+
+/// Çalma listesi biçimi: dosya metnini satırlara, satırları dosya metnine çevirir
+pub trait PlaylistFormat: Sync {
+    /// Biçimin dosya uzantıları (küçük harf)
+    fn extensions(&self) -> &'static [&'static str];
+
+    /// Metni ayrıştır; göreli yollar `base` klasörüne göre çözülür
+    fn parse(&self, text: &str, base: &Path) -> io::Result<Vec<PlaylistEntry>>;
+
+    /// Satırları metne çevir; yollar mümkünse `base` klasörüne göre göreli yazılır
+    fn format(&self, name: &str, entries: &[PlaylistEntry], base: &Path) -> String;
+}
+
+/// Desteklenen biçimler
+const FORMATS: &[&dyn PlaylistFormat] = &[&m3u::M3u, &pls::Pls, &xspf::Xspf];
+
+/// Dosya uzantısına göre biçim
+pub fn format_for(path: &Path) -> Option<&'static dyn PlaylistFormat> {
+    let extension = extension(path)?;
+    FORMATS
+        .iter()
+        .copied()
+        .find(|format| format.extensions().contains(&extension.as_str()))
+}
+
+/// Çalma listesi dosyası mı? (uzantıya göre)
+pub fn is_playlist(path: &Path) -> bool {
+    format_for(path).is_some()
+}
+
+fn unsupported() -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidInput,
+        "unsupported playlist format (use .m3u8, .m3u, .pls or .xspf)",
+    )
+}
+
+/// Çalma listesi dosyasını uzantısına göre oku; UTF-8 olmayan dosyalar Latin-1 sayılır
+pub fn load(path: &Path) -> io::Result<Playlist> {
+    let format = format_for(path).ok_or_else(unsupported)?;
+    let bytes = fs::read(path)?;
+    let text = match String::from_utf8(bytes) {
+        Ok(text) => text,
+        Err(e) => e.into_bytes().iter().map(|&b| b as char).collect(),
+    };
+    let base = path.parent().unwrap_or(Path::new("."));
+    Ok(Playlist {
+        name: name_from_path(path),
+        entries: format.parse(text.trim_start_matches('\u{feff}'), base)?,
+    })
+}
+
+/// Parçaları uzantının belirttiği biçimde kaydet
+pub fn save(path: &Path, tracks: &[Track]) -> io::Result<()> {
+    let format = format_for(path).ok_or_else(unsupported)?;
+    let entries: Vec<PlaylistEntry> = tracks.iter().map(PlaylistEntry::from_track).collect();
+    let base = match path.parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => {
+            fs::create_dir_all(parent)?;
+            parent.canonicalize()?
+        }
+        _ => std::env::current_dir()?,
+    };
+    fs::write(path, format.format(&name_from_path(path), &entries, &base))
+}
+
+/// Dosya adından liste adı
+pub fn name_from_path(path: &Path) -> String {
+    path.file_stem()
+        .map(|stem| stem.to_string_lossy().into_owned())
+        .unwrap_or_else(|| path.display().to_string())
+}
+
+fn extension(path: &Path) -> Option<String> {
+    path.extension()
+        .and_then(|ext| ext.to_str())
+        .map(str::to_lowercase)
+}
+
+/// Listede yazan yolu listenin klasörüne göre çöz; dosya varsa kütüphanedeki gibi tam yola çevrilir
+pub fn resolve_path(base: &Path, entry: &str) -> PathBuf {
+    // Windows'ta yazılmış listelerdeki ters bölü işaretleri
+    let entry = if cfg!(windows) {
+        entry.to_string()
+    } else {
+        entry.replace('\\', "/")
+    };
+    // file:// adresleri yüzde kodlamalıdır
+    let entry = match entry.strip_prefix("file://") {
+        Some(path) => percent_decode(path),
+        None => entry,
+    };
+    let path = base.join(entry);
+    path.canonicalize().unwrap_or(path)
+}
+
+/// Yolu listenin klasörüne göre göreli yaz; yalnızca kök klasör ortaksa tam yol kalır
+pub fn relative_path(path: &Path, base: &Path) -> PathBuf {
+    let path_parts: Vec<Component> = path.components().collect();
+    let base_parts: Vec<Component> = base.components().collect();
+    let common = path_parts
+        .iter()
+        .zip(&base_parts)
+        .take_while(|(a, b)| a == b)
+        .count();
+    let shares_folder = path_parts[..common]
+        .iter()
+        .any(|part| matches!(part, Component::Normal(_)));
+    if !shares_folder {
+        return path.to_path_buf();
+    }
+    let mut relative = PathBuf::new();
+    for _ in common..base_parts.len() {
+        relative.push("..");
+    }
+    relative.extend(&path_parts[common..]);
+    relative
+}
+
+/// `%20` gibi yüzde kodlarını çöz; geçersiz kodlar olduğu gibi kalır
+pub fn percent_decode(text: &str) -> String {
+    let bytes = text.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        let hex = bytes
+            .get(i + 1..i + 3)
+            .and_then(|hex| std::str::from_utf8(hex).ok())
+            .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+        match (bytes[i], hex) {
+            (b'%', Some(byte)) => {
+                decoded.push(byte);
+                i += 3;
+            }
+            (byte, _) => {
+                decoded.push(byte);
+                i += 1;
+            }
+        }
+    }
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
+/// Yolu URI'de kullanılabilecek biçimde yüzde kodla (`/` korunur)
+pub fn percent_encode(path: &str) -> String {
+    let mut encoded = String::with_capacity(path.len());
+    for byte in path.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' | b'/' => {
+                encoded.push(byte as char)
+            }
+            _ => encoded.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    encoded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::library::{Library, ScanFilter};
+
+    /// Teste özel geçici klasör (tam yol; biçim testleri de kullanır)
+    pub(super) fn temp_dir(name: &str) -> PathBuf {
+        let dir =
+            std::env::temp_dir().join(format!("meloic-playlist-{}-{}", name, std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        dir.canonicalize().unwrap()
+    }
+
+    /// Boş bir dosya oluştur (üst klasörleriyle)
+    pub(super) fn touch(path: &Path) {
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, b"").unwrap();
+    }
+
+    #[test]
+    fn relative_path_walks_up_from_list_folder() {
+        let base = Path::new("/music/lists");
+        assert_eq!(
+            relative_path(Path::new("/music/lists/a.flac"), base),
+            Path::new("a.flac")
+        );
+        assert_eq!(
+            relative_path(Path::new("/music/Artist/b.flac"), base),
+            Path::new("../Artist/b.flac")
+        );
+        // Yalnızca kök klasör ortaksa tam yol kalır
+        assert_eq!(
+            relative_path(Path::new("/other/c.flac"), base),
+            Path::new("/other/c.flac")
+        );
+    }
+
+    #[test]
+    fn percent_encoding_round_trips() {
+        let path = "/müzik/Miles Davis/01 So What (1959).flac";
+        let encoded = percent_encode(path);
+        assert_eq!(
+            encoded,
+            "/m%C3%BCzik/Miles%20Davis/01%20So%20What%20%281959%29.flac"
+        );
+        assert_eq!(percent_decode(&encoded), path);
+        // Geçersiz kodlar olduğu gibi kalır
+        assert_eq!(percent_decode("100%zz%2"), "100%zz%2");
+    }
+
+    #[test]
+    fn unresolvable_entries_are_reported_not_dropped() {
+        let dir = temp_dir("report");
+        let lists = [
+            ("list.m3u8", "#EXTM3U\n#EXTINF:10,Gone\nmissing.flac\n"),
+            (
+                "list.pls",
+                "[playlist]\nFile1=missing.flac\nTitle1=Gone\nLength1=10\n",
+            ),
+            (
+                "list.xspf",
+                "<playlist><trackList><track><location>missing.flac</location>\
+                 <title>Gone</title></track></trackList></playlist>",
+            ),
+        ];
+        for (name, text) in lists {
+            let path = dir.join(name);
+            fs::write(&path, text).unwrap();
+
+            // Satır okunur, yalnızca dosyası yoktur
+            let playlist = load(&path).unwrap();
+            assert_eq!(playlist.entries.len(), 1, "{}", name);
+            assert_eq!(playlist.entries[0].path, dir.join("missing.flac"));
+            assert!(!playlist.entries[0].path.is_file());
+
+            // Kütüphaneye eklenirken uyarı olarak bildirilir
+            let mut library = Library::with_index(Vec::new(), ScanFilter::default(), None);
+            let (tracks, warnings) = library.load_paths(std::slice::from_ref(&path));
+            assert!(tracks.is_empty(), "{}", name);
+            assert_eq!(
+                warnings,
+                vec![format!("{}: entry not found: Gone", path.display())]
+            );
+        }
+    }
+}
@@ -0,0 +1,165 @@
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::io;
+use std::path::Path;
+use std::time::Duration;
+
+use super::{PlaylistEntry, PlaylistFormat, relative_path, resolve_path};
+
+/// Aynı sıra numaralı anahtarlardan okunan alanlar
+#[derive(Default)]
+struct EntryFields {
+    file: Option<String>,
+    title: Option<String>,
+    duration: Option<Duration>,
+}
+
+/// Winamp/Shoutcast tarzı PLS çalma listeleri (`File1=`, `Title1=`, `Length1=`)
+pub struct Pls;
+
+impl PlaylistFormat for Pls {
+    fn extensions(&self) -> &'static [&'static str] {
+        &["pls"]
+    }
+
+    fn parse(&self, text: &str, base: &Path) -> io::Result<Vec<PlaylistEntry>> {
+        // Anahtarlar sıra numarasıyla gruplanır; dosyada herhangi bir sırada olabilirler
+        let mut files: BTreeMap<u32, EntryFields> = BTreeMap::new();
+        for line in text.lines() {
+            let Some((key, value)) = line.trim().split_once('=') else {
+                continue;
+            };
+            let key = key.trim().to_lowercase();
+            let value = value.trim();
+            let (field, number) =
+                key.split_at(key.trim_end_matches(|c: char| c.is_ascii_digit()).len());
+            let Ok(number) = number.parse::<u32>() else {
+                continue;
+            };
+            let entry = files.entry(number).or_default();
+            match field {
+                "file" => entry.file = Some(value.to_string()),
+                "title" if !value.is_empty() => entry.title = Some(value.to_string()),
+                // -1 süre bilinmiyor demektir
+                "length" => {
+                    entry.duration = value
+                        .parse::<f64>()
+                        .ok()
+                        .filter(|secs| *secs >= 0.0 && secs.is_finite())
+                        .map(Duration::from_secs_f64)
+                }
+                _ => {}
+            }
+        }
+
+        // Dosyası olmayan satırlar atılmaz, bulunamayan satır olarak gösterilir
+        Ok(files
+            .into_values()
+            .map(|fields| PlaylistEntry {
+                path: fields
+                    .file
+                    .map(|file| resolve_path(base, &file))
+                    .unwrap_or_default(),
+                title: fields.title,
+                duration: fields.duration,
+            })
+            .collect())
+    }
+
+    fn format(&self, _name: &str, entries: &[PlaylistEntry], base: &Path) -> String {
+        let mut text = String::from("[playlist]\n");
+        for (i, entry) in entries.iter().enumerate() {
+            let n = i + 1;
+            let _ = writeln!(
+                text,
+                "File{}={}",
+                n,
+                relative_path(&entry.path, base).display()
+            );
+            if let Some(title) = &entry.title {
+                let _ = writeln!(text, "Title{}={}", n, title);
+            }
+            // `LengthN` tamsayı saniyedir; saniyenin altı kaybolur
+            let secs = entry
+                .duration
+                .map_or(-1, |d| d.as_secs_f64().round() as i64);
+            let _ = writeln!(text, "Length{}={}", n, secs);
+        }
+        let _ = writeln!(text, "NumberOfEntries={}", entries.len());
+        text.push_str("Version=2\n");
+        text
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    use crate::playlist::tests::{temp_dir, touch};
+
+    #[test]
+    fn round_trip_keeps_title_duration_and_relative_paths() {
+        let dir = temp_dir("pls");
+        let base = dir.join("lists");
+        let entries = vec![
+            PlaylistEntry {
+                path: dir.join("John Coltrane/Blue Train.flac"),
+                title: Some("John Coltrane - Blue Train".to_string()),
+                duration: Some(Duration::from_millis(643_700)),
+            },
+            PlaylistEntry {
+                path: base.join("local.mp3"),
+                title: None,
+                duration: None,
+            },
+        ];
+        for entry in &entries {
+            touch(&entry.path);
+        }
+
+        let text = Pls.format("jazz", &entries, &base);
+        assert!(text.contains("File1=../John Coltrane/Blue Train.flac\n"));
+        assert!(text.contains("Length1=644\n"));
+        assert!(text.contains("File2=local.mp3\nLength2=-1\n"));
+        assert!(text.contains("NumberOfEntries=2\n"));
+
+        let parsed = Pls.parse(&text, &base).unwrap();
+        assert_eq!(parsed.len(), 2);
+        assert_eq!(parsed[0].path, entries[0].path);
+        assert_eq!(parsed[0].title, entries[0].title);
+        // Süre tam saniyeye yuvarlanır
+        let lost = parsed[0]
+            .duration
+            .unwrap()
+            .abs_diff(entries[0].duration.unwrap());
+        assert!(lost <= Duration::from_millis(500));
+        assert_eq!(parsed[1], entries[1]);
+    }
+
+    #[test]
+    fn keys_are_grouped_by_number_in_any_order() {
+        let text = "[playlist]\n\
+                    Title2=Second\n\
+                    file2=b.flac\n\
+                    FILE1=a.flac\n\
+                    Length1=30\n\
+                    NumberOfEntries=2\n";
+        let parsed = Pls.parse(text, Path::new("/music")).unwrap();
+        assert_eq!(parsed.len(), 2);
+        assert_eq!(parsed[0].path, Path::new("/music/a.flac"));
+        assert_eq!(parsed[0].duration, Some(Duration::from_secs(30)));
+        assert_eq!(parsed[1].path, Path::new("/music/b.flac"));
+        assert_eq!(parsed[1].title.as_deref(), Some("Second"));
+    }
+
+    #[test]
+    fn entry_without_file_is_kept() {
+        let text = "[playlist]\nTitle1=Gone\nFile2=gone.flac\n";
+        let parsed = Pls.parse(text, Path::new("/music")).unwrap();
+        assert_eq!(parsed.len(), 2);
+        assert_eq!(parsed[0].path, PathBuf::new());
+        assert_eq!(parsed[0].describe(), "Gone");
+        assert_eq!(parsed[1].path, Path::new("/music/gone.flac"));
+    }
+}
@@ -0,0 +1,778 @@
+use rand::seq::SliceRandom;
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+
+use crate::library::Track;
+use crate::library::history::PlayHistory;
+
+/// Kurallarla tanımlanan, kütüphane her tarandığında yeniden doldurulan çalma listesi
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SmartPlaylist {
+    pub name: String,
+    /// Kurallar, ör. `genre = jazz AND year < 1970`
+    pub rules: String,
+    /// Sıralama, ör. `year`, `added desc` veya `random`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sort: Option<String>,
+    /// En fazla kaç parça alınır
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub limit: Option<usize>,
+}
+
+impl SmartPlaylist {
+    /// Alt satırda yazılan tanımdan oluştur: `kurallar; sort <alan> [asc|desc]; limit <sayı>`
+    pub fn from_text(name: &str, text: &str) -> Result<SmartPlaylist, String> {
+        let mut parts = text.split(';').map(str::trim);
+        let mut playlist = SmartPlaylist {
+            name: name.trim().to_string(),
+            rules: parts.next().unwrap_or_default().to_string(),
+            sort: None,
+            limit: None,
+        };
+        for part in parts.filter(|part| !part.is_empty()) {
+            let (key, value) = part.split_once(char::is_whitespace).unwrap_or((part, ""));
+            match key.to_lowercase().as_str() {
+                "sort" => playlist.sort = Some(value.trim().to_string()),
+                "limit" => {
+                    let limit = value
+                        .trim()
+                        .parse()
+                        .map_err(|_| format!("'{}' is not a valid limit", value.trim()))?;
+                    playlist.limit = Some(limit);
+                }
+                _ => {
+                    return Err(format!(
+                        "unknown option '{}' (expected 'sort <field> [asc|desc]' or 'limit <n>')",
+                        part
+                    ));
+                }
+            }
+        }
+        playlist.validate()?;
+        Ok(playlist)
+    }
+
+    /// Kuralları, sıralamayı ve sınırı kontrol et
+    pub fn validate(&self) -> Result<(), String> {
+        if self.name.trim().is_empty() {
+            return Err("smart playlist name must not be empty".to_string());
+        }
+        parse_rules(&self.rules)?;
+        if let Some(sort) = &self.sort {
+            parse_sort(sort)?;
+        }
+        if self.limit == Some(0) {
+            return Err("limit must be at least 1".to_string());
+        }
+        Ok(())
+    }
+
+    /// Kurallara uyan parçaların listedeki sıraları; varsa sıralanır ve sınırlanır
+    pub fn evaluate(
+        &self,
+        tracks: &[Track],
+        history: &PlayHistory,
+        now: u64,
+    ) -> Result<Vec<usize>, String> {
+        let query = parse_rules(&self.rules)?;
+        let sort = self.sort.as_deref().map(parse_sort).transpose()?;
+
+        let mut matches: Vec<usize> = tracks
+            .iter()
+            .enumerate()
+            .filter(|(_, track)| query.matches(track, history.count(&track.id()), now))
+            .map(|(i, _)| i)
+            .collect();
+        if let Some(sort) = sort {
+            sort.apply(&mut matches, tracks, history);
+        }
+        if let Some(limit) = self.limit {
+            matches.truncate(limit);
+        }
+        Ok(matches)
+    }
+}
+
+/// Kuralların kullanabildiği parça alanları
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Field {
+    Title,
+    Artist,
+    Album,
+    Genre,
+    Year,
+    Rating,
+    Plays,
+    /// Kurallarda kaç gün önce eklendiği, sıralamada eklenme zamanı
+    Added,
+}
+
+impl Field {
+    const NAMES: &'static [(&'static str, Field)] = &[
+        ("title", Field::Title),
+        ("artist", Field::Artist),
+        ("album", Field::Album),
+        ("genre", Field::Genre),
+        ("year", Field::Year),
+        ("rating", Field::Rating),
+        ("plays", Field::Plays),
+        ("added", Field::Added),
+    ];
+
+    fn parse(name: &str) -> Result<Field, String> {
+        let name = name.to_lowercase();
+        Self::NAMES
+            .iter()
+            .find(|(n, _)| *n == name)
+            .map(|(_, field)| *field)
+            .ok_or_else(|| {
+                format!(
+                    "unknown field '{}' (expected one of: {})",
+                    name,
+                    Self::NAMES
+                        .iter()
+                        .map(|(n, _)| *n)
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                )
+            })
+    }
+
+    fn is_text(self) -> bool {
+        matches!(
+            self,
+            Field::Title | Field::Artist | Field::Album | Field::Genre
+        )
+    }
+
+    fn text(self, track: &Track) -> Option<&str> {
+        match self {
+            Field::Title => Some(&track.title),
+            Field::Artist => track.artist.as_deref(),
+            Field::Album => track.album.as_deref(),
+            Field::Genre => track.genre.as_deref(),
+            _ => None,
+        }
+    }
+
+    fn number(self, track: &Track, plays: u32) -> Option<f64> {
+        match self {
+            Field::Year => track.year.map(f64::from),
+            Field::Rating => track.rating.map(f64::from),
+            Field::Plays => Some(f64::from(plays)),
+            Field::Added => track.added.map(|added| added as f64),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Op {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+    /// Metin alanı verilen metni içeriyor mu?
+    Contains,
+}
+
+impl Op {
+    fn symbol(self) -> &'static str {
+        match self {
+            Op::Eq => "=",
+            Op::Ne => "!=",
+            Op::Lt => "<",
+            Op::Le => "<=",
+            Op::Gt => ">",
+            Op::Ge => ">=",
+            Op::Contains => "~",
+        }
+    }
+
+    fn compare(self, ordering: Ordering) -> bool {
+        match self {
+            Op::Eq => ordering == Ordering::Equal,
+            Op::Ne => ordering != Ordering::Equal,
+            Op::Lt => ordering == Ordering::Less,
+            Op::Le => ordering != Ordering::Greater,
+            Op::Gt => ordering == Ordering::Greater,
+            Op::Ge => ordering != Ordering::Less,
+            Op::Contains => false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Value {
+    /// Küçük harfli metin
+    Text(String),
+    Number(f64),
+}
+
+/// `alan işleç değer` biçimindeki tek kural
+#[derive(Debug, Clone, PartialEq)]
+struct Rule {
+    field: Field,
+    op: Op,
+    value: Value,
+}
+
+impl Rule {
+    /// Alanı boş olan parçalar yalnızca `!=` kurallarına uyar
+    fn matches(&self, track: &Track, plays: u32, now: u64) -> bool {
+        match &self.value {
+            Value::Text(value) => match self.field.text(track) {
+                None => self.op == Op::Ne,
+                Some(text) => {
+                    let text = text.to_lowercase();
+                    match self.op {
+                        Op::Contains => text.contains(value.as_str()),
+                        op => op.compare(text.as_str().cmp(value.as_str())),
+                    }
+                }
+            },
+            Value::Number(value) => {
+                let number = match self.field {
+                    // Eklenme zamanı kaç gün önce olduğuna çevrilir
+                    Field::Added => track
+                        .added
+                        .map(|added| now.saturating_sub(added) as f64 / 86_400.0),
+                    field => field.number(track, plays),
+                };
+                match number.and_then(|number| number.partial_cmp(value)) {
+                    Some(ordering) => self.op.compare(ordering),
+                    None => self.op == Op::Ne,
+                }
+            }
+        }
+    }
+}
+
+/// VEYA ile bağlanmış, kendi içinde VE ile bağlanmış kural grupları
+#[derive(Debug, Clone, PartialEq)]
+struct Query {
+    groups: Vec<Vec<Rule>>,
+}
+
+impl Query {
+    fn matches(&self, track: &Track, plays: u32, now: u64) -> bool {
+        self.groups
+            .iter()
+            .any(|group| group.iter().all(|rule| rule.matches(track, plays, now)))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Word(String),
+    /// Tırnak içindeki metin (ör. "rock and roll")
+    Quoted(String),
+    Op(Op),
+}
+
+impl Token {
+    fn text(&self) -> String {
+        match self {
+            Token::Word(word) => word.clone(),
+            Token::Quoted(text) => format!("\"{}\"", text),
+            Token::Op(op) => op.symbol().to_string(),
+        }
+    }
+}
+
+/// Kural metnini kelime, tırnaklı metin ve işleçlere ayır
+fn tokenize(text: &str) -> Result<Vec<Token>, String> {
+    let is_op = |c: char| matches!(c, '=' | '!' | '<' | '>' | '~');
+    let mut tokens = Vec::new();
+    let mut chars = text.chars().peekable();
+    while let Some(&c) = chars.peek() {
+        if c.is_whitespace() {
+            chars.next();
+        } else if c == '"' || c == '\'' {
+            chars.next();
+            let mut quoted = String::new();
+            let mut closed = false;
+            for next in chars.by_ref() {
+                if next == c {
+                    closed = true;
+                    break;
+                }
+                quoted.push(next);
+            }
+            if !closed {
+                return Err(format!("unclosed quote in '{}'", text));
+            }
+            tokens.push(Token::Quoted(quoted));
+        } else if is_op(c) {
+            chars.next();
+            let equals = chars.next_if_eq(&'=').is_some();
+            let op = match (c, equals) {
+                ('=', _) => Op::Eq,
+                ('!', true) => Op::Ne,
+                ('<', true) => Op::Le,
+                ('<', false) => Op::Lt,
+                ('>', true) => Op::Ge,
+                ('>', false) => Op::Gt,
+                ('~', _) => Op::Contains,
+                _ => return Err(format!("expected '!=' in '{}'", text)),
+            };
+            tokens.push(Token::Op(op));
+        } else {
+            let mut word = String::new();
+            while let Some(next) = chars.next_if(|&next| !next.is_whitespace() && !is_op(next)) {
+                word.push(next);
+            }
+            tokens.push(Token::Word(word));
+        }
+    }
+    Ok(tokens)
+}
+
+/// `genre = jazz AND year < 1970 OR never played` gibi kuralları ayrıştır; VE, VEYA'dan önce bağlanır
+fn parse_rules(text: &str) -> Result<Query, String> {
+    let tokens = tokenize(text)?;
+    if tokens.is_empty() {
+        return Err("rules must not be empty (e.g. \"genre = jazz AND year < 1970\")".to_string());
+    }
+    let mut groups = Vec::new();
+    let mut group = Vec::new();
+    let mut clause = Vec::new();
+    for token in tokens {
+        match &token {
+            Token::Word(word) if word.eq_ignore_ascii_case("and") => {
+                group.push(parse_clause(std::mem::take(&mut clause), "AND")?);
+            }
+            Token::Word(word) if word.eq_ignore_ascii_case("or") => {
+                group.push(parse_clause(std::mem::take(&mut clause), "OR")?);
+                groups.push(std::mem::take(&mut group));
+            }
+            _ => clause.push(token),
+        }
+    }
+    group.push(parse_clause(clause, "the end")?);
+    groups.push(group);
+    Ok(Query { groups })
+}
+
+/// Tek kuralı ayrıştır; `never played` ve `added in last 30 days` kısaltmaları da kabul edilir
+fn parse_clause(tokens: Vec<Token>, before: &str) -> Result<Rule, String> {
+    let words: Vec<String> = tokens.iter().map(|t| t.text().to_lowercase()).collect();
+    let words: Vec<&str> = words.iter().map(String::as_str).collect();
+    match words.as_slice() {
+        [] => return Err(format!("missing rule before {}", before)),
+        ["never", "played"] => {
+            return Ok(Rule {
+                field: Field::Plays,
+                op: Op::Eq,
+                value: Value::Number(0.0),
+            });
+        }
+        ["added", "in", "last", days] | ["added", "in", "last", days, "day" | "days"] => {
+            return Ok(Rule {
+                field: Field::Added,
+                op: Op::Le,
+                value: Value::Number(parse_days(days)?),
+            });
+        }
+        _ => {}
+    }
+
+    let clause = words.join(" ");
+    let (field, op, value) = match tokens.as_slice() {
+        [Token::Word(field), Token::Op(op), value @ ..] if !value.is_empty() => (field, *op, value),
+        _ => {
+            return Err(format!(
+                "expected '<field> <operator> <value>' but got '{}'",
+                clause
+            ));
+        }
+    };
+    let field = Field::parse(field)?;
+    let value: Vec<String> = value
+        .iter()
+        .map(|token| match token {
+            Token::Quoted(text) => text.clone(),
+            token => token.text(),
+        })
+        .collect();
+    let value = value.join(" ");
+
+    let value = if field.is_text() {
+        if !matches!(op, Op::Eq | Op::Ne | Op::Contains) {
+            return Err(format!(
+                "'{}' only works with numbers, use =, != or ~ in '{}'",
+                op.symbol(),
+                clause
+            ));
+        }
+        Value::Text(value.to_lowercase())
+    } else {
+        if op == Op::Contains {
+            return Err(format!("'~' only works with text in '{}'", clause));
+        }
+        let number = match field {
+            Field::Added => parse_days(&value)?,
+            _ => value
+                .parse()
+                .map_err(|_| format!("'{}' is not a number in '{}'", value, clause))?,
+        };
+        Value::Number(number)
+    };
+    Ok(Rule { field, op, value })
+}
+
+/// Gün sayısı: `30`, `30d` veya `30 days`
+fn parse_days(text: &str) -> Result<f64, String> {
+    let number = text
+        .trim()
+        .trim_end_matches("days")
+        .trim_end_matches("day")
+        .trim_end_matches('d')
+        .trim();
+    number
+        .parse()
+        .map_err(|_| format!("'{}' is not a number of days", text))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Sort {
+    Field { field: Field, descending: bool },
+    Random,
+}
+
+/// `year`, `added desc` veya `random` gibi sıralamayı ayrıştır
+fn parse_sort(text: &str) -> Result<Sort, String> {
+    let words: Vec<String> = text.split_whitespace().map(str::to_lowercase).collect();
+    match words
+        .iter()
+        .map(String::as_str)
+        .collect::<Vec<_>>()
+        .as_slice()
+    {
+        ["random"] => Ok(Sort::Random),
+        [field] => Ok(Sort::Field {
+            field: Field::parse(field)?,
+            descending: false,
+        }),
+        [field, order @ ("asc" | "desc")] => Ok(Sort::Field {
+            field: Field::parse(field)?,
+            descending: *order == "desc",
+        }),
+        _ => Err(format!(
+            "invalid sort '{}' (expected '<field> [asc|desc]' or 'random')",
+            text
+        )),
+    }
+}
+
+/// Sıralama değeri; boş alanlar her iki yönde de sona konur
+#[derive(Debug, Clone, PartialEq, PartialOrd)]
+enum SortValue {
+    Text(String),
+    Number(f64),
+}
+
+impl Sort {
+    fn apply(self, indices: &mut Vec<usize>, tracks: &[Track], history: &PlayHistory) {
+        let (field, descending) = match self {
+            Sort::Random => {
+                indices.shuffle(&mut rand::thread_rng());
+                return;
+            }
+            Sort::Field { field, descending } => (field, descending),
+        };
+        // Anahtarlar bir kez hesaplanır; eşit parçalar kütüphane sırasını korur
+        let mut keyed: Vec<(Option<SortValue>, usize)> = indices
+            .iter()
+            .map(|&i| {
+                let track = &tracks[i];
+                let value = if field.is_text() {
+                    field
+                        .text(track)
+                        .map(|text| SortValue::Text(text.to_lowercase()))
+                } else {
+                    field
+                        .number(track, history.count(&track.id()))
+                        .map(SortValue::Number)
+                };
+                (value, i)
+            })
+            .collect();
+        keyed.sort_by(|(a, _), (b, _)| match (a, b) {
+            (Some(a), Some(b)) => {
+                let ordering = a.partial_cmp(b).unwrap_or(Ordering::Equal);
+                if descending {
+                    ordering.reverse()
+                } else {
+                    ordering
+                }
+            }
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (None, None) => Ordering::Equal,
+        });
+        *indices = keyed.into_iter().map(|(_, i)| i).collect();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::Path;
+
+    const DAY: u64 = 86_400;
+    const NOW: u64 = 1_700_000_000;
+
+    fn track(title: &str, genre: &str, year: Option<u32>, added_days_ago: u64) -> Track {
+        let mut track = Track::from_file_name(Path::new(&format!("/lib/{}.flac", title)));
+        track.title = title.to_string();
+        track.genre = Some(genre.to_string()).filter(|genre| !genre.is_empty());
+        track.year = year;
+        track.added = Some(NOW - added_days_ago * DAY);
+        track
+    }
+
+    /// Küçük kütüphane: sıralar 0..5
+    fn library() -> Vec<Track> {
+        vec![
+            track("So What", "Jazz", Some(1959), 400),
+            track("Blue Train", "Jazz", Some(1957), 10),
+            track("Paranoid", "Rock", Some(1970), 3),
+            track("Come Closer", "Rock", None, 40),
+            track("Untitled", "", Some(2001), 1),
+        ]
+    }
+
+    /// "Blue Train" iki, "Paranoid" bir kez çalınmış
+    fn history() -> PlayHistory {
+        let mut history = PlayHistory::default();
+        for (title, count) in [("Blue Train", 2), ("Paranoid", 1)] {
+            history.plays.insert(
+                (format!("/lib/{}.flac", title).into(), None),
+                crate::library::history::PlayRecord { count, last: NOW },
+            );
+        }
+        history
+    }
+
+    fn evaluate(rules: &str, sort: Option<&str>, limit: Option<usize>) -> Vec<usize> {
+        SmartPlaylist {
+            name: "test".to_string(),
+            rules: rules.to_string(),
+            sort: sort.map(str::to_string),
+            limit,
+        }
+        .evaluate(&library(), &history(), NOW)
+        .unwrap()
+    }
+
+    fn rule(field: Field, op: Op, value: Value) -> Rule {
+        Rule { field, op, value }
+    }
+
+    fn text(value: &str) -> Value {
+        Value::Text(value.to_string())
+    }
+
+    #[test]
+    fn tokenizer_splits_words_quotes_and_operators() {
+        assert_eq!(
+            tokenize("genre=\"rock and roll\" AND year>=1970 OR title ~ 'so what' OR plays!=0")
+                .unwrap(),
+            vec![
+                Token::Word("genre".to_string()),
+                Token::Op(Op::Eq),
+                Token::Quoted("rock and roll".to_string()),
+                Token::Word("AND".to_string()),
+                Token::Word("year".to_string()),
+                Token::Op(Op::Ge),
+                Token::Word("1970".to_string()),
+                Token::Word("OR".to_string()),
+                Token::Word("title".to_string()),
+                Token::Op(Op::Contains),
+                Token::Quoted("so what".to_string()),
+                Token::Word("OR".to_string()),
+                Token::Word("plays".to_string()),
+                Token::Op(Op::Ne),
+                Token::Word("0".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn and_binds_tighter_than_or() {
+        let jazz = rule(Field::Genre, Op::Eq, text("jazz"));
+        let old = rule(Field::Year, Op::Lt, Value::Number(1960.0));
+        let rock = rule(Field::Genre, Op::Eq, text("rock"));
+        assert_eq!(
+            parse_rules("genre = Jazz and year < 1960 OR genre = rock").unwrap(),
+            Query {
+                groups: vec![vec![jazz.clone(), old.clone()], vec![rock.clone()]]
+            }
+        );
+        assert_eq!(
+            parse_rules("genre = rock or genre = jazz AND year < 1960").unwrap(),
+            Query {
+                groups: vec![vec![rock], vec![jazz, old]]
+            }
+        );
+        // Tırnak içindeki "and" bağlaç sayılmaz
+        assert_eq!(
+            parse_rules("genre = \"rock and roll\"").unwrap(),
+            Query {
+                groups: vec![vec![rule(Field::Genre, Op::Eq, text("rock and roll"))]]
+            }
+        );
+    }
+
+    #[test]
+    fn shorthand_clauses() {
+        let never = rule(Field::Plays, Op::Eq, Value::Number(0.0));
+        let recent = rule(Field::Added, Op::Le, Value::Number(30.0));
+        assert_eq!(
+            parse_rules("Never Played").unwrap().groups,
+            vec![vec![never]]
+        );
+        for rules in [
+            "added in last 30 days",
+            "added in last 30",
+            "added in last 30d",
+            "added <= 30 days",
+        ] {
+            assert_eq!(
+                parse_rules(rules).unwrap().groups,
+                vec![vec![recent.clone()]],
+                "{}",
+                rules
+            );
+        }
+    }
+
+    #[test]
+    fn invalid_rules_explain_the_problem() {
+        let error = |rules: &str| parse_rules(rules).unwrap_err();
+        assert!(error("").starts_with("rules must not be empty"));
+        assert_eq!(
+            error("genre = \"jazz"),
+            "unclosed quote in 'genre = \"jazz'"
+        );
+        assert_eq!(error("year ! 1970"), "expected '!=' in 'year ! 1970'");
+        assert!(error("mood = happy").starts_with("unknown field 'mood' (expected one of: title,"));
+        assert_eq!(
+            error("genre < jazz"),
+            "'<' only works with numbers, use =, != or ~ in 'genre < jazz'"
+        );
+        assert_eq!(
+            error("year ~ 19"),
+            "'~' only works with text in 'year ~ 19'"
+        );
+        assert_eq!(error("year > old"), "'old' is not a number in 'year > old'");
+        assert_eq!(
+            error("added in last few days"),
+            "'few' is not a number of days"
+        );
+        assert_eq!(error("AND genre = jazz"), "missing rule before AND");
+        assert_eq!(error("genre = jazz OR"), "missing rule before the end");
+        assert_eq!(
+            error("genre jazz"),
+            "expected '<field> <operator> <value>' but got 'genre jazz'"
+        );
+    }
+
+    #[test]
+    fn invalid_options_explain_the_problem() {
+        let error = |text: &str| SmartPlaylist::from_text("x", text).unwrap_err();
+        assert_eq!(
+            error("genre = jazz; sort year sideways"),
+            "invalid sort 'year sideways' (expected '<field> [asc|desc]' or 'random')"
+        );
+        assert_eq!(
+            error("genre = jazz; limit ten"),
+            "'ten' is not a valid limit"
+        );
+        assert_eq!(error("genre = jazz; limit 0"), "limit must be at least 1");
+        assert!(error("genre = jazz; shuffle").starts_with("unknown option 'shuffle'"));
+        assert_eq!(
+            SmartPlaylist::from_text(" ", "genre = jazz").unwrap_err(),
+            "smart playlist name must not be empty"
+        );
+    }
+
+    #[test]
+    fn from_text_reads_sort_and_limit() {
+        assert_eq!(
+            SmartPlaylist::from_text(" Old jazz ", "genre = jazz ; SORT year desc; limit 5")
+                .unwrap(),
+            SmartPlaylist {
+                name: "Old jazz".to_string(),
+                rules: "genre = jazz".to_string(),
+                sort: Some("year desc".to_string()),
+                limit: Some(5),
+            }
+        );
+    }
+
+    #[test]
+    fn evaluate_matches_rules_against_tracks_and_history() {
+        assert_eq!(evaluate("genre = jazz", None, None), vec![0, 1]);
+        assert_eq!(evaluate("title ~ BLUE", None, None), vec![1]);
+        // Alanı boş parçalar yalnızca `!=` kurallarına uyar
+        assert_eq!(evaluate("genre != jazz", None, None), vec![2, 3, 4]);
+        assert_eq!(evaluate("year < 2000", None, None), vec![0, 1, 2]);
+        assert_eq!(evaluate("never played", None, None), vec![0, 3, 4]);
+        assert_eq!(evaluate("plays >= 2", None, None), vec![1]);
+        assert_eq!(evaluate("added in last 10 days", None, None), vec![1, 2, 4]);
+        assert_eq!(
+            evaluate(
+                "genre = jazz AND added in last 30 days OR year = 2001",
+                None,
+                None
+            ),
+            vec![1, 4]
+        );
+    }
+
+    #[test]
+    fn evaluate_sorts_and_limits() {
+        let all = "added in last 1000 days";
+        // Boş alanlar her iki yönde de sona konur
+        assert_eq!(evaluate(all, Some("year"), None), vec![1, 0, 2, 4, 3]);
+        assert_eq!(evaluate(all, Some("year desc"), None), vec![4, 2, 0, 1, 3]);
+        assert_eq!(evaluate(all, Some("added desc"), Some(2)), vec![4, 2]);
+        // Eşit parçalar kütüphane sırasını korur
+        assert_eq!(evaluate(all, Some("plays desc"), None), vec![1, 2, 0, 3, 4]);
+        assert_eq!(evaluate(all, Some("title"), Some(3)), vec![1, 3, 2]);
+        assert_eq!(evaluate(all, None, Some(2)), vec![0, 1]);
+
+        let mut random = evaluate(all, Some("random"), Some(5));
+        random.sort();
+        assert_eq!(random, vec![0, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn cue_tracks_in_one_file_have_their_own_plays() {
+        use crate::library::track::CueRange;
+        use std::time::Duration;
+
+        let tracks: Vec<Track> = [0, 300]
+            .map(|start| {
+                let mut track = track("Album", "Jazz", None, 1);
+                track.cue = Some(CueRange {
+                    start: Duration::from_secs(start),
+                    end: None,
+                });
+                track
+            })
+            .to_vec();
+        let mut history = PlayHistory::default();
+        history.record(tracks[1].id());
+
+        let never = SmartPlaylist::from_text("x", "never played").unwrap();
+        assert_eq!(never.evaluate(&tracks, &history, NOW).unwrap(), vec![0]);
+        let played = SmartPlaylist::from_text("x", "plays >= 1; sort plays desc").unwrap();
+        assert_eq!(played.evaluate(&tracks, &history, NOW).unwrap(), vec![1]);
+    }
+}
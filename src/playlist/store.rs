@@ -0,0 +1,145 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use super::m3u::M3u;
+use super::smart::SmartPlaylist;
+use super::{PlaylistEntry, PlaylistFormat, name_from_path};
+
+/// Arayüzde oluşturulan akıllı listelerin dosyası
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(default)]
+struct SmartFile {
+    smart_playlists: Vec<SmartPlaylist>,
+}
+
+/// meloic içinde oluşturulan adlandırılmış çalma listeleri; her liste klasörde bir M3U8 dosyasıdır
+#[derive(Debug, Clone)]
+pub struct PlaylistStore {
+    pub dir: PathBuf,
+}
+
+impl PlaylistStore {
+    /// Varsayılan klasör (~/.local/share/meloic/playlists)
+    pub fn default_dir() -> Option<PathBuf> {
+        dirs::data_dir().map(|dir| dir.join("meloic").join("playlists"))
+    }
+
+    /// Varsayılan klasördeki listeler; veri klasörü bilinmiyorsa `None`
+    pub fn open_default() -> Option<PlaylistStore> {
+        Self::default_dir().map(PlaylistStore::open)
+    }
+
+    /// Verilen klasördeki listeler (klasör ilk kayıtta oluşturulur)
+    pub fn open(dir: PathBuf) -> PlaylistStore {
+        PlaylistStore { dir }
+    }
+
+    /// Klasördeki listeler, ada göre sıralı (klasör yoksa boş)
+    pub fn list(&self) -> Vec<PathBuf> {
+        let Ok(entries) = fs::read_dir(&self.dir) else {
+            return Vec::new();
+        };
+        let mut paths: Vec<PathBuf> = entries
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .filter(|path| path.is_file() && path.extension().is_some_and(|ext| ext == "m3u8"))
+            .collect();
+        paths.sort_by_key(|path| name_from_path(path).to_lowercase());
+        paths
+    }
+
+    /// Liste bu klasörde mi?
+    pub fn contains(&self, path: &Path) -> bool {
+        path.parent() == Some(self.dir.as_path())
+    }
+
+    /// Adın dosya yolu; ad geçersizse hata döner
+    fn path_for(&self, name: &str) -> io::Result<PathBuf> {
+        let name = name.trim();
+        let invalid = name.is_empty()
+            || name.starts_with('.')
+            || name.contains(['/', '\\'])
+            || name.chars().any(char::is_control);
+        if invalid {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("invalid playlist name '{}'", name),
+            ));
+        }
+        Ok(self.dir.join(format!("{}.m3u8", name)))
+    }
+
+    /// Boş bir liste oluştur; aynı adlı liste varsa hata döner
+    pub fn create(&self, name: &str) -> io::Result<PathBuf> {
+        let path = self.path_for(name)?;
+        if path.exists() {
+            return Err(already_exists(name));
+        }
+        self.write(&path, &[])?;
+        Ok(path)
+    }
+
+    /// Listeyi yeniden adlandır; yeni yolu döndürür
+    pub fn rename(&self, path: &Path, name: &str) -> io::Result<PathBuf> {
+        let target = self.path_for(name)?;
+        if target == path {
+            return Ok(target);
+        }
+        // Yalnızca büyük/küçük harfi değişen adlar da aynı dosyaya denk gelebilir
+        let same_name = name_from_path(path).to_lowercase() == name.trim().to_lowercase();
+        if target.exists() && !same_name {
+            return Err(already_exists(name));
+        }
+        fs::rename(path, &target)?;
+        Ok(target)
+    }
+
+    /// Listeyi sil
+    pub fn delete(&self, path: &Path) -> io::Result<()> {
+        fs::remove_file(path)
+    }
+
+    /// Satırları listeye yaz (önce geçici dosyaya, sonra yerine taşı)
+    pub fn write(&self, path: &Path, entries: &[PlaylistEntry]) -> io::Result<()> {
+        fs::create_dir_all(&self.dir)?;
+        // Boş taban: liste kütüphane dışında durduğundan yollar tam yazılır
+        let text = M3u.format(&name_from_path(path), entries, Path::new(""));
+        let tmp = path.with_extension("m3u8.tmp");
+        fs::write(&tmp, text)?;
+        fs::rename(&tmp, path)
+    }
+
+    fn smart_path(&self) -> PathBuf {
+        self.dir.join("smart.toml")
+    }
+
+    /// Arayüzde oluşturulan akıllı listeler (dosya yoksa veya okunamıyorsa boş)
+    pub fn load_smart(&self) -> Vec<SmartPlaylist> {
+        fs::read_to_string(self.smart_path())
+            .ok()
+            .and_then(|text| toml::from_str::<SmartFile>(&text).ok())
+            .map(|file| file.smart_playlists)
+            .unwrap_or_default()
+    }
+
+    /// Akıllı listeleri kaydet
+    pub fn save_smart(&self, smart_playlists: &[SmartPlaylist]) -> io::Result<()> {
+        fs::create_dir_all(&self.dir)?;
+        let file = SmartFile {
+            smart_playlists: smart_playlists.to_vec(),
+        };
+        let text = toml::to_string(&file).map_err(io::Error::other)?;
+        let tmp = self.smart_path().with_extension("toml.tmp");
+        fs::write(&tmp, text)?;
+        fs::rename(&tmp, self.smart_path())
+    }
+}
+
+fn already_exists(name: &str) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::AlreadyExists,
+        format!("a playlist named '{}' already exists", name.trim()),
+    )
+}
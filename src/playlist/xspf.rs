@@ -0,0 +1,218 @@
+use quick_xml::escape::escape;
+use quick_xml::events::Event;
+use quick_xml::reader::Reader;
+use std::fmt::Write as _;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use super::{
+    PlaylistEntry, PlaylistFormat, percent_decode, percent_encode, relative_path, resolve_path,
+};
+
+/// XSPF ("spiff") XML çalma listeleri
+pub struct Xspf;
+
+/// `<track>` içinde okunan alanlar
+#[derive(Default)]
+struct TrackFields {
+    location: Option<String>,
+    title: Option<String>,
+    creator: Option<String>,
+    duration: Option<Duration>,
+}
+
+impl TrackFields {
+    fn into_entry(self, base: &Path) -> PlaylistEntry {
+        // Konumu olmayan parçalar bulunamayan satır olarak gösterilir
+        let path = match self.location.as_deref() {
+            Some(location) if location.contains("://") => resolve_path(base, location),
+            Some(location) => resolve_path(base, &percent_decode(location)),
+            None => PathBuf::new(),
+        };
+        let title = match (self.creator, self.title) {
+            (Some(creator), Some(title)) => Some(format!("{} - {}", creator, title)),
+            (_, title) => title,
+        };
+        PlaylistEntry {
+            path,
+            title,
+            duration: self.duration,
+        }
+    }
+}
+
+impl PlaylistFormat for Xspf {
+    fn extensions(&self) -> &'static [&'static str] {
+        &["xspf"]
+    }
+
+    fn parse(&self, text: &str, base: &Path) -> io::Result<Vec<PlaylistEntry>> {
+        let mut reader = Reader::from_str(text);
+        reader.config_mut().trim_text(true);
+
+        let mut entries = Vec::new();
+        // Açık elemanlar (ad alanı öneki olmadan)
+        let mut open: Vec<String> = Vec::new();
+        let mut track: Option<TrackFields> = None;
+        loop {
+            let event = reader
+                .read_event()
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+            let text = match event {
+                Event::Start(element) => {
+                    let name = String::from_utf8_lossy(element.local_name().as_ref()).into_owned();
+                    if name == "track" {
+                        track = Some(TrackFields::default());
+                    }
+                    open.push(name);
+                    continue;
+                }
+                Event::End(_) => {
+                    if open.pop().as_deref() == Some("track")
+                        && let Some(fields) = track.take()
+                    {
+                        entries.push(fields.into_entry(base));
+                    }
+                    continue;
+                }
+                Event::Text(text) => text
+                    .unescape()
+                    .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?
+                    .into_owned(),
+                Event::CData(data) => String::from_utf8_lossy(&data).into_owned(),
+                Event::Eof => break,
+                _ => continue,
+            };
+
+            // Yalnızca doğrudan <track> altındaki alanlar okunur (ör. <extension> içindekiler değil)
+            let Some(fields) = track.as_mut() else {
+                continue;
+            };
+            if open.len() < 2 || open[open.len() - 2] != "track" {
+                continue;
+            }
+            match open.last().map(String::as_str) {
+                // Birden fazla konum varsa ilki kullanılır
+                Some("location") if fields.location.is_none() => fields.location = Some(text),
+                Some("title") => fields.title = Some(text),
+                Some("creator") => fields.creator = Some(text),
+                Some("duration") => {
+                    fields.duration = text.trim().parse().ok().map(Duration::from_millis)
+                }
+                _ => {}
+            }
+        }
+        Ok(entries)
+    }
+
+    fn format(&self, name: &str, entries: &[PlaylistEntry], base: &Path) -> String {
+        let mut text = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+        text.push_str("<playlist version=\"1\" xmlns=\"http://xspf.org/ns/0/\">\n");
+        let _ = writeln!(text, "  <title>{}</title>", escape(name));
+        text.push_str("  <trackList>\n");
+        for entry in entries {
+            let path = relative_path(&entry.path, base);
+            let location = if path.is_absolute() {
+                format!("file://{}", percent_encode(&path.to_string_lossy()))
+            } else {
+                percent_encode(&path.to_string_lossy())
+            };
+            text.push_str("    <track>\n");
+            let _ = writeln!(text, "      <location>{}</location>", escape(&location));
+            if let Some(title) = &entry.title {
+                let _ = writeln!(text, "      <title>{}</title>", escape(title));
+            }
+            if let Some(duration) = entry.duration {
+                let _ = writeln!(text, "      <duration>{}</duration>", duration.as_millis());
+            }
+            text.push_str("    </track>\n");
+        }
+        text.push_str("  </trackList>\n</playlist>\n");
+        text
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::playlist::tests::{temp_dir, touch};
+
+    #[test]
+    fn round_trip_keeps_title_duration_and_locations() {
+        let dir = temp_dir("xspf");
+        let base = dir.join("lists");
+        let entries = vec![
+            PlaylistEntry {
+                path: dir.join("Müzik & Caz/01 So What.flac"),
+                title: Some("Miles Davis - So What <live>".to_string()),
+                duration: Some(Duration::from_millis(562_437)),
+            },
+            PlaylistEntry {
+                path: base.join("local.mp3"),
+                title: None,
+                duration: None,
+            },
+        ];
+        for entry in &entries {
+            touch(&entry.path);
+        }
+
+        let text = Xspf.format("jazz & blues", &entries, &base);
+        assert!(text.contains("<title>jazz &amp; blues</title>"));
+        assert!(
+            text.contains("<location>../M%C3%BCzik%20%26%20Caz/01%20So%20What.flac</location>")
+        );
+        assert!(text.contains("<title>Miles Davis - So What &lt;live&gt;</title>"));
+        assert!(text.contains("<duration>562437</duration>"));
+
+        // XSPF milisaniye tutar; süre kayıpsız döner
+        let parsed = Xspf.parse(&text, &base).unwrap();
+        assert_eq!(parsed, entries);
+    }
+
+    #[test]
+    fn file_uri_is_percent_decoded() {
+        let text = r#"<?xml version="1.0" encoding="UTF-8"?>
+<playlist version="1" xmlns="http://xspf.org/ns/0/">
+  <trackList>
+    <track>
+      <location>file:///music/Miles%20Davis/So%20What.flac</location>
+      <location>file:///ignored.flac</location>
+      <creator>Miles Davis</creator>
+      <title>So What</title>
+      <duration>562000</duration>
+      <extension application="x"><title>Not this</title></extension>
+    </track>
+  </trackList>
+</playlist>"#;
+        let parsed = Xspf.parse(text, Path::new("/lists")).unwrap();
+        assert_eq!(
+            parsed,
+            vec![PlaylistEntry {
+                path: PathBuf::from("/music/Miles Davis/So What.flac"),
+                title: Some("Miles Davis - So What".to_string()),
+                duration: Some(Duration::from_secs(562)),
+            }]
+        );
+    }
+
+    #[test]
+    fn track_without_location_is_kept() {
+        let text = "<playlist><trackList>\
+                    <track><title>Gone</title></track>\
+                    <track><location>gone.flac</location></track>\
+                    </trackList></playlist>";
+        let parsed = Xspf.parse(text, Path::new("/music")).unwrap();
+        assert_eq!(parsed.len(), 2);
+        assert_eq!(parsed[0].path, PathBuf::new());
+        assert_eq!(parsed[0].describe(), "Gone");
+        assert_eq!(parsed[1].path, Path::new("/music/gone.flac"));
+    }
+
+    #[test]
+    fn malformed_xml_is_an_error() {
+        let text = "<playlist><trackList><track></trackList>";
+        assert!(Xspf.parse(text, Path::new("/music")).is_err());
+    }
+}
@@ -0,0 +1,71 @@
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::player::EffectConfig;
+use crate::player::equalizer::Gains;
+
+/// Oturumlar arasında saklanan oynatıcı durumu (~/.local/share/meloic/state.toml)
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SavedState {
+    /// Son ses seviyesi (yüzde); hiç kaydedilmediyse config'deki değer kullanılır
+    pub volume: Option<u8>,
+    pub muted: bool,
+    /// Son ekolayzır ayarı; hiç kaydedilmediyse config'deki hazır ayar kullanılır
+    pub equalizer: Option<SavedEqualizer>,
+    /// Son efekt zinciri; hiç kaydedilmediyse config'deki [[effects]] kullanılır
+    pub effects: Option<Vec<EffectConfig>>,
+    /// Kayıt anında config'deki ayarlar; config o zamandan beri değiştiyse config kullanılır
+    pub config: Option<ConfigSettings>,
+}
+
+/// Config'den gelen ekolayzır ayarı ve efekt zinciri
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConfigSettings {
+    pub equalizer: SavedEqualizer,
+    #[serde(default)]
+    pub effects: Vec<EffectConfig>,
+}
+
+/// Kaydedilmiş ekolayzır ayarı
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SavedEqualizer {
+    pub enabled: bool,
+    pub gains: Gains,
+}
+
+impl SavedState {
+    /// Varsayılan durum dosyasının yolu
+    pub fn default_path() -> Option<PathBuf> {
+        dirs::data_dir().map(|dir| dir.join("meloic").join("state.toml"))
+    }
+
+    /// Durumu yükle; dosya yoksa veya okunamıyorsa varsayılanı döndür
+    pub fn load() -> SavedState {
+        Self::default_path()
+            .and_then(|path| fs::read_to_string(path).ok())
+            .and_then(|text| toml::from_str(&text).ok())
+            .unwrap_or_default()
+    }
+
+    /// Durumu varsayılan dosyaya yaz
+    pub fn save(&self) -> io::Result<()> {
+        match Self::default_path() {
+            Some(path) => self.save_to(&path),
+            None => Ok(()),
+        }
+    }
+
+    /// Durumu diske yaz (önce geçici dosyaya, sonra yerine taşı)
+    fn save_to(&self, path: &Path) -> io::Result<()> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let text = toml::to_string(self).map_err(io::Error::other)?;
+        let tmp_path = path.with_extension("toml.tmp");
+        fs::write(&tmp_path, text)?;
+        fs::rename(tmp_path, path)
+    }
+}
@@ -1,54 +1,261 @@
 use rand::seq::SliceRandom;
 use ratatui::widgets::ListState;
-use rodio::{OutputStream, OutputStreamHandle, Sink};
-use std::path::PathBuf;
+use rodio::source::SeekError;
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
+use std::sync::mpsc::{Receiver, TryRecvError};
 
-/// Müzik çalma durumu
-#[derive(Debug, Clone, PartialEq)]
-pub enum PlaybackState {
-    Stopped,
-    Playing,
-    Paused,
-}
+use crate::config::expand_home;
+use crate::library::finder::{self, Finder, FuzzyMatch};
+use crate::library::history::PlayHistory;
+use crate::library::index::unix_now;
+use crate::library::loudness::{self, LoudnessEvent, Measurement};
+use crate::library::track::TrackId;
+use crate::library::{Library, ScanEvent, ScanProgress, Track};
+use crate::player::dsp::Effect;
+use crate::player::equalizer::{BAND_COUNT, MAX_GAIN_DB};
+use crate::player::{EqPreset, PlayError, Player, Queue};
+use crate::playlist::smart::SmartPlaylist;
+use crate::playlist::store::PlaylistStore;
+use crate::playlist::{self, PlaylistEntry};
+use crate::ui::browser::{Browser, Pane};
+use crate::ui::effects::{EffectRow, effect_rows};
+use crate::ui::notify::{Level, MessageLog};
+use crate::ui::playlists::{PlaylistPane, PlaylistsView, SmartList};
+use crate::ui::theme::Theme;
+use crate::ui::tree::{TreeRow, TreeView};
+
+/// Ekolayzırda bir tuşla ve sayfa tuşuyla yapılan kazanç değişikliği (dB)
+const EQ_STEP_DB: f32 = 1.0;
+const EQ_PAGE_DB: f32 = 3.0;
+/// Efekt parametresinde uzun adım, parametrenin adımının katı olarak
+const EFFECT_LONG_STEPS: f32 = 5.0;
+
+/// Otomatik geçişte art arda çalınamayan en fazla kaç parça atlanır
+const MAX_SKIPPED_TRACKS: usize = 10;
 
 /// Uygulama durumunu tutan ana struct
 pub struct App {
-    pub items: Vec<PathBuf>,
+    pub items: Vec<Track>,
     pub state: ListState,
     pub show_help: bool,
-    pub filtered_items: Vec<(usize, PathBuf)>, // (original_index, path)
+    /// Listede görünen parçaların `items` içindeki sıraları (parçalar kopyalanmaz)
+    pub filtered_items: Vec<usize>,
+    /// Kütüphane listesini süzen arama metni
+    pub search_query: String,
+    /// Arama satırına yazılıyor mu?
+    pub search_input: bool,
+    pub search_mode: SearchMode,
+    /// Bulanık arama thread'i ve son sonuçları
+    pub finder: Finder,
+    fuzzy_matches: Vec<FuzzyMatch>,
+    /// Bulanık arama sonucu bekleniyor mu?
+    pub fuzzy_pending: bool,
+    /// Parça listesi değişti, bulanık aramanın adayları yenilenmeli
+    finder_stale: bool,
+    /// Parçaların küçük harfli arama metinleri (ilk aramada oluşturulur)
+    search_texts: Vec<String>,
+    /// Arama başlamadan önce seçili olan parça (arama temizlenince geri seçilir)
+    search_origin: Option<TrackId>,
+    /// Alt satırda metin bekleyen komut (ör. çalma listesi kaydetme)
+    pub prompt: Option<Prompt>,
+    /// Parça yolundan listedeki sıraya (ilk kullanımda oluşturulur)
+    path_index: HashMap<PathBuf, usize>,
     pub total_tracks: usize,
     pub current_view: ViewMode,
-    pub playback_state: PlaybackState,
-    pub current_track: Option<PathBuf>,
-    pub sink: Option<Arc<Sink>>,
-    pub _stream: Option<OutputStream>, // _ ile başlıyor çünkü kullanmıyoruz ama yaşatmamız gerekiyor
-    pub stream_handle: Option<OutputStreamHandle>,
+    previous_view: ViewMode,
+    pub player: Player,
+    pub queue: Queue,
+    pub queue_state: ListState,
+    pub messages: MessageLog,
+    pub messages_state: ListState,
+    pub browser: Browser,
+    pub folders: TreeView,
+    pub playlists: PlaylistsView,
+    /// Adlandırılmış listelerin klasörü (veri klasörü bilinmiyorsa yok)
+    store: Option<PlaylistStore>,
+    /// Son parça eklenen adlandırılmış liste (ekleme isteminde önerilir)
+    last_playlist: Option<PathBuf>,
+    /// Kütüphanede işaretlenmiş parçalar (çoklu seçim)
+    pub marked: HashSet<TrackId>,
+    /// Config dosyasında tanımlı akıllı listeler
+    pub smart_config: Vec<SmartPlaylist>,
+    /// Ekolayzırda seçilebilecek hazır ayarlar ve seçili bant
+    pub eq_presets: Vec<EqPreset>,
+    pub eq_band: usize,
+    /// Efektler görünümünde seçili satır
+    pub effects_state: ListState,
+    /// Arayüzde oluşturulan akıllı listeler
+    smart_saved: Vec<SmartPlaylist>,
+    /// Parçaların çalınma sayıları (akıllı listelerde kullanılır)
+    pub history: PlayHistory,
+    /// Çalınamayan parçalar (listede işaretlenir)
+    pub broken: HashSet<PathBuf>,
+    pub page_size: usize,
+    /// Kısa ve uzun sarma adımları (saniye)
+    pub seek_step: u64,
+    pub seek_long_step: u64,
+    /// Ses açma/kısma adımı (yüzde)
+    pub volume_step: u8,
+    pub theme: Theme,
+    pub library: Library,
+    pub scan_progress: ScanProgress,
+    scan_rx: Option<Receiver<ScanEvent>>,
+    scan_buffer: Vec<Track>,
+    /// Taramada bulunan çalma listeleri
+    scan_playlists: Vec<PathBuf>,
+    scan_live: bool,
+    /// Ses yüksekliği ölçümü: biten ve toplam dosya sayısı
+    pub loudness_progress: (usize, usize),
+    loudness_rx: Option<Receiver<LoudnessEvent>>,
+    /// Tarama sürerken biten ölçümler; indeks geri gelince uygulanır
+    loudness_pending: Vec<Measurement>,
+}
+/// Arama satırının türü
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SearchMode {
+    /// Tüm kelimeleri içeren parçalar, kütüphane sırasıyla
+    Substring,
+    /// fzf tarzı bulanık eşleşme, puana göre sıralı
+    Fuzzy,
+}
+
+/// Alt satırda metin girilerek tamamlanan komut
+#[derive(Debug, Clone)]
+pub struct Prompt {
+    pub kind: PromptKind,
+    pub text: String,
+}
+
+#[derive(Debug, Clone)]
+pub enum PromptKind {
+    /// Parçaları verilen yola kaydet (biçim uzantıdan, varsayılan M3U8)
+    SavePlaylist(Vec<Track>),
+    /// Verilen adla boş bir liste oluştur
+    NewPlaylist,
+    /// Parçaları verilen adlı listeye ekle (yoksa oluşturulur)
+    AddToPlaylist(Vec<Track>),
+    RenamePlaylist(PathBuf),
+    /// "y" yazılırsa listeyi sil
+    ConfirmDelete(PathBuf),
+    /// Yeni akıllı listenin adı
+    SmartName,
+    /// Verilen adlı akıllı listenin kuralları
+    SmartRules(String),
+    /// "y" yazılırsa arayüzde oluşturulan akıllı listeyi sil
+    ConfirmDeleteSmart(String),
+}
+
+impl Prompt {
+    /// Satırın başında gösterilen soru
+    pub fn label(&self) -> &'static str {
+        match self.kind {
+            PromptKind::SavePlaylist(_) => "💾 Save playlist (.m3u8/.pls/.xspf) as: ",
+            PromptKind::NewPlaylist => "📝 New playlist name: ",
+            PromptKind::AddToPlaylist(_) => "➕ Add to playlist (a new name creates it): ",
+            PromptKind::RenamePlaylist(_) => "✏️  Rename playlist to: ",
+            PromptKind::ConfirmDelete(_) | PromptKind::ConfirmDeleteSmart(_) => {
+                "🗑  Delete this playlist? (y/n): "
+            }
+            PromptKind::SmartName => "✨ Smart playlist name: ",
+            PromptKind::SmartRules(_) => {
+                "✨ Rules (e.g. genre = jazz AND year < 1970; sort year desc; limit 50): "
+            }
+        }
+    }
 }
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum ViewMode {
     Normal,
+    Queue,
+    Messages,
+    Browser,
+    Folders,
+    Playlists,
+    Equalizer,
+    Effects,
     Help,
 }
 
 impl App {
-    /// Yeni bir App instance'ı oluştur
-    pub fn new(items: Vec<PathBuf>) -> App {
+    /// Yeni bir App instance'ı oluştur; listeler ve çalma geçmişi veri klasöründen okunur
+    pub fn new(library: Library, items: Vec<Track>) -> App {
+        App::with_data(
+            library,
+            items,
+            PlaylistStore::open_default(),
+            PlayHistory::load(),
+        )
+    }
+
+    /// Listeleri ve çalma geçmişi verilen App oluştur (ör. testlerde geçici klasörle)
+    pub fn with_data(
+        library: Library,
+        items: Vec<Track>,
+        store: Option<PlaylistStore>,
+        history: PlayHistory,
+    ) -> App {
         let total = items.len();
-        App {
-            items: items.clone(),
+        let mut app = App {
+            items,
             state: ListState::default(),
             show_help: false,
-            filtered_items: items.into_iter().enumerate().collect(),
+            filtered_items: Vec::new(),
+            search_query: String::new(),
+            search_input: false,
+            search_mode: SearchMode::Substring,
+            finder: Finder::new(),
+            fuzzy_matches: Vec::new(),
+            fuzzy_pending: false,
+            finder_stale: true,
+            search_texts: Vec::new(),
+            search_origin: None,
+            prompt: None,
+            path_index: HashMap::new(),
             total_tracks: total,
             current_view: ViewMode::Normal,
-            playback_state: PlaybackState::Stopped,
-            current_track: None,
-            sink: None,
-            _stream: None,
-            stream_handle: None,
+            previous_view: ViewMode::Normal,
+            player: Player::new(1.0),
+            queue: Queue::default(),
+            queue_state: ListState::default(),
+            messages: MessageLog::default(),
+            messages_state: ListState::default(),
+            browser: Browser::default(),
+            folders: TreeView::default(),
+            playlists: PlaylistsView::default(),
+            store,
+            last_playlist: None,
+            marked: HashSet::new(),
+            smart_config: Vec::new(),
+            eq_presets: EqPreset::builtin(),
+            eq_band: 0,
+            effects_state: ListState::default(),
+            smart_saved: Vec::new(),
+            history,
+            broken: HashSet::new(),
+            page_size: 10,
+            seek_step: 5,
+            seek_long_step: 30,
+            volume_step: 5,
+            theme: Theme::default(),
+            library,
+            scan_progress: ScanProgress::default(),
+            scan_rx: None,
+            loudness_progress: (0, 0),
+            loudness_rx: None,
+            loudness_pending: Vec::new(),
+            scan_buffer: Vec::new(),
+            scan_playlists: Vec::new(),
+            scan_live: false,
+        };
+        if let Some(store) = &app.store {
+            app.playlists.set_named(store.list());
+            app.smart_saved = store.load_smart();
         }
+        app.update_filtered_items();
+        app
     }
 
     /// İlk öğeyi seç
@@ -58,11 +265,38 @@ impl App {
         }
     }
 
+    /// Aktif görünümdeki listenin durumu ve uzunluğu
+    fn active_list(&mut self) -> (&mut ListState, usize) {
+        match self.current_view {
+            ViewMode::Queue => (&mut self.queue_state, self.queue.len()),
+            ViewMode::Messages => (&mut self.messages_state, self.messages.len()),
+            ViewMode::Browser => {
+                self.browser.refresh(&self.items);
+                self.browser.active_list()
+            }
+            ViewMode::Folders => {
+                self.folders.refresh(&self.library.roots, &self.items);
+                (&mut self.folders.state, self.folders.rows.len())
+            }
+            ViewMode::Playlists => self.playlists.active_list(),
+            ViewMode::Effects => {
+                let len = effect_rows(&self.player.effects.chain()).len();
+                (&mut self.effects_state, len)
+            }
+            _ => (&mut self.state, self.filtered_items.len()),
+        }
+    }
+
     /// Sonraki öğeye geç (liste sonunda ise başa sar)
     pub fn next(&mut self) {
-        let i = match self.state.selected() {
+        if self.current_view == ViewMode::Equalizer {
+            self.adjust_band(-EQ_STEP_DB);
+            return;
+        }
+        let (state, len) = self.active_list();
+        let i = match state.selected() {
             Some(i) => {
-                if i >= self.filtered_items.len().saturating_sub(1) {
+                if i >= len.saturating_sub(1) {
                     0
                 } else {
                     i + 1
@@ -70,52 +304,70 @@ impl App {
             }
             None => 0,
         };
-        self.state.select(Some(i));
+        state.select(Some(i));
     }
 
     /// Önceki öğeye geç (liste başında ise sona sar)
     pub fn previous(&mut self) {
-        let i = match self.state.selected() {
+        if self.current_view == ViewMode::Equalizer {
+            self.adjust_band(EQ_STEP_DB);
+            return;
+        }
+        let (state, len) = self.active_list();
+        let i = match state.selected() {
             Some(i) => {
                 if i == 0 {
-                    self.filtered_items.len().saturating_sub(1)
+                    len.saturating_sub(1)
                 } else {
                     i - 1
                 }
             }
             None => 0,
         };
-        self.state.select(Some(i));
+        state.select(Some(i));
     }
 
-    /// Sayfa sonraki (10 öğe)
+    /// Sayfa sonraki (page_size öğe)
     pub fn next_page(&mut self) {
-        if let Some(selected) = self.state.selected() {
-            let new_index =
-                std::cmp::min(selected + 10, self.filtered_items.len().saturating_sub(1));
-            self.state.select(Some(new_index));
+        if self.current_view == ViewMode::Equalizer {
+            self.adjust_band(-EQ_PAGE_DB);
+            return;
+        }
+        let page_size = self.page_size;
+        let (state, len) = self.active_list();
+        if let Some(selected) = state.selected() {
+            let new_index = std::cmp::min(selected + page_size, len.saturating_sub(1));
+            state.select(Some(new_index));
         }
     }
 
-    /// Sayfa önceki (10 öğe)
+    /// Sayfa önceki (page_size öğe)
     pub fn previous_page(&mut self) {
-        if let Some(selected) = self.state.selected() {
-            let new_index = selected.saturating_sub(10);
-            self.state.select(Some(new_index));
+        if self.current_view == ViewMode::Equalizer {
+            self.adjust_band(EQ_PAGE_DB);
+            return;
+        }
+        let page_size = self.page_size;
+        let (state, _) = self.active_list();
+        if let Some(selected) = state.selected() {
+            let new_index = selected.saturating_sub(page_size);
+            state.select(Some(new_index));
         }
     }
 
     /// Liste başına git
     pub fn go_to_top(&mut self) {
-        if !self.filtered_items.is_empty() {
-            self.state.select(Some(0));
+        let (state, len) = self.active_list();
+        if len > 0 {
+            state.select(Some(0));
         }
     }
 
     /// Liste sonuna git
     pub fn go_to_bottom(&mut self) {
-        if !self.filtered_items.is_empty() {
-            self.state.select(Some(self.filtered_items.len() - 1));
+        let (state, len) = self.active_list();
+        if len > 0 {
+            state.select(Some(len - 1));
         }
     }
 
@@ -132,38 +384,484 @@ impl App {
         }
     }
 
+    /// Rastgele bir parça seç ve çal
+    pub fn play_random(&mut self) {
+        self.select_random();
+        if let Some(selected) = self.get_selected() {
+            let track = self.queue.play_now(selected).clone();
+            let _ = self.play_track(track);
+        }
+    }
+
     /// Yardım penceresini aç/kapat
     pub fn toggle_help(&mut self) {
         self.show_help = !self.show_help;
-        self.current_view = if self.show_help {
-            ViewMode::Help
+        if self.show_help {
+            self.previous_view = self.current_view.clone();
+            self.current_view = ViewMode::Help;
         } else {
-            ViewMode::Normal
-        };
+            self.current_view = self.previous_view.clone();
+        }
     }
 
-    /// Filtrelenmiş öğeleri güncelle (şu an sadece tüm öğeleri gösteriyor)
+    /// Görünümü değiştir (yardım açıksa kapatılır)
+    pub fn set_view(&mut self, view: ViewMode) {
+        self.show_help = false;
+        if view == ViewMode::Queue
+            && self.queue_state.selected().is_none()
+            && !self.queue.is_empty()
+        {
+            self.queue_state
+                .select(Some(self.queue.current_index().unwrap_or(0)));
+        }
+        if view == ViewMode::Effects && self.effects_state.selected().is_none() {
+            self.effects_state.select(Some(0));
+        }
+        // Günlük en yeni mesajdan başlar
+        if view == ViewMode::Messages && !self.messages.is_empty() {
+            self.messages_state.select(Some(0));
+        }
+        self.current_view = view;
+    }
+
+    /// Filtrelenmiş öğeleri güncelle: arama metnindeki tüm kelimeleri içeren parçalar,
+    /// bulanık aramada ise son sonuçlar puan sırasıyla
     fn update_filtered_items(&mut self) {
-        self.filtered_items = self
-            .items
-            .iter()
-            .enumerate()
-            .map(|(i, path)| (i, path.clone()))
+        if self.fuzzy_active() {
+            self.filtered_items = self
+                .fuzzy_matches
+                .iter()
+                .map(|m| m.index)
+                .filter(|&i| i < self.items.len())
+                .collect();
+            return;
+        }
+        let terms: Vec<String> = self
+            .search_query
+            .to_lowercase()
+            .split_whitespace()
+            .map(str::to_string)
+            .collect();
+        if !terms.is_empty() && self.search_texts.len() != self.items.len() {
+            self.search_texts = self.items.iter().map(Track::search_text).collect();
+        }
+        self.filtered_items = (0..self.items.len())
+            .filter(|&i| {
+                terms.is_empty()
+                    || terms
+                        .iter()
+                        .all(|term| self.search_texts[i].contains(term.as_str()))
+            })
             .collect();
     }
 
-    /// Kütüphaneyi yenile
-    pub fn refresh(&mut self) {
-        // Bu fonksiyon ileride kütüphane tarama logic'i eklendiğinde kullanılabilir
+    /// Listede görünen parçalar, sırasıyla
+    pub fn filtered_tracks(&self) -> impl Iterator<Item = &Track> {
+        self.filtered_items.iter().map(|&i| &self.items[i])
+    }
+
+    /// Listede verilen sıradaki parça
+    pub fn filtered_track(&self, position: usize) -> Option<&Track> {
+        self.filtered_items.get(position).map(|&i| &self.items[i])
+    }
+
+    /// Verilen parçayı listede seç; listede yoksa ilk öğeyi seç
+    fn select_track(&mut self, id: Option<&TrackId>) {
+        let position = id.and_then(|id| self.filtered_tracks().position(|track| track.id() == *id));
+        match position {
+            Some(i) => self.state.select(Some(i)),
+            None => {
+                self.state.select(None);
+                self.select_first();
+            }
+        }
+    }
+
+    /// Arama satırını aç; süzme yazdıkça uygulanır
+    pub fn start_search(&mut self, mode: SearchMode) {
+        self.set_view(ViewMode::Normal);
+        if !self.is_searching() {
+            self.search_origin = self.get_selected().map(|track| track.id());
+        }
+        self.search_mode = mode;
+        self.search_input = true;
+        self.search_query.clear();
+        self.apply_search();
+    }
+
+    /// Arama satırına karakter ekle
+    pub fn search_push(&mut self, c: char) {
+        self.search_query.push(c);
+        self.apply_search();
+    }
+
+    /// Arama satırındaki son karakteri sil
+    pub fn search_pop(&mut self) {
+        self.search_query.pop();
+        self.apply_search();
+    }
+
+    /// Yazmayı bitir; süzme açık kalır
+    pub fn submit_search(&mut self) {
+        if self.search_query.trim().is_empty() {
+            self.clear_search();
+        } else {
+            self.search_input = false;
+        }
+    }
+
+    /// Aramayı temizle ve aramadan önceki seçimi geri getir
+    pub fn clear_search(&mut self) {
+        self.search_input = false;
+        self.search_query.clear();
+        self.update_filtered_items();
+        let origin = self.search_origin.take();
+        self.select_track(origin.as_ref());
+    }
+
+    /// Arama açık mı (yazılıyor veya liste süzülmüş)?
+    pub fn is_searching(&self) -> bool {
+        self.search_input || !self.search_query.is_empty()
+    }
+
+    /// Bulanık arama sorgusu var mı?
+    pub fn fuzzy_active(&self) -> bool {
+        self.search_mode == SearchMode::Fuzzy && !self.search_query.trim().is_empty()
+    }
+
+    /// Arama metni değişti: listeyi süz, ilk eşleşmeyi seç; bulanık aramada
+    /// sonuçlar gelene kadar önceki liste gösterilir
+    fn apply_search(&mut self) {
+        if self.fuzzy_active() {
+            self.run_finder();
+            return;
+        }
+        self.update_filtered_items();
+        if self.search_query.trim().is_empty() {
+            let origin = self.search_origin.clone();
+            self.select_track(origin.as_ref());
+        } else {
+            self.state.select(None);
+            self.select_first();
+        }
+    }
+
+    /// Bulanık aramayı arka planda başlat (adaylar gerekiyorsa yenilenir)
+    fn run_finder(&mut self) {
+        if self.finder_stale {
+            self.finder
+                .set_candidates(self.items.iter().map(finder::candidate_text).collect());
+            self.finder_stale = false;
+        }
+        self.finder.search(self.search_query.trim());
+        self.fuzzy_pending = true;
+    }
+
+    /// Bulanık aramanın sonucu geldiyse listeyi güncelle
+    pub fn poll_finder(&mut self) {
+        let Some(result) = self.finder.poll() else {
+            return;
+        };
+        self.fuzzy_pending = false;
+        if !self.fuzzy_active() {
+            return;
+        }
+        self.fuzzy_matches = result.matches;
+        self.update_filtered_items();
+        self.state.select(None);
         self.select_first();
     }
 
+    /// Parça listesi değişti: bulanık arama açıksa eski sonuçlar geçersizdir
+    fn items_changed(&mut self) {
+        self.total_tracks = self.items.len();
+        self.finder_stale = true;
+        self.search_texts.clear();
+        self.browser.mark_stale();
+        self.folders.mark_stale();
+        self.path_index.clear();
+        if self.fuzzy_active() {
+            self.fuzzy_matches.clear();
+            self.run_finder();
+        }
+    }
+
+    /// Sonraki (ileri) veya önceki eşleşmeye atla (liste sonunda başa sarar)
+    pub fn jump_to_match(&mut self, forward: bool) {
+        if self.current_view == ViewMode::Equalizer {
+            self.cycle_eq_preset(forward);
+            return;
+        }
+        if self.search_query.is_empty() || self.current_view != ViewMode::Normal {
+            return;
+        }
+        if forward {
+            self.next();
+        } else {
+            self.previous();
+        }
+    }
+
+    /// Esc: aramayı temizle veya yardımı kapat; yapacak bir şey yoksa çıkılmalı (true)
+    pub fn back(&mut self) -> bool {
+        if self.prompt.is_some() {
+            self.prompt = None;
+        } else if self.is_searching() {
+            self.clear_search();
+        } else if self.show_help {
+            self.toggle_help();
+        } else if !self.marked.is_empty() {
+            self.marked.clear();
+        } else {
+            return true;
+        }
+        false
+    }
+
+    /// Kütüphaneyi artımlı olarak yeniden tara; çalınamayan parça işaretleri sıfırlanır
+    pub fn refresh(&mut self) {
+        self.broken.clear();
+        self.start_scan();
+    }
+
+    /// Arka plan taramasını başlat (zaten çalışıyorsa bir şey yapma)
+    pub fn start_scan(&mut self) {
+        if self.scan_rx.is_some() {
+            return;
+        }
+        // Liste boşsa parçalar bulundukça eklenir, doluysa tarama bitince tek seferde değiştirilir
+        self.scan_live = self.items.is_empty();
+        self.scan_buffer.clear();
+        self.scan_playlists.clear();
+        self.scan_progress = ScanProgress::default();
+        self.scan_rx = Some(self.library.spawn_scan());
+    }
+
+    /// Tarama thread'inden gelen mesajları işle
+    pub fn poll_scan(&mut self) {
+        let Some(rx) = self.scan_rx.take() else {
+            return;
+        };
+
+        let mut added = false;
+        let mut finished = false;
+        let mut lost = false;
+        loop {
+            match rx.try_recv() {
+                Ok(ScanEvent::Track(track)) => {
+                    if self.scan_live {
+                        self.items.push(track);
+                        added = true;
+                    } else {
+                        self.scan_buffer.push(track);
+                    }
+                }
+                Ok(ScanEvent::Playlist(path)) => {
+                    self.playlists.add(path.clone());
+                    self.scan_playlists.push(path);
+                }
+                Ok(ScanEvent::Progress(progress)) => self.scan_progress = progress,
+                Ok(ScanEvent::Finished(progress, index)) => {
+                    self.scan_progress = progress;
+                    self.library.index = index;
+                    finished = true;
+                    break;
+                }
+                Err(TryRecvError::Empty) => break,
+                Err(TryRecvError::Disconnected) => {
+                    // Thread beklenmedik şekilde bitti; eldeki indeks tarama öncesinden kalmadır
+                    self.scan_progress.done = true;
+                    self.library.disable_save();
+                    lost = true;
+                    self.messages.push(
+                        Level::Error,
+                        "Library scan stopped unexpectedly; the library index will not be saved this session",
+                        None,
+                    );
+                    finished = true;
+                    break;
+                }
+            }
+        }
+
+        if finished {
+            if !lost {
+                self.report_scan();
+            }
+            // Silinen listeler de görünümden kalkar
+            let playlists = std::mem::take(&mut self.scan_playlists);
+            self.playlists.set_files(playlists);
+            if !self.scan_live {
+                let items = std::mem::take(&mut self.scan_buffer);
+                self.set_items(items);
+            }
+            let measurements = std::mem::take(&mut self.loudness_pending);
+            if !measurements.is_empty() {
+                self.apply_loudness(measurements);
+            }
+            self.refresh_smart();
+        } else {
+            self.scan_rx = Some(rx);
+        }
+        if added {
+            let had_selection = self.state.selected().is_some();
+            self.items_changed();
+            self.update_filtered_items();
+            if !had_selection {
+                self.select_first();
+            }
+        }
+    }
+
+    /// Tarama sonucunu günlüğe yaz
+    fn report_scan(&mut self) {
+        let progress = self.scan_progress;
+        if progress.errors > 0 {
+            self.messages.push(
+                Level::Warning,
+                format!(
+                    "Library scan finished: {} files, {} could not be read",
+                    progress.seen, progress.errors
+                ),
+                None,
+            );
+        } else {
+            self.messages.push(
+                Level::Info,
+                format!("Library scan finished: {} files", progress.seen),
+                None,
+            );
+        }
+    }
+
+    /// Ses yüksekliği eksik parçaların (ve albümlerinin) ölçümünü arka planda başlat
+    pub fn analyze_loudness(&mut self) {
+        if self.loudness_rx.is_some() {
+            self.messages
+                .push(Level::Info, "Loudness analysis is already running", None);
+            return;
+        }
+        let files = loudness::pending(&self.items);
+        if files.is_empty() {
+            self.messages.push(
+                Level::Info,
+                "All tracks already have ReplayGain values",
+                None,
+            );
+            return;
+        }
+        self.messages.push(
+            Level::Info,
+            format!("Measuring the loudness of {} files", files.len()),
+            None,
+        );
+        self.loudness_progress = (0, files.len());
+        self.loudness_rx = Some(loudness::spawn_analyze(files));
+    }
+
+    /// Ölçüm thread'inden gelen mesajları işle
+    pub fn poll_loudness(&mut self) {
+        let Some(rx) = self.loudness_rx.take() else {
+            return;
+        };
+        loop {
+            match rx.try_recv() {
+                Ok(LoudnessEvent::Progress(done)) => self.loudness_progress.0 = done,
+                Ok(LoudnessEvent::Finished(measurements)) => {
+                    self.report_loudness(&measurements);
+                    if self.is_scanning() {
+                        // İndeks tarama thread'inde; değerler tarama bitince yazılır
+                        self.loudness_pending = measurements;
+                    } else {
+                        self.apply_loudness(measurements);
+                    }
+                    return;
+                }
+                Err(TryRecvError::Empty) => break,
+                Err(TryRecvError::Disconnected) => return,
+            }
+        }
+        self.loudness_rx = Some(rx);
+    }
+
+    /// Ölçüm sonucunu günlüğe yaz
+    fn report_loudness(&mut self, measurements: &[Measurement]) {
+        let mut errors = 0;
+        for measurement in measurements {
+            if let Err(e) = &measurement.result {
+                errors += 1;
+                self.messages.push(
+                    Level::Warning,
+                    format!("Could not measure loudness: {}", e),
+                    Some(measurement.path.clone()),
+                );
+            }
+        }
+        self.messages.push(
+            if errors > 0 {
+                Level::Warning
+            } else {
+                Level::Success
+            },
+            format!(
+                "Loudness analysis finished: {} files measured, {} failed",
+                measurements.len() - errors,
+                errors
+            ),
+            None,
+        );
+    }
+
+    /// Ölçülen değerleri parçalara ve indekse yaz
+    fn apply_loudness(&mut self, measurements: Vec<Measurement>) {
+        let values: HashMap<PathBuf, _> = measurements
+            .into_iter()
+            .filter_map(|measurement| Some((measurement.path, measurement.result.ok()?)))
+            .collect();
+        for track in &mut self.items {
+            if let Some(replay_gain) = values.get(&track.path) {
+                track.replay_gain = *replay_gain;
+            }
+        }
+        for (path, replay_gain) in &values {
+            self.library.index.set_replay_gain(path, *replay_gain);
+        }
+        if let Err(e) = self.library.save() {
+            self.messages.push(
+                Level::Error,
+                format!("Could not save the library index: {}", e),
+                None,
+            );
+        }
+    }
+
+    /// Ses yüksekliği ölçümü devam ediyor mu?
+    pub fn is_analyzing(&self) -> bool {
+        self.loudness_rx.is_some()
+    }
+
+    /// Tarama devam ediyor mu?
+    pub fn is_scanning(&self) -> bool {
+        self.scan_rx.is_some()
+    }
+
+    /// Parça listesini değiştir, seçili parçayı mümkünse koru
+    fn set_items(&mut self, items: Vec<Track>) {
+        let selected = self.get_selected().map(|track| track.id());
+        self.folders.pin_selection(&self.items);
+
+        self.items = items;
+        self.items_changed();
+        self.update_filtered_items();
+        self.select_track(selected.as_ref());
+    }
+
     /// Seçili öğeyi döndür
-    pub fn get_selected(&self) -> Option<PathBuf> {
+    pub fn get_selected(&self) -> Option<Track> {
         self.state
             .selected()
-            .and_then(|i| self.filtered_items.get(i))
-            .map(|(_, path)| path.clone())
+            .and_then(|i| self.filtered_track(i))
+            .cloned()
     }
 
     /// Liste boş mu?
@@ -171,83 +869,1263 @@ impl App {
         self.filtered_items.is_empty()
     }
 
-    /// Mevcut görünüm listesi
-    pub fn get_display_items(&self) -> &Vec<(usize, PathBuf)> {
-        &self.filtered_items
+    /// Seçili öğeyi çal: kütüphanede parçayı kuyruğa alıp çalar, kuyrukta o konuma atlar
+    pub fn play_selected(&mut self) {
+        match self.current_view {
+            ViewMode::Queue => {
+                if let Some(index) = self.queue_state.selected()
+                    && let Some(track) = self.queue.jump(index).cloned()
+                {
+                    let _ = self.play_track(track);
+                }
+            }
+            ViewMode::Messages => {}
+            ViewMode::Equalizer => self.toggle_equalizer(),
+            ViewMode::Effects => self.toggle_effect(),
+            ViewMode::Browser => match self.browser.pane {
+                Pane::Artists => self.browser.next_pane(),
+                Pane::Albums => self.play_album(),
+                Pane::Tracks => {
+                    let start = self.browser.track_state.selected().unwrap_or(0);
+                    self.play_album_from(start);
+                }
+            },
+            ViewMode::Playlists => {
+                let start = match self.playlists.pane {
+                    PlaylistPane::Lists => 0,
+                    PlaylistPane::Entries => self.playlists.entry_state.selected().unwrap_or(0),
+                };
+                self.play_playlist_from(start);
+            }
+            ViewMode::Folders => {
+                self.folders.refresh(&self.library.roots, &self.items);
+                match self.folders.selected_row() {
+                    Some(TreeRow::Folder { .. }) => self.folders.toggle(),
+                    Some(TreeRow::Track { track, folder, .. }) => {
+                        // Parçanın klasörü o parçadan itibaren çalınır
+                        let tracks = self.folders.tree.nodes[folder].tracks.clone();
+                        let start = tracks.iter().position(|&i| i == track).unwrap_or(0);
+                        self.play_indices(&tracks, start);
+                    }
+                    None => {}
+                }
+            }
+            _ => {
+                if let Some(selected) = self.get_selected() {
+                    let track = self.queue.play_now(selected).clone();
+                    let _ = self.play_track(track);
+                }
+            }
+        }
     }
 
-    /// Müzik çalmayı başlat
-    pub fn play_track(&mut self, track: PathBuf) -> Result<(), Box<dyn std::error::Error>> {
-        use rodio::{Decoder, OutputStream, Sink};
-        use std::fs::File;
-        use std::io::BufReader;
+    /// Seçili parçayı kuyruğun sonuna ekle
+    pub fn queue_append(&mut self) {
+        for track in self.selected_tracks() {
+            self.queue.push(track);
+        }
+    }
 
-        // Eğer zaten bir şarkı çalıyorsa durdur
-        if let Some(ref sink) = self.sink {
-            sink.stop();
+    /// Seçili parçayı çalan parçanın arkasına ekle
+    pub fn queue_play_next(&mut self) {
+        // Sırayla arkaya eklenebilmeleri için tersten eklenir
+        for track in self.selected_tracks().into_iter().rev() {
+            self.queue.play_next(track);
         }
+    }
+
+    /// Kuyruğa eklenecek parçalar: tarayıcıda etkin sütundaki seçim, klasör ağacında seçili
+    /// klasörün tüm parçaları, kütüphanede işaretli parçalar, diğer durumlarda seçili parça
+    fn selected_tracks(&mut self) -> Vec<Track> {
+        let indices = match self.current_view {
+            ViewMode::Normal if !self.marked.is_empty() => {
+                return self
+                    .items
+                    .iter()
+                    .filter(|track| self.marked.contains(&track.id()))
+                    .cloned()
+                    .collect();
+            }
+            ViewMode::Browser => {
+                self.browser.refresh(&self.items);
+                self.browser.selected_tracks()
+            }
+            ViewMode::Folders => {
+                self.folders.refresh(&self.library.roots, &self.items);
+                self.folders.selected_tracks()
+            }
+            ViewMode::Playlists => {
+                let entries = self.selected_playlist_entries();
+                let tracks = match self.playlists.pane {
+                    PlaylistPane::Lists => entries,
+                    PlaylistPane::Entries => self
+                        .playlists
+                        .entry_state
+                        .selected()
+                        .and_then(|i| entries.get(i).cloned())
+                        .into_iter()
+                        .collect(),
+                };
+                return self
+                    .resolve_entries(&tracks)
+                    .into_iter()
+                    .flatten()
+                    .collect();
+            }
+            _ => return self.get_selected().into_iter().collect(),
+        };
+        indices
+            .into_iter()
+            .filter_map(|i| self.items.get(i).cloned())
+            .collect()
+    }
 
-        // Stream'i ilk kez oluştur veya mevcut olanı kullan
-        if self._stream.is_none() {
-            let (stream, handle) = OutputStream::try_default()?;
-            self._stream = Some(stream);
-            self.stream_handle = Some(handle);
+    /// Tarayıcıda seçili albümü baştan çal
+    pub fn play_album(&mut self) {
+        self.play_album_from(0);
+    }
+
+    /// Kuyruğu seçili albümle değiştir ve verilen parçadan çalmaya başla
+    fn play_album_from(&mut self, start: usize) {
+        if self.current_view != ViewMode::Browser {
+            return;
+        }
+        self.browser.refresh(&self.items);
+        if let Some(album) = self.browser.selected_album() {
+            let tracks = album.tracks.clone();
+            self.play_indices(&tracks, start);
+        }
+    }
+
+    /// Kuyruğu verilen parçalarla (parça listesindeki sıralar) değiştir ve `start`'tan çal
+    fn play_indices(&mut self, indices: &[usize], start: usize) {
+        let tracks: Vec<Track> = indices
+            .iter()
+            .filter_map(|&i| self.items.get(i).cloned())
+            .collect();
+        if tracks.is_empty() {
+            return;
+        }
+        self.queue.clear();
+        self.queue_state.select(None);
+        for track in tracks {
+            self.queue.push(track);
         }
+        if let Some(track) = self.queue.jump(start).cloned() {
+            let _ = self.play_track(track);
+        }
+    }
 
-        if let Some(ref handle) = self.stream_handle {
-            let sink = Sink::try_new(handle)?;
-            let file = File::open(&track)?;
-            let source = Decoder::new(BufReader::new(file))?;
+    /// Tarayıcıda seçili sanatçının tüm parçalarını kuyruğun sonuna ekle
+    pub fn queue_artist(&mut self) {
+        if self.current_view != ViewMode::Browser {
+            return;
+        }
+        self.browser.refresh(&self.items);
+        let Some(artist) = self.browser.selected_artist() else {
+            return;
+        };
+        let tracks: Vec<Track> = artist
+            .tracks()
+            .filter_map(|i| self.items.get(i).cloned())
+            .collect();
+        for track in tracks {
+            self.queue.push(track);
+        }
+    }
 
-            sink.append(source);
+    /// Klasör ağacında seçili klasörü alt klasörleriyle birlikte baştan çal
+    pub fn play_folder(&mut self) {
+        if self.current_view != ViewMode::Folders {
+            return;
+        }
+        self.folders.refresh(&self.library.roots, &self.items);
+        if let Some(folder) = self.folders.selected_folder() {
+            let tracks = self.folders.tree.tracks(folder);
+            self.play_indices(&tracks, 0);
+        }
+    }
 
-            self.sink = Some(Arc::new(sink));
-            self.current_track = Some(track);
-            self.playback_state = PlaybackState::Playing;
+    /// Klasör ağacında seçili klasörün tüm parçalarını kuyruğun sonuna ekle
+    pub fn queue_folder(&mut self) {
+        if self.current_view != ViewMode::Folders {
+            return;
+        }
+        self.folders.refresh(&self.library.roots, &self.items);
+        let Some(folder) = self.folders.selected_folder() else {
+            return;
+        };
+        for i in self.folders.tree.tracks(folder) {
+            if let Some(track) = self.items.get(i) {
+                self.queue.push(track.clone());
+            }
         }
+    }
 
-        Ok(())
+    /// Klasör ağacında açık klasörü kapat veya üst klasöre git
+    pub fn collapse_folder(&mut self) {
+        if self.current_view == ViewMode::Folders {
+            self.folders.refresh(&self.library.roots, &self.items);
+            self.folders.collapse();
+        }
     }
 
-    /// Müziği duraklat/devam ettir
-    pub fn toggle_pause(&mut self) {
-        if let Some(ref sink) = self.sink {
-            match self.playback_state {
-                PlaybackState::Playing => {
-                    sink.pause();
-                    self.playback_state = PlaybackState::Paused;
+    /// Tarayıcıda sonraki (ileri) veya önceki sütuna geç
+    pub fn switch_pane(&mut self, forward: bool) {
+        match self.current_view {
+            ViewMode::Browser if forward => self.browser.next_pane(),
+            ViewMode::Browser => self.browser.previous_pane(),
+            ViewMode::Playlists => self.playlists.switch_pane(),
+            ViewMode::Equalizer => self.select_band(if forward { 1 } else { -1 }),
+            _ => {}
+        }
+    }
+
+    /// Seçili çalma listesinin satırları
+    fn selected_playlist_entries(&mut self) -> Vec<PlaylistEntry> {
+        self.playlists
+            .selected()
+            .map(|list| list.playlist.entries.clone())
+            .unwrap_or_default()
+    }
+
+    /// Liste satırlarını parçalara çevir: kütüphanedeki parçalar olduğu gibi, kütüphane dışındaki
+    /// dosyalar etiketlerinden okunur; bulunamayan satırlar `None` olur ve günlüğe yazılır
+    fn resolve_entries(&mut self, entries: &[PlaylistEntry]) -> Vec<Option<Track>> {
+        if self.path_index.is_empty() {
+            // CUE sayfasıyla bölünmüş dosyalar ilk sanal parçalarıyla eşleşir
+            for (i, track) in self.items.iter().enumerate() {
+                self.path_index.entry(track.path.clone()).or_insert(i);
+            }
+        }
+        let mut resolved = Vec::with_capacity(entries.len());
+        for entry in entries {
+            let track = match self.path_index.get(&entry.path) {
+                Some(&i) => Some(self.items[i].clone()),
+                None if entry.path.is_file() => Some(self.library.load_file(&entry.path)),
+                None => {
+                    self.messages.push(
+                        Level::Warning,
+                        format!("Playlist entry not found: {}", entry.describe()),
+                        Some(entry.path.clone()).filter(|path| !path.as_os_str().is_empty()),
+                    );
+                    None
+                }
+            };
+            resolved.push(track);
+        }
+        resolved
+    }
+
+    /// Kuyruğu seçili çalma listesiyle değiştir ve verilen satırdan çal
+    fn play_playlist_from(&mut self, start: usize) {
+        let entries = self.selected_playlist_entries();
+        let resolved = self.resolve_entries(&entries);
+        // Bulunamayan satırlar atlandığından başlangıç satırı bulunabilenler arasında sayılır
+        let start = resolved.iter().take(start).flatten().count();
+        let tracks: Vec<Track> = resolved.into_iter().flatten().collect();
+        if tracks.is_empty() {
+            return;
+        }
+        self.queue.clear();
+        self.queue_state.select(None);
+        for track in tracks {
+            self.queue.push(track);
+        }
+        if let Some(track) = self.queue.jump(start).cloned() {
+            let _ = self.play_track(track);
+        }
+    }
+
+    /// Kaydedilecek parçalar: kuyrukta tüm kuyruk, süzülmüş kütüphanede tüm eşleşmeler,
+    /// diğer durumlarda seçim; kayıt yolu alt satırda sorulur
+    pub fn start_save_playlist(&mut self) {
+        let (tracks, name) = match self.current_view {
+            ViewMode::Queue => (self.queue.iter().cloned().collect(), "queue"),
+            ViewMode::Normal if !self.search_query.trim().is_empty() => {
+                (self.filtered_tracks().cloned().collect(), "playlist")
+            }
+            _ => (self.selected_tracks(), "playlist"),
+        };
+        if tracks.is_empty() {
+            self.messages
+                .push(Level::Warning, "Nothing to save to a playlist", None);
+            return;
+        }
+        // Varsayılan olarak ilk kütüphane kökünün altına kaydedilir
+        let folder = self
+            .library
+            .roots
+            .first()
+            .and_then(|root| root.canonicalize().ok())
+            .or_else(|| std::env::current_dir().ok())
+            .unwrap_or_default();
+        self.prompt = Some(Prompt {
+            kind: PromptKind::SavePlaylist(tracks),
+            text: folder.join(format!("{}.m3u8", name)).display().to_string(),
+        });
+    }
+
+    /// Metin girişi açık mı (arama satırı veya istem)?
+    pub fn is_typing(&self) -> bool {
+        self.search_input || self.prompt.is_some()
+    }
+
+    /// Yazılan karakteri istem satırına veya aramaya ekle
+    pub fn input_char(&mut self, c: char) {
+        match &mut self.prompt {
+            Some(prompt) => prompt.text.push(c),
+            None => self.search_push(c),
+        }
+    }
+
+    /// İstem satırındaki veya aramadaki son karakteri sil
+    pub fn input_backspace(&mut self) {
+        match &mut self.prompt {
+            Some(prompt) => {
+                prompt.text.pop();
+            }
+            None => self.search_pop(),
+        }
+    }
+
+    /// Enter: istemi tamamla veya aramayı kabul et
+    pub fn input_submit(&mut self) {
+        match self.prompt.take() {
+            Some(prompt) => self.run_prompt(prompt),
+            None => self.submit_search(),
+        }
+    }
+
+    /// Esc: istemi kapat veya aramayı temizle
+    pub fn input_cancel(&mut self) {
+        if self.prompt.take().is_none() {
+            self.clear_search();
+        }
+    }
+
+    fn run_prompt(&mut self, prompt: Prompt) {
+        let text = prompt.text.trim();
+        if text.is_empty() {
+            return;
+        }
+        match prompt.kind {
+            PromptKind::SavePlaylist(tracks) => {
+                let mut path = expand_home(Path::new(text));
+                if path.extension().is_none() {
+                    path.set_extension("m3u8");
                 }
-                PlaybackState::Paused => {
-                    sink.play();
-                    self.playback_state = PlaybackState::Playing;
+                self.save_playlist(&path, &tracks);
+            }
+            PromptKind::NewPlaylist => {
+                self.create_playlist(text);
+            }
+            PromptKind::AddToPlaylist(tracks) => self.add_to_playlist(text, &tracks),
+            PromptKind::RenamePlaylist(path) => self.rename_playlist(&path, text),
+            PromptKind::ConfirmDelete(path) => {
+                if matches!(text.to_lowercase().as_str(), "y" | "yes") {
+                    self.delete_playlist(&path);
+                }
+            }
+            PromptKind::SmartName => {
+                let name = text.to_string();
+                if self
+                    .smart_definitions()
+                    .any(|(smart, _)| smart.name == name)
+                {
+                    self.messages.push(
+                        Level::Error,
+                        format!("A smart playlist named '{}' already exists", name),
+                        None,
+                    );
+                    self.prompt = Some(Prompt {
+                        kind: PromptKind::SmartName,
+                        text: name,
+                    });
+                } else {
+                    self.prompt = Some(Prompt {
+                        kind: PromptKind::SmartRules(name),
+                        text: String::new(),
+                    });
+                }
+            }
+            PromptKind::SmartRules(name) => self.create_smart_playlist(name, text),
+            PromptKind::ConfirmDeleteSmart(name) => {
+                if matches!(text.to_lowercase().as_str(), "y" | "yes") {
+                    self.delete_smart_playlist(&name);
                 }
-                PlaybackState::Stopped => {
-                    // Şarkı durduysa hiçbir şey yapma
+            }
+        }
+    }
+
+    /// Parçaları çalma listesi olarak kaydet; kütüphane kökleri altındaysa listeler görünümüne eklenir
+    fn save_playlist(&mut self, path: &Path, tracks: &[Track]) {
+        if let Err(e) = playlist::save(path, tracks) {
+            self.messages.push(
+                Level::Error,
+                format!("Could not save playlist: {}", e),
+                Some(path.to_path_buf()),
+            );
+            return;
+        }
+        let path = path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
+        let in_library = self
+            .library
+            .roots
+            .iter()
+            .filter_map(|root| root.canonicalize().ok())
+            .any(|root| path.starts_with(root));
+        if let Some(store) = self.store.as_ref().filter(|store| store.contains(&path)) {
+            self.playlists.set_named(store.list());
+            self.playlists.reload(&path);
+        } else if in_library {
+            self.playlists.add(path.clone());
+            self.playlists.reload(&path);
+        }
+        self.messages.push(
+            Level::Success,
+            format!("Saved {} tracks to a playlist", tracks.len()),
+            Some(path),
+        );
+    }
+
+    /// Kütüphanede seçili parçayı işaretle veya işaretini kaldır ve sonraki parçaya geç
+    pub fn toggle_mark(&mut self) {
+        if self.current_view != ViewMode::Normal {
+            return;
+        }
+        let Some(track) = self.get_selected() else {
+            return;
+        };
+        let id = track.id();
+        if !self.marked.remove(&id) {
+            self.marked.insert(id);
+        }
+        // Son parçada başa sarılmaz
+        if self
+            .state
+            .selected()
+            .is_some_and(|i| i + 1 < self.filtered_items.len())
+        {
+            self.next();
+        }
+    }
+
+    /// Yeni adlandırılmış liste için ad sor
+    pub fn start_new_playlist(&mut self) {
+        self.prompt = Some(Prompt {
+            kind: PromptKind::NewPlaylist,
+            text: String::new(),
+        });
+    }
+
+    /// Seçili veya işaretli parçaları eklemek için liste adını sor; son kullanılan liste önerilir
+    pub fn start_add_to_playlist(&mut self) {
+        let tracks = self.selected_tracks();
+        if tracks.is_empty() {
+            self.messages
+                .push(Level::Warning, "Nothing to add to a playlist", None);
+            return;
+        }
+        let suggested = self
+            .last_playlist
+            .as_ref()
+            .filter(|path| self.playlists.is_named(path))
+            .map(|path| playlist::name_from_path(path))
+            .unwrap_or_default();
+        self.prompt = Some(Prompt {
+            kind: PromptKind::AddToPlaylist(tracks),
+            text: suggested,
+        });
+    }
+
+    /// Listeler görünümünde seçili adlandırılmış listeyi yeniden adlandırmak için ad sor
+    pub fn start_rename_playlist(&mut self) {
+        if let Some(path) = self.editable_playlist() {
+            self.prompt = Some(Prompt {
+                text: playlist::name_from_path(&path),
+                kind: PromptKind::RenamePlaylist(path),
+            });
+        }
+    }
+
+    /// Listeler görünümünde seçili adlandırılmış liste; kütüphanedeki dosyalar yalnızca okunur
+    fn editable_playlist(&mut self) -> Option<PathBuf> {
+        if self.current_view != ViewMode::Playlists {
+            return None;
+        }
+        if self.playlists.selected_smart().is_some() {
+            self.messages.push(
+                Level::Warning,
+                "Smart playlists are filled by their rules and cannot be edited",
+                None,
+            );
+            return None;
+        }
+        let path = self.playlists.selected_path()?.clone();
+        if !self.playlists.is_named(&path) {
+            self.messages.push(
+                Level::Warning,
+                "Only playlists created in meloic can be edited",
+                Some(path),
+            );
+            return None;
+        }
+        Some(path)
+    }
+
+    /// Adlandırılmış listeler klasörü; veri klasörü bilinmiyorsa günlüğe yazılır
+    fn playlist_store(&mut self) -> Option<PlaylistStore> {
+        if self.store.is_none() {
+            self.messages.push(
+                Level::Error,
+                "Could not find a data directory for playlists",
+                None,
+            );
+        }
+        self.store.clone()
+    }
+
+    /// Boş bir adlandırılmış liste oluştur ve listeler görünümünde seç
+    fn create_playlist(&mut self, name: &str) -> Option<PathBuf> {
+        let store = self.playlist_store()?;
+        match store.create(name) {
+            Ok(path) => {
+                self.playlists.set_named(store.list());
+                self.playlists.select_path(Some(path.clone()));
+                self.messages.push(
+                    Level::Success,
+                    format!("Created playlist '{}'", playlist::name_from_path(&path)),
+                    None,
+                );
+                Some(path)
+            }
+            Err(e) => {
+                self.messages.push(
+                    Level::Error,
+                    format!("Could not create playlist: {}", e),
+                    None,
+                );
+                None
+            }
+        }
+    }
+
+    /// Parçaları adlandırılmış listenin sonuna ekle; bu adla liste yoksa oluşturulur
+    fn add_to_playlist(&mut self, name: &str, tracks: &[Track]) {
+        let existing = self
+            .playlists
+            .named
+            .iter()
+            .find(|path| playlist::name_from_path(path) == name)
+            .cloned();
+        let Some(path) = existing.or_else(|| self.create_playlist(name)) else {
+            return;
+        };
+        let list = self.playlists.playlist(&path);
+        // Okunamayan liste üzerine yazılmaz
+        if let Some(error) = &list.error {
+            let message = format!("Could not read playlist: {}", error);
+            self.messages.push(Level::Error, message, Some(path));
+            return;
+        }
+        let mut entries = list.playlist.entries.clone();
+        entries.extend(tracks.iter().map(PlaylistEntry::from_track));
+        if self.write_playlist(&path, entries) {
+            self.messages.push(
+                Level::Success,
+                format!(
+                    "Added {} tracks to '{}'",
+                    tracks.len(),
+                    playlist::name_from_path(&path)
+                ),
+                None,
+            );
+            self.last_playlist = Some(path);
+            self.marked.clear();
+        }
+    }
+
+    fn rename_playlist(&mut self, path: &Path, name: &str) {
+        let Some(store) = self.playlist_store() else {
+            return;
+        };
+        match store.rename(path, name) {
+            Ok(renamed) => {
+                self.playlists.set_named(store.list());
+                self.playlists.select_path(Some(renamed.clone()));
+                if self.last_playlist.as_deref() == Some(path) {
+                    self.last_playlist = Some(renamed.clone());
+                }
+                self.messages.push(
+                    Level::Success,
+                    format!(
+                        "Renamed playlist to '{}'",
+                        playlist::name_from_path(&renamed)
+                    ),
+                    None,
+                );
+            }
+            Err(e) => self.messages.push(
+                Level::Error,
+                format!("Could not rename playlist: {}", e),
+                Some(path.to_path_buf()),
+            ),
+        }
+    }
+
+    fn delete_playlist(&mut self, path: &Path) {
+        let Some(store) = self.playlist_store() else {
+            return;
+        };
+        match store.delete(path) {
+            Ok(()) => {
+                self.playlists.set_named(store.list());
+                if self.last_playlist.as_deref() == Some(path) {
+                    self.last_playlist = None;
+                }
+                self.messages.push(
+                    Level::Success,
+                    format!("Deleted playlist '{}'", playlist::name_from_path(path)),
+                    None,
+                );
+            }
+            Err(e) => self.messages.push(
+                Level::Error,
+                format!("Could not delete playlist: {}", e),
+                Some(path.to_path_buf()),
+            ),
+        }
+    }
+
+    /// Adlandırılmış listenin satırlarını diske yaz ve görünümü güncelle
+    fn write_playlist(&mut self, path: &Path, entries: Vec<PlaylistEntry>) -> bool {
+        let Some(store) = self.playlist_store() else {
+            return false;
+        };
+        match store.write(path, &entries) {
+            Ok(()) => {
+                self.playlists.set_entries(path, entries);
+                true
+            }
+            Err(e) => {
+                self.messages.push(
+                    Level::Error,
+                    format!("Could not save playlist: {}", e),
+                    Some(path.to_path_buf()),
+                );
+                false
+            }
+        }
+    }
+
+    /// Listeler görünümünde seçili satırı çıkar veya seçili listeyi (onayla) sil
+    fn remove_playlist_item(&mut self) {
+        if self.playlists.pane == PlaylistPane::Lists
+            && let Some(smart) = self.playlists.selected_smart()
+        {
+            let name = smart.definition.name.clone();
+            if smart.from_config {
+                self.messages.push(
+                    Level::Warning,
+                    format!("'{}' is defined in the config file", name),
+                    None,
+                );
+            } else {
+                self.prompt = Some(Prompt {
+                    kind: PromptKind::ConfirmDeleteSmart(name),
+                    text: String::new(),
+                });
+            }
+            return;
+        }
+        let Some(path) = self.editable_playlist() else {
+            return;
+        };
+        match self.playlists.pane {
+            PlaylistPane::Lists => {
+                self.prompt = Some(Prompt {
+                    kind: PromptKind::ConfirmDelete(path),
+                    text: String::new(),
+                })
+            }
+            PlaylistPane::Entries => {
+                let Some(index) = self.playlists.entry_state.selected() else {
+                    return;
+                };
+                let mut entries = self.playlists.playlist(&path).playlist.entries.clone();
+                if index >= entries.len() {
+                    return;
+                }
+                entries.remove(index);
+                let len = entries.len();
+                if self.write_playlist(&path, entries) {
+                    let selected = (len > 0).then(|| index.min(len - 1));
+                    self.playlists.entry_state.select(selected);
+                }
+            }
+        }
+    }
+
+    /// Listeler görünümünde seçili satırı yukarı (-1) veya aşağı (+1) taşı
+    fn move_playlist_entry(&mut self, offset: isize) {
+        if self.playlists.pane != PlaylistPane::Entries {
+            return;
+        }
+        let Some(path) = self.editable_playlist() else {
+            return;
+        };
+        let Some(index) = self.playlists.entry_state.selected() else {
+            return;
+        };
+        let mut entries = self.playlists.playlist(&path).playlist.entries.clone();
+        let target = index.saturating_add_signed(offset);
+        if target == index || index >= entries.len() || target >= entries.len() {
+            return;
+        }
+        let entry = entries.remove(index);
+        entries.insert(target, entry);
+        if self.write_playlist(&path, entries) {
+            self.playlists.entry_state.select(Some(target));
+        }
+    }
+
+    /// Yeni akıllı liste için ad sor; ardından kurallar sorulur
+    pub fn start_smart_playlist(&mut self) {
+        self.prompt = Some(Prompt {
+            kind: PromptKind::SmartName,
+            text: String::new(),
+        });
+    }
+
+    /// Config'deki ve arayüzde oluşturulan akıllı listeler (config'dekiler mi?)
+    fn smart_definitions(&self) -> impl Iterator<Item = (&SmartPlaylist, bool)> {
+        self.smart_config
+            .iter()
+            .map(|smart| (smart, true))
+            .chain(self.smart_saved.iter().map(|smart| (smart, false)))
+    }
+
+    /// Akıllı listeleri parça listesine göre yeniden doldur (açılışta ve her tarama sonunda)
+    pub fn refresh_smart(&mut self) {
+        let now = unix_now();
+        let lists = self
+            .smart_definitions()
+            .map(|(smart, from_config)| {
+                let result = smart
+                    .evaluate(&self.items, &self.history, now)
+                    .map(|indices| {
+                        indices
+                            .into_iter()
+                            .map(|i| PlaylistEntry::from_track(&self.items[i]))
+                            .collect()
+                    });
+                SmartList::new(smart.clone(), from_config, result)
+            })
+            .collect();
+        self.playlists.set_smart(lists);
+    }
+
+    /// Kuralları ayrıştır ve akıllı listeyi kaydet; kurallar geçersizse istem yeniden açılır
+    fn create_smart_playlist(&mut self, name: String, text: &str) {
+        let smart = match SmartPlaylist::from_text(&name, text) {
+            Ok(smart) => smart,
+            Err(e) => {
+                self.messages
+                    .push(Level::Error, format!("Invalid smart playlist: {}", e), None);
+                self.prompt = Some(Prompt {
+                    kind: PromptKind::SmartRules(name),
+                    text: text.to_string(),
+                });
+                return;
+            }
+        };
+        let Some(store) = self.playlist_store() else {
+            return;
+        };
+        self.smart_saved.push(smart);
+        if let Err(e) = store.save_smart(&self.smart_saved) {
+            self.smart_saved.pop();
+            self.messages.push(
+                Level::Error,
+                format!("Could not save smart playlist: {}", e),
+                None,
+            );
+            return;
+        }
+        self.refresh_smart();
+        self.playlists.select_smart(&name);
+        let count = self
+            .playlists
+            .selected_smart()
+            .map_or(0, |smart| smart.list.playlist.entries.len());
+        self.messages.push(
+            Level::Success,
+            format!("Created smart playlist '{}' with {} tracks", name, count),
+            None,
+        );
+    }
+
+    fn delete_smart_playlist(&mut self, name: &str) {
+        let Some(store) = self.playlist_store() else {
+            return;
+        };
+        let remaining: Vec<SmartPlaylist> = self
+            .smart_saved
+            .iter()
+            .filter(|smart| smart.name != name)
+            .cloned()
+            .collect();
+        if let Err(e) = store.save_smart(&remaining) {
+            self.messages.push(
+                Level::Error,
+                format!("Could not delete smart playlist: {}", e),
+                None,
+            );
+            return;
+        }
+        self.smart_saved = remaining;
+        self.refresh_smart();
+        self.messages.push(
+            Level::Success,
+            format!("Deleted smart playlist '{}'", name),
+            None,
+        );
+    }
+
+    /// Kuyrukta seçili parçayı çıkar; listeler görünümünde liste satırını çıkarır veya listeyi siler
+    pub fn remove_item(&mut self) {
+        if self.current_view == ViewMode::Equalizer {
+            self.player.equalizer.set_gain(self.eq_band, 0.0);
+            return;
+        }
+        if self.current_view == ViewMode::Effects {
+            self.reset_effect();
+            return;
+        }
+        if self.current_view == ViewMode::Playlists {
+            self.remove_playlist_item();
+            return;
+        }
+        if self.current_view != ViewMode::Queue {
+            return;
+        }
+        if let Some(index) = self.queue_state.selected() {
+            self.queue.remove(index);
+            if self.queue.is_empty() {
+                self.queue_state.select(None);
+            } else if index >= self.queue.len() {
+                self.queue_state.select(Some(self.queue.len() - 1));
+            }
+        }
+    }
+
+    /// Kuyrukta (veya adlandırılmış listede) seçili parçayı yukarı (-1) veya aşağı (+1) taşı;
+    /// efektler görünümünde seçili efekti zincirde taşır
+    pub fn move_item(&mut self, offset: isize) {
+        if self.current_view == ViewMode::Effects {
+            self.move_effect(offset);
+            return;
+        }
+        if self.current_view == ViewMode::Playlists {
+            self.move_playlist_entry(offset);
+            return;
+        }
+        if self.current_view != ViewMode::Queue {
+            return;
+        }
+        if let Some(index) = self.queue_state.selected() {
+            let target = index.saturating_add_signed(offset);
+            if self.queue.move_item(index, target) {
+                self.queue_state.select(Some(target));
+            }
+        }
+    }
+
+    /// Kuyruğu temizle (çalan parça çalmaya devam eder)
+    pub fn clear_queue(&mut self) {
+        self.queue.clear();
+        self.queue_state.select(None);
+    }
+
+    /// Tüm listeyi kuyruğa ekle
+    pub fn enqueue_all(&mut self) {
+        for &i in &self.filtered_items {
+            self.queue.push(self.items[i].clone());
+        }
+    }
+
+    /// Karışık çalmayı aç/kapat
+    pub fn toggle_shuffle(&mut self) {
+        let shuffle = !self.queue.is_shuffled();
+        self.queue.set_shuffle(shuffle);
+        self.player.shuffled = shuffle;
+    }
+
+    /// Tekrar modunu değiştir (kapalı -> tümü -> tek parça)
+    pub fn cycle_repeat(&mut self) {
+        self.queue.repeat = self.queue.repeat.cycle();
+    }
+
+    /// Sıradaki parçaya geç
+    pub fn next_track(&mut self) {
+        let reference = self.player.current.clone();
+        self.advance(reference, false);
+    }
+
+    /// Kuyruktaki önceki parçaya dön
+    pub fn previous_track(&mut self) {
+        if let Some(track) = self.queue.back().cloned() {
+            let _ = self.play_track(track);
+        }
+    }
+
+    /// Kuyruktaki sıradaki parçayı çal; kuyruk bittiyse kütüphanede referans parçadan
+    /// sonra gelen (karışık modda rastgele) parçayla devam et
+    fn advance(&mut self, mut reference: Option<Track>, on_finish: bool) {
+        for attempt in 0..MAX_SKIPPED_TRACKS {
+            // Tekrar eden parça çalınamıyorsa aynı parçayı tekrar denemeye gerek yok
+            let next = if on_finish && attempt == 0 {
+                self.queue.advance_on_finish()
+            } else {
+                self.queue.advance()
+            };
+            let next = match next {
+                Some(track) => Some(track.clone()),
+                None => self
+                    .next_library_track(reference.as_ref())
+                    .map(|track| self.queue.play_now(track).clone()),
+            };
+            let Some(track) = next else {
+                self.player.stop();
+                return;
+            };
+            // Çalınamayan parçaları atla; ses cihazı yoksa denemeye devam etmenin anlamı yok
+            match self.play_track(track.clone()) {
+                Ok(()) => return,
+                Err(e) if e.is_track_error() => reference = Some(track),
+                Err(_) => return,
+            }
+        }
+        self.player.stop();
+    }
+
+    /// Parçayı çal; hata günlüğe yazılır ve bozuk dosyalar işaretlenir
+    fn play_track(&mut self, track: Track) -> Result<(), PlayError> {
+        let result = self.player.play(track.clone());
+        match &result {
+            Ok(()) => {
+                self.broken.remove(&track.path);
+                self.history.record(track.id());
+            }
+            Err(e) => {
+                if e.is_track_error() {
+                    self.broken.insert(track.path.clone());
                 }
+                self.messages.push(
+                    Level::Error,
+                    format!("Cannot play {}: {}", track.display_name(), e),
+                    Some(track.path),
+                );
             }
         }
+        result
+    }
+
+    /// Filtrelenmiş listede verilen parçadan sonra gelen parça; karışık modda rastgele bir parça
+    fn next_library_track(&self, reference: Option<&Track>) -> Option<Track> {
+        let is_reference = |track: &Track| reference.is_some_and(|r| r.is_same(track));
+        if self.queue.is_shuffled() {
+            let candidates: Vec<&Track> = self
+                .filtered_tracks()
+                .filter(|track| !is_reference(track))
+                .collect();
+            return candidates
+                .choose(&mut rand::thread_rng())
+                .map(|t| (*t).clone());
+        }
+
+        let position = self.filtered_tracks().position(is_reference)?;
+        self.filtered_track(position + 1).cloned()
+    }
+
+    /// Müziği duraklat/devam ettir
+    pub fn toggle_pause(&mut self) {
+        self.player.toggle_pause();
     }
 
     /// Müziği tamamen durdur
     pub fn stop_playback(&mut self) {
-        if let Some(ref sink) = self.sink {
-            sink.stop();
+        self.player.stop();
+    }
+
+    /// Çalan parçada ileri (pozitif) veya geri (negatif) adım kadar sar
+    pub fn seek_steps(&mut self, steps: i64, long: bool) {
+        // Ekolayzırda oklar bant seçer
+        if self.current_view == ViewMode::Equalizer {
+            self.select_band(steps.signum() as isize);
+            return;
+        }
+        // Efektlerde oklar seçili parametreyi değiştirir
+        if self.current_view == ViewMode::Effects {
+            self.adjust_effect(steps, long);
+            return;
         }
-        self.sink = None;
-        self.current_track = None;
-        self.playback_state = PlaybackState::Stopped;
-        // Stream'i kapatmıyoruz, çünkü tekrar kullanabiliriz
+        let step = if long {
+            self.seek_long_step
+        } else {
+            self.seek_step
+        };
+        let result = self.player.seek_by(steps * step as i64);
+        self.report_seek(result);
     }
 
-    /// Müzik çalma durumunu kontrol et
-    pub fn update_playback_status(&mut self) {
-        if let Some(ref sink) = self.sink {
-            if sink.empty() {
-                // Şarkı bitti
-                self.current_track = None;
-                self.playback_state = PlaybackState::Stopped;
-                self.sink = None;
+    /// Ekolayzırda önceki/sonraki banda geç (uçlarda başa/sona sarar)
+    fn select_band(&mut self, offset: isize) {
+        self.eq_band = (self.eq_band as isize + offset).rem_euclid(BAND_COUNT as isize) as usize;
+    }
+
+    /// Seçili bantın kazancını değiştir; ekolayzır kapalıysa açılır
+    fn adjust_band(&mut self, delta: f32) {
+        let equalizer = &self.player.equalizer;
+        let gain = (equalizer.gain(self.eq_band) + delta).clamp(-MAX_GAIN_DB, MAX_GAIN_DB);
+        equalizer.set_gain(self.eq_band, gain);
+        equalizer.set_enabled(true);
+    }
+
+    /// Sonraki/önceki hazır ayarı uygula; ekolayzır kapalıysa açılır
+    fn cycle_eq_preset(&mut self, forward: bool) {
+        if self.eq_presets.is_empty() {
+            return;
+        }
+        let len = self.eq_presets.len();
+        let next = match self.eq_preset_index() {
+            Some(i) if forward => (i + 1) % len,
+            Some(i) => (i + len - 1) % len,
+            None if forward => 0,
+            None => len - 1,
+        };
+        let equalizer = &self.player.equalizer;
+        equalizer.set_gains(&self.eq_presets[next].gains);
+        equalizer.set_enabled(true);
+    }
+
+    /// Geçerli kazançlarla aynı olan hazır ayar
+    fn eq_preset_index(&self) -> Option<usize> {
+        let gains = self.player.equalizer.gains();
+        self.eq_presets
+            .iter()
+            .position(|preset| preset.gains == gains)
+    }
+
+    /// Geçerli kazançlarla aynı olan hazır ayarın adı; elle ayarlandıysa `None`
+    pub fn eq_preset_name(&self) -> Option<&str> {
+        self.eq_preset_index()
+            .map(|i| self.eq_presets[i].name.as_str())
+    }
+
+    /// Ekolayzırı aç/kapat
+    pub fn toggle_equalizer(&mut self) {
+        let equalizer = &self.player.equalizer;
+        equalizer.set_enabled(!equalizer.is_enabled());
+    }
+
+    /// Efektler görünümünde seçili satırın efekti, zincirdeki sırası ve (parametre
+    /// satırıysa) parametresi
+    fn selected_effect(&self) -> Option<(usize, Arc<Effect>, Option<usize>)> {
+        let chain = self.player.effects.chain();
+        let row = *effect_rows(&chain).get(self.effects_state.selected()?)?;
+        Some(match row {
+            EffectRow::Effect(i) => (i, Arc::clone(&chain[i]), None),
+            EffectRow::Param(i, p) => (i, Arc::clone(&chain[i]), Some(p)),
+        })
+    }
+
+    /// Seçili efekti aç/kapat
+    pub fn toggle_effect(&mut self) {
+        if let Some((_, effect, _)) = self.selected_effect() {
+            effect.set_enabled(!effect.is_enabled());
+        }
+    }
+
+    /// Seçili parametreyi adımı kadar değiştir; efekt kapalıysa açılır
+    fn adjust_effect(&mut self, steps: i64, long: bool) {
+        let Some((_, effect, Some(param))) = self.selected_effect() else {
+            return;
+        };
+        let step = effect.spec().params[param].step;
+        let steps = steps as f32 * if long { EFFECT_LONG_STEPS } else { 1.0 };
+        // Adımın katlarında kalsın
+        let value = ((effect.param(param) + steps * step) / step).round() * step;
+        effect.set_param(param, value);
+        effect.set_enabled(true);
+    }
+
+    /// Seçili parametreyi (efekt satırında tüm parametreleri) varsayılana döndür
+    fn reset_effect(&mut self) {
+        match self.selected_effect() {
+            Some((_, effect, Some(param))) => {
+                effect.set_param(param, effect.spec().params[param].default);
             }
+            Some((_, effect, None)) => effect.reset_params(),
+            None => {}
+        }
+    }
+
+    /// Seçili efekti zincirde yukarı (-1) veya aşağı (+1) taşı; seçim efektle birlikte gider
+    fn move_effect(&mut self, offset: isize) {
+        let Some((index, _, param)) = self.selected_effect() else {
+            return;
+        };
+        if !self.player.effects.move_effect(index, offset) {
+            return;
+        }
+        let target = index.saturating_add_signed(offset);
+        let selected = match param {
+            Some(p) => EffectRow::Param(target, p),
+            None => EffectRow::Effect(target),
+        };
+        let rows = effect_rows(&self.player.effects.chain());
+        self.effects_state
+            .select(rows.iter().position(|&row| row == selected));
+    }
+
+    /// Çalan parçanın verilen yüzdesine atla
+    pub fn seek_to_percent(&mut self, percent: u8) {
+        let result = self.player.seek_to_percent(percent);
+        self.report_seek(result);
+    }
+
+    /// Sarma hatasını günlüğe yaz
+    fn report_seek(&mut self, result: Result<(), SeekError>) {
+        if let Err(e) = result
+            && let Some(track) = &self.player.current
+        {
+            self.messages.push(
+                Level::Warning,
+                format!("Cannot seek in {}: {}", track.display_name(), e),
+                Some(track.path.clone()),
+            );
+        }
+    }
+
+    /// Ses seviyesini adım kadar artır (pozitif) veya azalt (negatif)
+    pub fn change_volume(&mut self, steps: i32) {
+        self.player.change_volume(steps * self.volume_step as i32);
+    }
+
+    /// Sesi kapat/aç
+    pub fn toggle_mute(&mut self) {
+        self.player.toggle_mute();
+    }
+
+    /// Müzik çalma durumunu kontrol et, parça bittiyse sıradakine geç
+    pub fn update_playback_status(&mut self) {
+        if let Some(finished) = self.player.take_finished() {
+            self.advance(Some(finished), true);
+        }
+        // Parçalar arasında boşluk kalmaması için sıradaki parça önceden açılır
+        if self.player.current.is_some() {
+            let next = self.upcoming_track();
+            self.player.set_following(next.as_ref());
+        }
+    }
+
+    /// Çalan parça kendiliğinden bitince çalınacak parça; karışık modda tahmin edilemez
+    fn upcoming_track(&self) -> Option<Track> {
+        match self.queue.peek_on_finish() {
+            Some(track) => Some(track.clone()),
+            None if self.queue.is_shuffled() => None,
+            None => self.next_library_track(self.player.current.as_ref()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::library::ScanFilter;
+
+    fn app() -> App {
+        let tracks = [
+            "/lib/Jazz/Miles Davis - So What.mp3",
+            "/lib/Jazz/John Coltrane - Blue Train.mp3",
+            "/lib/Rock/Miles Kane - Come Closer.mp3",
+            "/lib/Jazz/Miles Davis - Blue in Green.mp3",
+        ]
+        .map(|path| Track::from_file_name(Path::new(path)))
+        .to_vec();
+        // Gerçek veri klasörü okunmaz ve yazılmaz: indeks bellekte, listeler geçici klasörde
+        let library = Library::with_index(Vec::new(), ScanFilter::default(), None);
+        let store = PlaylistStore::open(
+            std::env::temp_dir().join(format!("meloic-app-{}", std::process::id())),
+        );
+        let mut app = App::with_data(library, tracks, Some(store), PlayHistory::default());
+        app.select_first();
+        app
+    }
+
+    fn search(app: &mut App, query: &str) {
+        app.start_search(SearchMode::Substring);
+        for c in query.chars() {
+            app.input_char(c);
         }
     }
+
+    fn titles(app: &App) -> Vec<&str> {
+        app.filtered_tracks()
+            .map(|track| track.title.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn search_requires_every_term() {
+        let mut app = app();
+        search(&mut app, "miles blue");
+        assert_eq!(titles(&app), ["Blue in Green"]);
+
+        // Terimler alanlar arasında ve büyük/küçük harften bağımsız aranır
+        search(&mut app, "DAVIS jazz");
+        assert_eq!(titles(&app), ["So What", "Blue in Green"]);
+        assert_eq!(app.filtered_items, [0, 3]);
+
+        search(&mut app, "miles nothing");
+        assert!(app.is_empty());
+    }
+
+    #[test]
+    fn next_and_previous_match_wrap_around() {
+        let mut app = app();
+        search(&mut app, "miles");
+        app.input_submit();
+        assert_eq!(app.state.selected(), Some(0));
+
+        app.jump_to_match(false);
+        assert_eq!(app.get_selected().unwrap().title, "Blue in Green");
+        app.jump_to_match(true);
+        assert_eq!(app.get_selected().unwrap().title, "So What");
+        app.jump_to_match(true);
+        app.jump_to_match(true);
+        app.jump_to_match(true);
+        assert_eq!(app.get_selected().unwrap().title, "So What");
+    }
+
+    #[test]
+    fn escape_restores_selection_before_search() {
+        let mut app = app();
+        app.state.select(Some(1));
+        search(&mut app, "miles");
+        assert_eq!(app.get_selected().unwrap().title, "So What");
+
+        app.input_cancel();
+        assert!(!app.is_searching());
+        assert_eq!(app.filtered_items, [0, 1, 2, 3]);
+        assert_eq!(app.get_selected().unwrap().title, "Blue Train");
+
+        // Arama kabul edildikten sonra Esc de aynı seçime döner
+        search(&mut app, "kane");
+        app.input_submit();
+        assert!(!app.back());
+        assert_eq!(app.get_selected().unwrap().title, "Blue Train");
+    }
 }
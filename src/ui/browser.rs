@@ -0,0 +1,339 @@
+use crate::library::Track;
+use crate::library::browse::{self, AlbumEntry, ArtistEntry};
+use crate::library::track::format_duration;
+use crate::ui::app::App;
+use ratatui::{
+    Frame,
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, List, ListItem, ListState, Paragraph},
+};
+use std::time::Duration;
+
+/// Tarayıcıdaki sütunlar
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Pane {
+    Artists,
+    Albums,
+    Tracks,
+}
+
+/// Sanatçı / albüm / parça tarayıcısının durumu
+pub struct Browser {
+    pub artists: Vec<ArtistEntry>,
+    pub pane: Pane,
+    pub artist_state: ListState,
+    pub album_state: ListState,
+    pub track_state: ListState,
+    /// Albüm ve parça sütunlarının gösterdiği sanatçı ve albüm
+    shown: (Option<usize>, Option<usize>),
+    /// Parça listesi değişti, ağaç yeniden kurulmalı
+    stale: bool,
+}
+
+impl Default for Browser {
+    fn default() -> Self {
+        Browser {
+            artists: Vec::new(),
+            pane: Pane::Artists,
+            artist_state: ListState::default(),
+            album_state: ListState::default(),
+            track_state: ListState::default(),
+            shown: (None, None),
+            stale: true,
+        }
+    }
+}
+
+impl Browser {
+    /// Parça listesi değiştiğinde çağrılır; ağaç bir sonraki kullanımda yeniden kurulur
+    pub fn mark_stale(&mut self) {
+        self.stale = true;
+    }
+
+    /// Gerekiyorsa ağacı yeniden kur (seçili sanatçı ve albüm korunur) ve seçimleri eşitle
+    pub fn refresh(&mut self, tracks: &[Track]) {
+        if self.stale {
+            let artist = self.selected_artist().map(|a| a.name.clone());
+            let album = self.selected_album().map(|a| a.title.clone());
+
+            self.artists = browse::group_by_artist(tracks);
+            self.stale = false;
+
+            let artist_index = artist
+                .and_then(|name| self.artists.iter().position(|a| a.name == name))
+                .or_else(|| (!self.artists.is_empty()).then_some(0));
+            self.artist_state.select(artist_index);
+            let album_index = artist_index.map(|i| {
+                album
+                    .and_then(|title| self.artists[i].albums.iter().position(|a| a.title == title))
+                    .unwrap_or(0)
+            });
+            self.album_state.select(album_index);
+            self.shown = (artist_index, album_index);
+            self.track_state.select(album_index.map(|_| 0));
+        }
+        self.sync();
+    }
+
+    /// Sanatçı veya albüm seçimi değiştiyse alttaki sütunları başa al
+    fn sync(&mut self) {
+        if self.artist_state.selected() != self.shown.0 {
+            self.shown.0 = self.artist_state.selected();
+            let has_albums = self.selected_artist().is_some_and(|a| !a.albums.is_empty());
+            self.album_state = ListState::default();
+            self.album_state.select(has_albums.then_some(0));
+        }
+        if self.album_state.selected() != self.shown.1 {
+            self.shown.1 = self.album_state.selected();
+            let has_tracks = self.selected_album().is_some_and(|a| !a.tracks.is_empty());
+            self.track_state = ListState::default();
+            self.track_state.select(has_tracks.then_some(0));
+        }
+    }
+
+    /// Etkin sütunun durumu ve uzunluğu
+    pub fn active_list(&mut self) -> (&mut ListState, usize) {
+        self.sync();
+        match self.pane {
+            Pane::Artists => (&mut self.artist_state, self.artists.len()),
+            Pane::Albums => {
+                let len = self.selected_artist().map_or(0, |a| a.albums.len());
+                (&mut self.album_state, len)
+            }
+            Pane::Tracks => {
+                let len = self.selected_album().map_or(0, |a| a.tracks.len());
+                (&mut self.track_state, len)
+            }
+        }
+    }
+
+    /// Sonraki sütuna geç (sonda başa sarar)
+    pub fn next_pane(&mut self) {
+        self.pane = match self.pane {
+            Pane::Artists => Pane::Albums,
+            Pane::Albums => Pane::Tracks,
+            Pane::Tracks => Pane::Artists,
+        };
+    }
+
+    /// Önceki sütuna geç (başta sona sarar)
+    pub fn previous_pane(&mut self) {
+        self.pane = match self.pane {
+            Pane::Artists => Pane::Tracks,
+            Pane::Albums => Pane::Artists,
+            Pane::Tracks => Pane::Albums,
+        };
+    }
+
+    /// Seçili sanatçı
+    pub fn selected_artist(&self) -> Option<&ArtistEntry> {
+        self.artist_state
+            .selected()
+            .and_then(|i| self.artists.get(i))
+    }
+
+    /// Seçili albüm
+    pub fn selected_album(&self) -> Option<&AlbumEntry> {
+        let artist = self.selected_artist()?;
+        self.album_state
+            .selected()
+            .and_then(|i| artist.albums.get(i))
+    }
+
+    /// Etkin sütundaki seçimin parçaları (parça listesindeki sıralar)
+    pub fn selected_tracks(&self) -> Vec<usize> {
+        match self.pane {
+            Pane::Artists => self
+                .selected_artist()
+                .map(|a| a.tracks().collect())
+                .unwrap_or_default(),
+            Pane::Albums => self
+                .selected_album()
+                .map(|a| a.tracks.clone())
+                .unwrap_or_default(),
+            Pane::Tracks => self
+                .selected_album()
+                .zip(self.track_state.selected())
+                .and_then(|(album, i)| album.tracks.get(i).copied())
+                .into_iter()
+                .collect(),
+        }
+    }
+}
+
+/// Sanatçı / albüm / parça tarayıcısını çiz
+pub fn draw_browser(f: &mut Frame, area: Rect, app: &mut App) {
+    app.browser.refresh(&app.items);
+
+    if app.browser.artists.is_empty() {
+        let empty_widget = Paragraph::new("🎵 No tracks in the library yet")
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title("👤 Artists")
+                    .style(Style::default().fg(app.theme.secondary)),
+            )
+            .style(Style::default().fg(app.theme.accent))
+            .alignment(Alignment::Center);
+        f.render_widget(empty_widget, area);
+        return;
+    }
+
+    let columns = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Percentage(28),
+            Constraint::Percentage(32),
+            Constraint::Percentage(40),
+        ])
+        .split(area);
+
+    let browser = &app.browser;
+    let theme = &app.theme;
+    let playing = app.player.current.as_ref();
+
+    let artists: Vec<ListItem> = browser
+        .artists
+        .iter()
+        .map(|artist| {
+            ListItem::new(Line::from(vec![
+                Span::styled(artist.name.clone(), Style::default().fg(theme.text)),
+                Span::styled(
+                    format!("  {}", artist.track_count()),
+                    Style::default().fg(theme.muted),
+                ),
+            ]))
+        })
+        .collect();
+
+    let albums: Vec<ListItem> = browser
+        .selected_artist()
+        .map(|artist| {
+            artist
+                .albums
+                .iter()
+                .map(|album| {
+                    let year = album.year.map_or("    ".to_string(), |y| y.to_string());
+                    ListItem::new(Line::from(vec![
+                        Span::styled(format!("{}  ", year), Style::default().fg(theme.info)),
+                        Span::styled(album.title.clone(), Style::default().fg(theme.text)),
+                        Span::styled(
+                            format!("  {}", album.tracks.len()),
+                            Style::default().fg(theme.muted),
+                        ),
+                    ]))
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let album = browser.selected_album();
+    let tracks: Vec<ListItem> = album
+        .map(|album| {
+            album
+                .tracks
+                .iter()
+                .filter_map(|&i| app.items.get(i))
+                .map(|track| {
+                    let number = match (track.disc_number, track.track_number) {
+                        (Some(disc), Some(n)) if disc > 1 => format!("{}-{:02}", disc, n),
+                        (_, Some(n)) => format!("{:02}", n),
+                        _ => "--".to_string(),
+                    };
+                    let (marker, title_style) = if playing.is_some_and(|p| p.is_same(track)) {
+                        (
+                            "▶ ",
+                            Style::default()
+                                .fg(theme.success)
+                                .add_modifier(Modifier::BOLD),
+                        )
+                    } else {
+                        ("  ", Style::default().fg(theme.text))
+                    };
+                    let mut spans = vec![
+                        Span::styled(marker, title_style),
+                        Span::styled(format!("{}. ", number), Style::default().fg(theme.muted)),
+                        Span::styled(track.title.clone(), title_style),
+                    ];
+                    if let Some(duration) = track.duration {
+                        spans.push(Span::styled(
+                            format!("  {}", format_duration(duration)),
+                            Style::default().fg(theme.muted),
+                        ));
+                    }
+                    ListItem::new(Line::from(spans))
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let album_title = match album {
+        Some(album) => {
+            let total: Duration = album
+                .tracks
+                .iter()
+                .filter_map(|&i| app.items.get(i).and_then(|t| t.duration))
+                .sum();
+            format!("🎵 {} · {}", album.title, format_duration(total))
+        }
+        None => "🎵 Tracks".to_string(),
+    };
+    let titles = [
+        format!("👤 Artists · {}", browser.artists.len()),
+        "💿 Albums".to_string(),
+        album_title,
+    ];
+
+    let pane = browser.pane;
+    let lists = [
+        (artists, Pane::Artists),
+        (albums, Pane::Albums),
+        (tracks, Pane::Tracks),
+    ];
+    let mut widgets = Vec::new();
+    for ((items, list_pane), title) in lists.into_iter().zip(titles) {
+        let active = list_pane == pane;
+        // Etkin sütun vurgulanır, diğerlerinde seçim yalnızca renkle gösterilir
+        let (border, highlight) = if active {
+            (
+                theme.primary,
+                Style::default()
+                    .bg(theme.secondary)
+                    .fg(theme.text)
+                    .add_modifier(Modifier::BOLD),
+            )
+        } else {
+            (
+                theme.muted,
+                Style::default()
+                    .fg(theme.accent)
+                    .add_modifier(Modifier::BOLD),
+            )
+        };
+        widgets.push(
+            List::new(items)
+                .block(
+                    Block::default()
+                        .borders(Borders::ALL)
+                        .title(title)
+                        .border_style(Style::default().fg(border)),
+                )
+                .highlight_style(highlight)
+                .highlight_symbol(if active { "▶ " } else { "  " }),
+        );
+    }
+
+    let mut widgets = widgets.into_iter();
+    let states = [
+        &mut app.browser.artist_state,
+        &mut app.browser.album_state,
+        &mut app.browser.track_state,
+    ];
+    for (column, state) in columns.iter().zip(states) {
+        if let Some(widget) = widgets.next() {
+            f.render_stateful_widget(widget, *column, state);
+        }
+    }
+}
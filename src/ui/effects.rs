@@ -0,0 +1,121 @@
+use crate::player::dsp::Effect;
+use crate::ui::app::App;
+use ratatui::{
+    Frame,
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, List, ListItem, Paragraph},
+};
+use std::sync::Arc;
+
+/// Parametre çubuğunun genişliği (karakter)
+const BAR_WIDTH: usize = 20;
+
+/// Efektler görünümündeki satır: efektin kendisi veya bir parametresi
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum EffectRow {
+    Effect(usize),
+    Param(usize, usize),
+}
+
+/// Zincirin satırları: her efekt ve altında parametreleri
+pub fn effect_rows(chain: &[Arc<Effect>]) -> Vec<EffectRow> {
+    chain
+        .iter()
+        .enumerate()
+        .flat_map(|(i, effect)| {
+            std::iter::once(EffectRow::Effect(i))
+                .chain((0..effect.spec().params.len()).map(move |p| EffectRow::Param(i, p)))
+        })
+        .collect()
+}
+
+/// Efekt zincirini sırasıyla, parametreleri çubuklarla çiz
+pub fn draw_effects(f: &mut Frame, area: Rect, app: &mut App) {
+    let theme = &app.theme;
+    let chain = app.player.effects.chain();
+    let enabled = chain.iter().filter(|effect| effect.is_enabled()).count();
+
+    let items: Vec<ListItem> = effect_rows(&chain)
+        .into_iter()
+        .map(|row| match row {
+            EffectRow::Effect(i) => {
+                let effect = &chain[i];
+                let (marker, style) = if effect.is_enabled() {
+                    (
+                        "[on] ",
+                        Style::default()
+                            .fg(theme.success)
+                            .add_modifier(Modifier::BOLD),
+                    )
+                } else {
+                    ("[off]", Style::default().fg(theme.muted))
+                };
+                ListItem::new(Line::from(vec![
+                    Span::styled(format!("{}. ", i + 1), Style::default().fg(theme.muted)),
+                    Span::styled(marker, style),
+                    Span::styled(
+                        format!(" {}", effect.spec().label),
+                        Style::default().fg(theme.text).add_modifier(Modifier::BOLD),
+                    ),
+                ]))
+            }
+            EffectRow::Param(i, p) => {
+                let effect = &chain[i];
+                let spec = &effect.spec().params[p];
+                let value = effect.param(p);
+                let filled = ((value - spec.min) / (spec.max - spec.min) * BAR_WIDTH as f32).round()
+                    as usize;
+                let color = if effect.is_enabled() {
+                    theme.secondary
+                } else {
+                    theme.muted
+                };
+                ListItem::new(Line::from(vec![
+                    Span::styled(
+                        format!("       {:<14}", spec.label),
+                        Style::default().fg(theme.text_dim),
+                    ),
+                    Span::styled("━".repeat(filled), Style::default().fg(color)),
+                    Span::styled(
+                        "─".repeat(BAR_WIDTH - filled.min(BAR_WIDTH)),
+                        Style::default().fg(theme.muted),
+                    ),
+                    Span::styled(
+                        format!("  {}", spec.format(value)),
+                        Style::default().fg(theme.text),
+                    ),
+                ]))
+            }
+        })
+        .collect();
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(format!("🎛️ Effects · {} of {} on", enabled, chain.len()))
+        .border_style(Style::default().fg(theme.primary));
+    let inner = block.inner(area);
+    f.render_widget(block, area);
+
+    let rows = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(3), Constraint::Length(1)])
+        .split(inner);
+
+    let list = List::new(items)
+        .highlight_style(
+            Style::default()
+                .bg(theme.secondary)
+                .fg(theme.text)
+                .add_modifier(Modifier::BOLD),
+        )
+        .highlight_symbol("▶ ");
+    let hint = Paragraph::new(
+        "Enter on/off · ←/→ adjust (Shift ×5) · Delete reset · K/J move effect in the chain",
+    )
+    .style(Style::default().fg(theme.muted))
+    .alignment(Alignment::Center);
+    f.render_widget(hint, rows[1]);
+    f.render_stateful_widget(list, rows[0], &mut app.effects_state);
+}
@@ -0,0 +1,96 @@
+use crate::player::equalizer::{BAND_COUNT, MAX_GAIN_DB, band_label};
+use crate::ui::app::App;
+use ratatui::{
+    Frame,
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+};
+
+/// Ekolayzır bantlarını dikey sürgüler olarak çiz
+pub fn draw_equalizer(f: &mut Frame, area: Rect, app: &App) {
+    let theme = &app.theme;
+    let equalizer = &app.player.equalizer;
+    let enabled = equalizer.is_enabled();
+
+    let title = format!(
+        "🎚️ Equalizer · {} · {}",
+        app.eq_preset_name().unwrap_or("custom"),
+        if enabled { "on" } else { "off" }
+    );
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(title)
+        .border_style(Style::default().fg(theme.primary));
+    let inner = block.inner(area);
+    f.render_widget(block, area);
+
+    let rows = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(3), Constraint::Length(1)])
+        .split(inner);
+    let columns = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Ratio(1, BAND_COUNT as u32); BAND_COUNT])
+        .split(rows[0]);
+
+    // Her satır bir kazanç aralığını gösterir: en üst +MAX, en alt -MAX
+    let height = columns[0].height.saturating_sub(2).max(1) as usize;
+    let step = 2.0 * MAX_GAIN_DB / height as f32;
+    for (band, &column) in columns.iter().enumerate() {
+        let gain = equalizer.gain(band);
+        let selected = band == app.eq_band;
+        let color = if !enabled {
+            theme.muted
+        } else if selected {
+            theme.accent
+        } else {
+            theme.secondary
+        };
+
+        let mut lines: Vec<Line> = (0..height)
+            .map(|row| {
+                let level = MAX_GAIN_DB - (row as f32 + 0.5) * step;
+                let (symbol, color) = if (level - gain).abs() <= step / 2.0 {
+                    ("▬▬▬", color)
+                } else if (gain > 0.0 && level > 0.0 && level < gain)
+                    || (gain < 0.0 && level < 0.0 && level > gain)
+                {
+                    ("███", color)
+                } else if level.abs() <= step / 2.0 {
+                    ("───", theme.muted)
+                } else {
+                    (" │ ", theme.muted)
+                };
+                Line::from(Span::styled(symbol, Style::default().fg(color)))
+            })
+            .collect();
+
+        let label_style = if selected {
+            Style::default()
+                .fg(theme.accent)
+                .add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(theme.text_dim)
+        };
+        lines.push(Line::from(Span::styled(
+            format!("{:+.0} dB", gain),
+            label_style,
+        )));
+        lines.push(Line::from(Span::styled(
+            format!("{} Hz", band_label(band)),
+            label_style,
+        )));
+
+        let slider = Paragraph::new(lines).alignment(Alignment::Center);
+        f.render_widget(slider, column);
+    }
+
+    let hint = Paragraph::new(
+        "←/→ band · ↑/↓ ±1 dB · PgUp/PgDn ±3 dB · Delete reset band · n/N preset · Enter on/off",
+    )
+    .style(Style::default().fg(theme.muted))
+    .alignment(Alignment::Center);
+    f.render_widget(hint, rows[1]);
+}
@@ -1,10 +1,15 @@
-use crossterm::event::{self, Event, KeyCode, KeyModifiers};
+use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyModifiers};
+use std::collections::{BTreeMap, HashMap};
 use std::{io, time::Duration};
 
+use crate::config::KeySpec;
+
 /// Kullanıcı aksiyonlarını temsil eden enum
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum AppAction {
     Quit,
+    /// Aramayı temizle, yardımı kapat veya çık
+    Back,
     NextItem,
     PreviousItem,
     SelectItem,
@@ -14,52 +19,408 @@ pub enum AppAction {
     GoToBottom,
     ToggleHelp,
     Refresh,
+    /// Eksik ReplayGain değerlerini ölç
+    AnalyzeLoudness,
     Random,
     TogglePause,
     StopPlayback,
+    NextTrack,
+    PreviousTrack,
+    ToggleShuffle,
+    CycleRepeat,
+    QueueAppend,
+    QueuePlayNext,
+    RemoveItem,
+    MoveItemUp,
+    MoveItemDown,
+    ClearQueue,
+    ShowLibrary,
+    ShowQueue,
+    ShowMessages,
+    ShowBrowser,
+    NextPane,
+    PreviousPane,
+    PlayAlbum,
+    QueueArtist,
+    ShowFolders,
+    PlayFolder,
+    QueueFolder,
+    CollapseFolder,
+    ShowPlaylists,
+    ShowEqualizer,
+    ShowEffects,
+    SavePlaylist,
+    NewPlaylist,
+    /// Kurallarla doldurulan liste oluştur
+    NewSmartPlaylist,
+    RenamePlaylist,
+    AddToPlaylist,
+    /// Kütüphanede parçayı çoklu seçime ekle/çıkar
+    ToggleMark,
+    SeekForward,
+    SeekBackward,
+    SeekForwardLong,
+    SeekBackwardLong,
+    /// Parçanın verilen yüzdesine atla
+    SeekTo(u8),
+    VolumeUp,
+    VolumeDown,
+    ToggleMute,
+    StartSearch,
+    StartFinder,
+    NextMatch,
+    PreviousMatch,
+    // Metin girişi sırasında üretilen aksiyonlar (tuş eşlemesi yok)
+    InputChar(char),
+    InputBackspace,
+    InputSubmit,
+    InputCancel,
 }
 
-/// Event'ları dinle ve uygun aksiyonu döndür
-pub fn handle_events() -> io::Result<Option<AppAction>> {
-    if event::poll(Duration::from_millis(100))? {
-        if let Event::Key(key) = event::read()? {
-            return Ok(match (key.code, key.modifiers) {
-                // Çıkış
-                (KeyCode::Char('q'), _) | (KeyCode::Esc, _) => Some(AppAction::Quit),
-
-                // Navigasyon - Vim tarzı
-                (KeyCode::Down, _) | (KeyCode::Char('j'), _) => Some(AppAction::NextItem),
-                (KeyCode::Up, _) | (KeyCode::Char('k'), _) => Some(AppAction::PreviousItem),
-
-                // Sayfa navigasyonu
-                (KeyCode::PageDown, _) | (KeyCode::Char('d'), KeyModifiers::CONTROL) => {
-                    Some(AppAction::NextPage)
-                }
-                (KeyCode::PageUp, _) | (KeyCode::Char('u'), KeyModifiers::CONTROL) => {
-                    Some(AppAction::PreviousPage)
-                }
+impl AppAction {
+    /// Config dosyasında kullanılan aksiyon isimleri
+    pub const NAMES: &'static [(&'static str, AppAction)] = &[
+        ("quit", AppAction::Quit),
+        ("back", AppAction::Back),
+        ("next_item", AppAction::NextItem),
+        ("previous_item", AppAction::PreviousItem),
+        ("select_item", AppAction::SelectItem),
+        ("next_page", AppAction::NextPage),
+        ("previous_page", AppAction::PreviousPage),
+        ("go_to_top", AppAction::GoToTop),
+        ("go_to_bottom", AppAction::GoToBottom),
+        ("toggle_help", AppAction::ToggleHelp),
+        ("refresh", AppAction::Refresh),
+        ("analyze_loudness", AppAction::AnalyzeLoudness),
+        ("random", AppAction::Random),
+        ("toggle_pause", AppAction::TogglePause),
+        ("stop_playback", AppAction::StopPlayback),
+        ("next_track", AppAction::NextTrack),
+        ("previous_track", AppAction::PreviousTrack),
+        ("toggle_shuffle", AppAction::ToggleShuffle),
+        ("cycle_repeat", AppAction::CycleRepeat),
+        ("queue_append", AppAction::QueueAppend),
+        ("queue_play_next", AppAction::QueuePlayNext),
+        ("remove_item", AppAction::RemoveItem),
+        ("move_item_up", AppAction::MoveItemUp),
+        ("move_item_down", AppAction::MoveItemDown),
+        ("clear_queue", AppAction::ClearQueue),
+        ("show_library", AppAction::ShowLibrary),
+        ("show_queue", AppAction::ShowQueue),
+        ("show_messages", AppAction::ShowMessages),
+        ("show_browser", AppAction::ShowBrowser),
+        ("next_pane", AppAction::NextPane),
+        ("previous_pane", AppAction::PreviousPane),
+        ("play_album", AppAction::PlayAlbum),
+        ("queue_artist", AppAction::QueueArtist),
+        ("show_folders", AppAction::ShowFolders),
+        ("play_folder", AppAction::PlayFolder),
+        ("queue_folder", AppAction::QueueFolder),
+        ("collapse_folder", AppAction::CollapseFolder),
+        ("show_playlists", AppAction::ShowPlaylists),
+        ("show_equalizer", AppAction::ShowEqualizer),
+        ("show_effects", AppAction::ShowEffects),
+        ("save_playlist", AppAction::SavePlaylist),
+        ("new_playlist", AppAction::NewPlaylist),
+        ("new_smart_playlist", AppAction::NewSmartPlaylist),
+        ("rename_playlist", AppAction::RenamePlaylist),
+        ("add_to_playlist", AppAction::AddToPlaylist),
+        ("toggle_mark", AppAction::ToggleMark),
+        ("seek_forward", AppAction::SeekForward),
+        ("seek_backward", AppAction::SeekBackward),
+        ("seek_forward_long", AppAction::SeekForwardLong),
+        ("seek_backward_long", AppAction::SeekBackwardLong),
+        ("seek_to_0", AppAction::SeekTo(0)),
+        ("seek_to_10", AppAction::SeekTo(10)),
+        ("seek_to_20", AppAction::SeekTo(20)),
+        ("seek_to_30", AppAction::SeekTo(30)),
+        ("seek_to_40", AppAction::SeekTo(40)),
+        ("seek_to_50", AppAction::SeekTo(50)),
+        ("seek_to_60", AppAction::SeekTo(60)),
+        ("seek_to_70", AppAction::SeekTo(70)),
+        ("seek_to_80", AppAction::SeekTo(80)),
+        ("seek_to_90", AppAction::SeekTo(90)),
+        ("volume_up", AppAction::VolumeUp),
+        ("volume_down", AppAction::VolumeDown),
+        ("toggle_mute", AppAction::ToggleMute),
+        ("start_search", AppAction::StartSearch),
+        ("start_finder", AppAction::StartFinder),
+        ("next_match", AppAction::NextMatch),
+        ("previous_match", AppAction::PreviousMatch),
+    ];
+
+    /// İsimden aksiyon bul
+    pub fn from_name(name: &str) -> Option<AppAction> {
+        Self::NAMES
+            .iter()
+            .find(|(n, _)| *n == name)
+            .map(|(_, action)| *action)
+    }
+}
+
+/// Tuş kombinasyonlarını aksiyonlara eşleyen tablo
+#[derive(Debug, Clone)]
+pub struct KeyMap {
+    bindings: HashMap<(KeyCode, KeyModifiers), AppAction>,
+}
 
-                // Başa/sona git
-                (KeyCode::Home, _) | (KeyCode::Char('g'), _) => Some(AppAction::GoToTop),
-                (KeyCode::End, _) | (KeyCode::Char('G'), _) => Some(AppAction::GoToBottom),
+impl Default for KeyMap {
+    fn default() -> Self {
+        use AppAction::*;
+        let none = KeyModifiers::NONE;
+        let ctrl = KeyModifiers::CONTROL;
+        let shift = KeyModifiers::SHIFT;
+        let alt = KeyModifiers::ALT;
 
-                // Seçim
-                (KeyCode::Enter, _) | (KeyCode::Char(' '), _) => Some(AppAction::SelectItem),
+        let bindings = [
+            // Çıkış
+            (KeyCode::Char('q'), none, Quit),
+            (KeyCode::Esc, none, Back),
+            // Navigasyon - Vim tarzı
+            (KeyCode::Down, none, NextItem),
+            (KeyCode::Char('j'), none, NextItem),
+            (KeyCode::Up, none, PreviousItem),
+            (KeyCode::Char('k'), none, PreviousItem),
+            // Sayfa navigasyonu
+            (KeyCode::PageDown, none, NextPage),
+            (KeyCode::Char('d'), ctrl, NextPage),
+            (KeyCode::PageUp, none, PreviousPage),
+            (KeyCode::Char('u'), ctrl, PreviousPage),
+            // Başa/sona git
+            (KeyCode::Home, none, GoToTop),
+            (KeyCode::Char('g'), none, GoToTop),
+            (KeyCode::End, none, GoToBottom),
+            (KeyCode::Char('G'), none, GoToBottom),
+            // Seçim
+            (KeyCode::Enter, none, SelectItem),
+            (KeyCode::Char(' '), none, SelectItem),
+            // Özel fonksiyonlar
+            (KeyCode::Char('?'), none, ToggleHelp),
+            (KeyCode::Char('h'), none, ToggleHelp),
+            (KeyCode::F(1), none, ToggleHelp),
+            (KeyCode::Char('r'), none, Refresh),
+            (KeyCode::F(5), none, Refresh),
+            (KeyCode::Char('l'), none, AnalyzeLoudness),
+            (KeyCode::Char('s'), none, Random),
+            // Müzik kontrolleri
+            (KeyCode::Char('p'), none, TogglePause),
+            (KeyCode::Char('x'), none, StopPlayback),
+            (KeyCode::Char('>'), none, NextTrack),
+            (KeyCode::Char('<'), none, PreviousTrack),
+            (KeyCode::Char('S'), none, ToggleShuffle),
+            (KeyCode::Char('R'), none, CycleRepeat),
+            // Sarma
+            (KeyCode::Right, none, SeekForward),
+            (KeyCode::Left, none, SeekBackward),
+            (KeyCode::Right, shift, SeekForwardLong),
+            (KeyCode::Char(']'), none, SeekForwardLong),
+            (KeyCode::Left, shift, SeekBackwardLong),
+            (KeyCode::Char('['), none, SeekBackwardLong),
+            // Ses
+            (KeyCode::Char('+'), none, VolumeUp),
+            (KeyCode::Char('='), none, VolumeUp),
+            (KeyCode::Char('-'), none, VolumeDown),
+            (KeyCode::Char('m'), none, ToggleMute),
+            // Kuyruk
+            (KeyCode::Char('e'), none, QueueAppend),
+            (KeyCode::Char('E'), none, QueuePlayNext),
+            (KeyCode::Delete, none, RemoveItem),
+            (KeyCode::Char('K'), none, MoveItemUp),
+            (KeyCode::Char('J'), none, MoveItemDown),
+            (KeyCode::Char('C'), none, ClearQueue),
+            // Arama
+            (KeyCode::Char('/'), none, StartSearch),
+            (KeyCode::Char('f'), none, StartFinder),
+            (KeyCode::Char('p'), ctrl, StartFinder),
+            (KeyCode::Char('n'), none, NextMatch),
+            (KeyCode::Char('N'), none, PreviousMatch),
+            // Görünümler
+            (KeyCode::Char('1'), none, ShowLibrary),
+            (KeyCode::Char('2'), none, ShowQueue),
+            (KeyCode::Char('3'), none, ShowMessages),
+            (KeyCode::Char('4'), none, ShowBrowser),
+            // Tarayıcı
+            (KeyCode::Tab, none, NextPane),
+            (KeyCode::BackTab, none, PreviousPane),
+            (KeyCode::Char('a'), none, PlayAlbum),
+            (KeyCode::Char('A'), none, QueueArtist),
+            // Klasör ağacı
+            (KeyCode::Char('5'), none, ShowFolders),
+            (KeyCode::Char('P'), none, PlayFolder),
+            (KeyCode::Char('Q'), none, QueueFolder),
+            (KeyCode::Backspace, none, CollapseFolder),
+            // Çalma listeleri
+            (KeyCode::Char('6'), none, ShowPlaylists),
+            (KeyCode::Char('w'), none, SavePlaylist),
+            (KeyCode::Char('c'), none, NewPlaylist),
+            (KeyCode::Char('b'), none, NewSmartPlaylist),
+            (KeyCode::F(2), none, RenamePlaylist),
+            (KeyCode::Char('L'), none, AddToPlaylist),
+            (KeyCode::Char('v'), none, ToggleMark),
+            // Ekolayzır
+            (KeyCode::Char('7'), none, ShowEqualizer),
+            // Efektler
+            (KeyCode::Char('8'), none, ShowEffects),
+        ];
 
-                // Özel fonksiyonlar
-                (KeyCode::Char('?'), _) | (KeyCode::Char('h'), _) | (KeyCode::F(1), _) => {
-                    Some(AppAction::ToggleHelp)
+        let mut bindings: HashMap<_, _> = bindings
+            .into_iter()
+            .map(|(code, modifiers, action)| ((code, modifiers), action))
+            .collect();
+        // Alt+0..9: parçanın %0..%90'ına atla
+        for digit in 0..=9u8 {
+            bindings.insert(
+                (KeyCode::Char((b'0' + digit) as char), alt),
+                SeekTo(digit * 10),
+            );
+        }
+
+        KeyMap { bindings }
+    }
+}
+
+impl KeyMap {
+    /// Varsayılan eşlemeyi config'deki [keys] tablosuyla güncelle;
+    /// config'de verilen aksiyonun varsayılan tuşları tamamen değiştirilir
+    pub fn from_config(keys: &BTreeMap<String, KeySpec>) -> Result<KeyMap, String> {
+        let mut keymap = KeyMap::default();
+        let mut configured: HashMap<(KeyCode, KeyModifiers), &str> = HashMap::new();
+
+        for (name, spec) in keys {
+            let action = AppAction::from_name(name).ok_or_else(|| {
+                format!(
+                    "unknown action '{}' in [keys] (expected one of: {})",
+                    name,
+                    AppAction::NAMES
+                        .iter()
+                        .map(|(n, _)| *n)
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                )
+            })?;
+            keymap.bindings.retain(|_, bound| *bound != action);
+
+            for key in spec.keys() {
+                let combo = parse_key(key).map_err(|e| format!("keys.{}: {}", name, e))?;
+                if let Some(other) = configured.insert(combo, name) {
+                    return Err(format!(
+                        "key '{}' is bound to both '{}' and '{}'",
+                        key, other, name
+                    ));
                 }
-                (KeyCode::Char('r'), _) | (KeyCode::F(5), _) => Some(AppAction::Refresh),
-                (KeyCode::Char('s'), _) => Some(AppAction::Random),
+                keymap.bindings.insert(combo, action);
+            }
+        }
 
-                // Müzik kontrolleri
-                (KeyCode::Char('p'), _) => Some(AppAction::TogglePause),
-                (KeyCode::Char('x'), _) => Some(AppAction::StopPlayback),
+        Ok(keymap)
+    }
 
-                _ => None,
-            });
+    /// Tuşa karşılık gelen aksiyonu bul
+    pub fn action_for(&self, key: KeyEvent) -> Option<AppAction> {
+        self.bindings
+            .get(&normalize(key.code, key.modifiers))
+            .copied()
+    }
+}
+
+/// Büyük harfler Shift ile gelir; eşlemede Shift'i karakterin kendisi temsil eder
+fn normalize(code: KeyCode, modifiers: KeyModifiers) -> (KeyCode, KeyModifiers) {
+    match code {
+        KeyCode::Char(_) => (code, modifiers - KeyModifiers::SHIFT),
+        KeyCode::BackTab => (code, modifiers - KeyModifiers::SHIFT),
+        _ => (code, modifiers),
+    }
+}
+
+/// "q", "Ctrl+d", "PageDown", "F5" gibi tuş tanımlarını ayrıştır
+pub fn parse_key(spec: &str) -> Result<(KeyCode, KeyModifiers), String> {
+    let mut modifiers = KeyModifiers::NONE;
+    let mut parts: Vec<&str> = spec.split('+').collect();
+    // "+" tuşunun kendisi ("Ctrl++" veya "+")
+    let key = if spec.ends_with("++") || spec == "+" {
+        parts.truncate(parts.len().saturating_sub(2));
+        "+"
+    } else {
+        parts.pop().unwrap_or_default()
+    };
+
+    for modifier in parts {
+        match modifier.to_lowercase().as_str() {
+            "ctrl" | "control" => modifiers |= KeyModifiers::CONTROL,
+            "alt" => modifiers |= KeyModifiers::ALT,
+            "shift" => modifiers |= KeyModifiers::SHIFT,
+            other => return Err(format!("unknown modifier '{}' in key '{}'", other, spec)),
+        }
+    }
+
+    let mut chars = key.chars();
+    let code = match (chars.next(), chars.next()) {
+        (Some(c), None) => KeyCode::Char(c),
+        _ => match key.to_lowercase().as_str() {
+            "space" => KeyCode::Char(' '),
+            "enter" | "return" => KeyCode::Enter,
+            "esc" | "escape" => KeyCode::Esc,
+            "tab" => KeyCode::Tab,
+            "backtab" => KeyCode::BackTab,
+            "backspace" => KeyCode::Backspace,
+            "delete" | "del" => KeyCode::Delete,
+            "insert" | "ins" => KeyCode::Insert,
+            "up" => KeyCode::Up,
+            "down" => KeyCode::Down,
+            "left" => KeyCode::Left,
+            "right" => KeyCode::Right,
+            "home" => KeyCode::Home,
+            "end" => KeyCode::End,
+            "pageup" => KeyCode::PageUp,
+            "pagedown" => KeyCode::PageDown,
+            name => match name.strip_prefix('f').and_then(|n| n.parse::<u8>().ok()) {
+                Some(n) if (1..=12).contains(&n) => KeyCode::F(n),
+                _ => return Err(format!("unknown key '{}'", spec)),
+            },
+        },
+    };
+
+    // "Shift+g" ile "G" aynı tuştur
+    let code = match code {
+        KeyCode::Char(c) if modifiers.contains(KeyModifiers::SHIFT) => {
+            KeyCode::Char(c.to_ascii_uppercase())
+        }
+        _ => code,
+    };
+    Ok(normalize(code, modifiers))
+}
+
+/// Event'ları dinle ve uygun aksiyonu döndür; metin girişinde yazılan karakterler
+/// tuş eşlemesine uğramadan giriş aksiyonu olarak döner
+pub fn handle_events(
+    keymap: &KeyMap,
+    poll_interval: Duration,
+    text_input: bool,
+) -> io::Result<Option<AppAction>> {
+    if event::poll(poll_interval)?
+        && let Event::Key(key) = event::read()?
+    {
+        if text_input && let Some(action) = input_action(key) {
+            return Ok(Some(action));
         }
+        return Ok(keymap.action_for(key));
     }
     Ok(None)
 }
+
+/// Metin girişinde tuşun karşılığı; ok tuşları gibi diğer tuşlar eşlemeye bırakılır
+fn input_action(key: KeyEvent) -> Option<AppAction> {
+    match key.code {
+        KeyCode::Char(c)
+            if !key
+                .modifiers
+                .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT) =>
+        {
+            Some(AppAction::InputChar(c))
+        }
+        KeyCode::Backspace => Some(AppAction::InputBackspace),
+        KeyCode::Enter => Some(AppAction::InputSubmit),
+        KeyCode::Esc => Some(AppAction::InputCancel),
+        _ => None,
+    }
+}
@@ -2,13 +2,13 @@ use crate::ui::app::App;
 use ratatui::{
     Frame,
     layout::{Alignment, Constraint, Direction, Layout, Rect},
-    style::{Color, Modifier, Style},
+    style::{Modifier, Style},
     text::{Line, Span},
     widgets::{Block, Borders, Clear, Paragraph},
 };
 
 /// Yardım ekranını çiz
-pub fn draw_help_screen(f: &mut Frame, _app: &App) {
+pub fn draw_help_screen(f: &mut Frame, app: &App) {
     let area = centered_rect(70, 80, f.size());
 
     f.render_widget(Clear, area);
@@ -17,7 +17,7 @@ pub fn draw_help_screen(f: &mut Frame, _app: &App) {
         Line::from(vec![Span::styled(
             "🎵 MELOIC - Music Player Help",
             Style::default()
-                .fg(Color::Rgb(0, 255, 255)) // Neon cyan
+                .fg(app.theme.primary) // Neon cyan
                 .add_modifier(Modifier::BOLD),
         )]),
         Line::from(""),
@@ -25,11 +25,11 @@ pub fn draw_help_screen(f: &mut Frame, _app: &App) {
         Line::from(vec![Span::styled(
             "📍 NAVIGATION",
             Style::default()
-                .fg(Color::Rgb(255, 255, 0)) // Neon yellow
+                .fg(app.theme.accent) // Neon yellow
                 .add_modifier(Modifier::BOLD),
         )]),
         Line::from("  ↑/↓ or j/k     - Move up/down in track list"),
-        Line::from("  Page Up/Down   - Jump 10 tracks"),
+        Line::from(format!("  Page Up/Down   - Jump {} tracks", app.page_size)),
         Line::from("  Home/End       - Go to first/last track"),
         Line::from("  g/G            - Go to top/bottom (vim style)"),
         Line::from(""),
@@ -37,37 +37,76 @@ pub fn draw_help_screen(f: &mut Frame, _app: &App) {
         Line::from(vec![Span::styled(
             "🎮 PLAYBACK CONTROLS",
             Style::default()
-                .fg(Color::Rgb(0, 255, 0)) // Neon green
+                .fg(app.theme.success) // Neon green
                 .add_modifier(Modifier::BOLD),
         )]),
         Line::from("  Enter/Space    - Play selected track"),
         Line::from("  p              - Pause/Resume current track"),
         Line::from("  x              - Stop playback completely"),
-        Line::from("  s              - Shuffle (random track selection)"),
+        Line::from("  s              - Play a random track"),
+        Line::from("  S              - Toggle shuffle mode"),
+        Line::from("  R              - Cycle repeat mode (off/all/one)"),
+        Line::from("  > / <          - Next/previous track"),
+        Line::from("  → / ←          - Seek forward/back (short step)"),
+        Line::from("  ] / [          - Seek forward/back (long step, also Shift+→/←)"),
+        Line::from("  Alt+0..9       - Jump to 0%..90% of the track"),
+        Line::from("  + / -          - Volume up/down"),
+        Line::from("  m              - Mute/unmute"),
+        Line::from(""),
+        // Queue bölümü
+        Line::from(vec![Span::styled(
+            "🎶 QUEUE",
+            Style::default()
+                .fg(app.theme.info)
+                .add_modifier(Modifier::BOLD),
+        )]),
+        Line::from("  e / E          - Add to end of queue / play next"),
+        Line::from("  J / K          - Move queue or playlist entry down/up"),
+        Line::from("  Delete         - Remove queue/playlist entry or delete a playlist"),
+        Line::from("  C              - Clear queue"),
         Line::from(""),
         // Features bölümü
         Line::from(vec![Span::styled(
             "✨ FEATURES",
             Style::default()
-                .fg(Color::Rgb(255, 0, 255)) // Neon magenta
+                .fg(app.theme.secondary) // Neon magenta
                 .add_modifier(Modifier::BOLD),
         )]),
+        Line::from("  1 / 2 / 3 / 4  - Library / queue / messages / browser"),
+        Line::from("  5              - Folder tree (Enter opens/closes a folder)"),
+        Line::from("  P / Q          - Play folder / queue folder (with subfolders)"),
+        Line::from("  Backspace      - Close folder or go to parent folder"),
+        Line::from("  6              - Playlists (Tab: lists / entries)"),
+        Line::from("  7              - Equalizer (←/→ band, ↑/↓ gain, n/N preset, Enter on/off)"),
+        Line::from("  8              - Effects (Enter on/off, ←/→ adjust, K/J reorder chain)"),
+        Line::from("  w              - Save queue or selection as a playlist"),
+        Line::from("  c / F2         - New playlist / rename selected playlist"),
+        Line::from("  b              - Build a smart playlist from rules (genre, year, rating...)"),
+        Line::from("  v / L          - Mark track / add marked or selected tracks to a playlist"),
+        Line::from("  Tab/Shift+Tab  - Next/previous browser column"),
+        Line::from("  a / A          - Play album / queue artist"),
+        Line::from("  /              - Search title, artist, album and path"),
+        Line::from("  f or Ctrl+p    - Fuzzy finder (ranked, fzf-style)"),
+        Line::from("  n / N          - Next/previous match"),
         Line::from("  r or F5        - Refresh music library"),
+        Line::from("  l              - Measure loudness of tracks without ReplayGain values"),
         Line::from("  ?/h or F1      - Show/hide this help screen"),
         Line::from(""),
         // System bölümü
         Line::from(vec![Span::styled(
             "⚙️  SYSTEM",
-            Style::default().fg(Color::Rgb(255, 100, 0)) // Neon orange
-            .add_modifier(Modifier::BOLD),
+            Style::default()
+                .fg(app.theme.warning) // Neon orange
+                .add_modifier(Modifier::BOLD),
         )]),
-        Line::from("  q or Esc       - Quit application"),
+        Line::from("  Esc            - Clear search, close help, clear marks or quit"),
+        Line::from("  q              - Quit application"),
         Line::from(""),
         // Supported formats
         Line::from(vec![Span::styled(
             "🎧 SUPPORTED FORMATS",
             Style::default()
-                .fg(Color::Rgb(100, 150, 255)) // Neon blue
+                .fg(app.theme.info) // Neon blue
                 .add_modifier(Modifier::BOLD),
         )]),
         Line::from("  MP3, FLAC, WAV, OGG, M4A, AAC"),
@@ -75,13 +114,13 @@ pub fn draw_help_screen(f: &mut Frame, _app: &App) {
         // Footer
         Line::from(vec![Span::styled(
             "💡 TIP: Meloic scans 'music' folder or current directory for audio files",
-            Style::default().fg(Color::Rgb(150, 150, 150)), // Parlak gri
+            Style::default().fg(app.theme.muted), // Parlak gri
         )]),
         Line::from(""),
         Line::from(vec![Span::styled(
             "Press h, ? or f1 key to close this help screen",
             Style::default()
-                .fg(Color::Rgb(255, 255, 255)) // Parlak beyaz
+                .fg(app.theme.text) // Parlak beyaz
                 .add_modifier(Modifier::BOLD)
                 .add_modifier(Modifier::UNDERLINED),
         )]),
@@ -94,12 +133,12 @@ pub fn draw_help_screen(f: &mut Frame, _app: &App) {
                 .title(" 🆘 Help & Controls ")
                 .title_style(
                     Style::default()
-                        .fg(Color::Rgb(0, 255, 255)) // Neon cyan
+                        .fg(app.theme.primary) // Neon cyan
                         .add_modifier(Modifier::BOLD),
                 )
-                .border_style(Style::default().fg(Color::Rgb(255, 0, 255))), // Neon magenta border
+                .border_style(Style::default().fg(app.theme.secondary)), // Neon magenta border
         )
-        .style(Style::default().fg(Color::Rgb(255, 255, 255))) // Parlak beyaz text
+        .style(Style::default().fg(app.theme.text)) // Parlak beyaz text
         .alignment(Alignment::Left);
 
     f.render_widget(help_widget, area);
@@ -0,0 +1,128 @@
+use crate::ui::app::App;
+use crate::ui::notify::{Level, Message};
+use crate::ui::theme::Theme;
+use ratatui::{
+    Frame,
+    layout::{Alignment, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, Paragraph, Wrap},
+};
+
+/// Seviyeye göre ikon ve renk
+fn level_style(level: Level, theme: &Theme) -> (&'static str, Color) {
+    match level {
+        Level::Info => ("ℹ️", theme.info),
+        Level::Success => ("✅", theme.success),
+        Level::Warning => ("⚠️", theme.warning),
+        Level::Error => ("❌", theme.error),
+    }
+}
+
+/// Mesajın ne kadar önce eklendiği ("5s ago", "3m ago")
+fn age(message: &Message) -> String {
+    let secs = message.at.elapsed().as_secs();
+    match secs {
+        0..60 => format!("{}s ago", secs),
+        60..3600 => format!("{}m ago", secs / 60),
+        _ => format!("{}h ago", secs / 3600),
+    }
+}
+
+/// Mesaj günlüğünü çiz
+pub fn draw_message_log(f: &mut Frame, area: Rect, app: &mut App) {
+    if app.messages.is_empty() {
+        let empty_widget =
+            Paragraph::new("✅ No messages\n\nPlayback and scan problems will show up here")
+                .block(
+                    Block::default()
+                        .borders(Borders::ALL)
+                        .title("📜 Messages")
+                        .style(Style::default().fg(app.theme.secondary)),
+                )
+                .style(Style::default().fg(app.theme.accent))
+                .alignment(Alignment::Center);
+
+        f.render_widget(empty_widget, area);
+        return;
+    }
+
+    let items: Vec<ListItem> = app
+        .messages
+        .iter()
+        .map(|message| {
+            let (icon, color) = level_style(message.level, &app.theme);
+            let mut lines = vec![Line::from(vec![
+                Span::styled(format!("{} ", icon), Style::default().fg(color)),
+                Span::styled(
+                    format!("{:>7}  ", age(message)),
+                    Style::default().fg(app.theme.muted),
+                ),
+                Span::styled(message.text.clone(), Style::default().fg(app.theme.text)),
+            ])];
+            if let Some(path) = &message.path {
+                lines.push(Line::from(Span::styled(
+                    format!("            {}", path.display()),
+                    Style::default().fg(app.theme.text_dim),
+                )));
+            }
+            ListItem::new(lines)
+        })
+        .collect();
+
+    let list = List::new(items)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(format!("📜 Messages · {}", app.messages.len()))
+                .border_style(Style::default().fg(app.theme.primary)),
+        )
+        .highlight_style(
+            Style::default()
+                .bg(app.theme.secondary)
+                .add_modifier(Modifier::BOLD),
+        )
+        .highlight_symbol("▶ ");
+
+    f.render_stateful_widget(list, area, &mut app.messages_state);
+}
+
+/// En yeni mesajı birkaç saniyeliğine sağ üstte bildirim olarak göster
+pub fn draw_toast(f: &mut Frame, app: &App) {
+    let Some(message) = app.messages.toast() else {
+        return;
+    };
+    let screen = f.size();
+    let width = screen.width.saturating_sub(6).min(70);
+    let height = if message.path.is_some() { 4 } else { 3 };
+    if width < 20 || screen.height < height + 4 {
+        return;
+    }
+    // Liste çerçevesinin içinde, sağ üst köşede
+    let area = Rect::new(screen.width - width - 3, 4, width, height);
+
+    let (icon, color) = level_style(message.level, &app.theme);
+    let mut lines = vec![Line::from(Span::styled(
+        message.text.clone(),
+        Style::default()
+            .fg(app.theme.text)
+            .add_modifier(Modifier::BOLD),
+    ))];
+    if let Some(path) = &message.path {
+        lines.push(Line::from(Span::styled(
+            path.display().to_string(),
+            Style::default().fg(app.theme.text_dim),
+        )));
+    }
+
+    let toast = Paragraph::new(lines)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(format!("{} Press 3 for messages", icon))
+                .border_style(Style::default().fg(color)),
+        )
+        .wrap(Wrap { trim: true });
+    f.render_widget(Clear, area);
+    f.render_widget(toast, area);
+}
@@ -1,6 +1,15 @@
 pub mod app;
+pub mod browser;
+pub mod effects;
+pub mod equalizer;
 pub mod events;
 pub mod help;
+pub mod log;
+pub mod notify;
+pub mod playlists;
+pub mod queue;
+pub mod theme;
+pub mod tree;
 pub mod widgets;
 
 use crossterm::{
@@ -9,14 +18,61 @@ use crossterm::{
     terminal::{EnterAlternateScreen, LeaveAlternateScreen, disable_raw_mode, enable_raw_mode},
 };
 use ratatui::{Terminal, backend::CrosstermBackend};
-use std::{io, path::PathBuf};
+use std::io;
+use std::time::Duration;
 
-use app::App;
-use events::handle_events;
+use crate::library::{Library, Track};
+use crate::player::{Crossfade, EffectConfig, EqPreset, Normalization, VolumeCurve};
+use crate::playlist::smart::SmartPlaylist;
+use crate::state::{ConfigSettings, SavedEqualizer, SavedState};
+
+use app::{App, SearchMode};
+use events::{KeyMap, handle_events};
+use theme::Theme;
 use widgets::draw_ui;
 
+/// UI açılış seçenekleri
+pub struct UiOptions {
+    /// Kütüphane kökleri arka planda taransın mı?
+    pub scan_library: bool,
+    /// Açılışta çalmaya başlansın mı?
+    pub autoplay: bool,
+    /// Açılışta tüm parçalar kuyruğa eklensin mi?
+    pub enqueue_all: bool,
+    /// Karışık çalma açık mı?
+    pub shuffle: bool,
+    pub volume: f32,
+    /// Açılışta ses kapalı mı?
+    pub muted: bool,
+    /// Ses açma/kısma adımı (yüzde)
+    pub volume_step: u8,
+    pub volume_curve: VolumeCurve,
+    pub crossfade: Crossfade,
+    pub normalization: Normalization,
+    /// Açılıştaki ekolayzır ayarı ve arayüzde seçilebilecek hazır ayarlar
+    pub equalizer: SavedEqualizer,
+    pub eq_presets: Vec<EqPreset>,
+    /// Açılıştaki efekt zinciri
+    pub effects: Vec<EffectConfig>,
+    /// Config'deki ekolayzır ve efekt ayarları; çıkışta durumla birlikte saklanır
+    pub config_settings: ConfigSettings,
+    pub page_size: usize,
+    /// Kısa ve uzun sarma adımları (saniye)
+    pub seek_step: u64,
+    pub seek_long_step: u64,
+    pub poll_interval: Duration,
+    pub theme: Theme,
+    pub keymap: KeyMap,
+    /// Config dosyasında tanımlı akıllı listeler
+    pub smart_playlists: Vec<SmartPlaylist>,
+}
+
 /// Ana UI fonksiyonu - terminal kurulumu ve event loop'u yönetir
-pub fn run_ui(tracks: &Vec<PathBuf>) -> io::Result<Option<PathBuf>> {
+pub fn run_ui(
+    library: Library,
+    tracks: Vec<Track>,
+    options: UiOptions,
+) -> io::Result<()> {
     // Terminal kurulumu
     enable_raw_mode()?;
     let mut stdout = io::stdout();
@@ -25,10 +81,42 @@ pub fn run_ui(tracks: &Vec<PathBuf>) -> io::Result<Option<PathBuf>> {
     let mut terminal = Terminal::new(backend)?;
 
     // App oluştur ve çalıştır
-    let mut app = App::new(tracks.clone());
+    let mut app = App::new(library, tracks);
+    app.player.volume = options.volume;
+    app.player.muted = options.muted;
+    app.player.curve = options.volume_curve;
+    app.player.crossfade = options.crossfade;
+    app.player.normalization = options.normalization;
+    app.player.equalizer.set_gains(&options.equalizer.gains);
+    app.player.equalizer.set_enabled(options.equalizer.enabled);
+    app.eq_presets = options.eq_presets;
+    app.player.effects.configure(&options.effects);
+    app.volume_step = options.volume_step;
+    app.page_size = options.page_size;
+    app.seek_step = options.seek_step;
+    app.seek_long_step = options.seek_long_step;
+    app.theme = options.theme;
+    app.smart_config = options.smart_playlists;
+    app.refresh_smart();
     app.select_first(); // İlk öğeyi seç
+    app.queue.set_shuffle(options.shuffle);
+    app.player.shuffled = options.shuffle;
+    if options.enqueue_all {
+        app.enqueue_all();
+    }
+    if options.autoplay {
+        app.next_track();
+    }
+    if options.scan_library {
+        app.start_scan(); // Kütüphaneyi arka planda tara
+    }
 
-    let result = run_app(&mut terminal, app);
+    let result = run_app(
+        &mut terminal,
+        &mut app,
+        &options.keymap,
+        options.poll_interval,
+    );
 
     // Terminal'i eski haline getir
     disable_raw_mode()?;
@@ -39,43 +127,109 @@ pub fn run_ui(tracks: &Vec<PathBuf>) -> io::Result<Option<PathBuf>> {
     )?;
     terminal.show_cursor()?;
 
+    // Ses seviyesini bir sonraki açılış için sakla
+    let state = SavedState {
+        volume: Some((app.player.volume * 100.0).round() as u8),
+        muted: app.player.muted,
+        equalizer: Some(SavedEqualizer {
+            enabled: app.player.equalizer.is_enabled(),
+            gains: app.player.equalizer.gains(),
+        }),
+        effects: Some(app.player.effects.configs()),
+        config: Some(options.config_settings),
+    };
+    if let Err(e) = state.save() {
+        eprintln!("⚠️  Could not save player state: {}", e);
+    }
+    if let Err(e) = app.history.save() {
+        eprintln!("⚠️  Could not save play history: {}", e);
+    }
+
     result
 }
 
 /// Ana uygulama döngüsü
 fn run_app(
     terminal: &mut Terminal<CrosstermBackend<std::io::Stdout>>,
-    mut app: App,
-) -> io::Result<Option<PathBuf>> {
+    app: &mut App,
+    keymap: &KeyMap,
+    poll_interval: Duration,
+) -> io::Result<()> {
     loop {
-        // Müzik durumunu güncelle
+        // Tarama ve müzik durumunu güncelle
+        app.poll_scan();
+        app.poll_loudness();
+        app.poll_finder();
         app.update_playback_status();
 
         // UI'ı çiz
-        terminal.draw(|f| draw_ui(f, &mut app))?;
+        terminal.draw(|f| draw_ui(f, app))?;
 
         // Event'ları handle et
-        match handle_events()? {
-            Some(events::AppAction::Quit) => return Ok(None),
+        match handle_events(keymap, poll_interval, app.is_typing())? {
+            Some(events::AppAction::Quit) => return Ok(()),
+            Some(events::AppAction::Back) if app.back() => return Ok(()),
+            Some(events::AppAction::Back) => {}
             Some(events::AppAction::NextItem) => app.next(),
             Some(events::AppAction::PreviousItem) => app.previous(),
             Some(events::AppAction::NextPage) => app.next_page(),
             Some(events::AppAction::PreviousPage) => app.previous_page(),
             Some(events::AppAction::GoToTop) => app.go_to_top(),
             Some(events::AppAction::GoToBottom) => app.go_to_bottom(),
-            Some(events::AppAction::SelectItem) => {
-                if let Some(selected) = app.get_selected() {
-                    // Müziği doğrudan çal, UI'dan çıkma
-                    if let Err(_) = app.play_track(selected) {
-                        // Hata durumunda sessizce devam et
-                    }
-                }
-            }
+            Some(events::AppAction::SelectItem) => app.play_selected(),
             Some(events::AppAction::ToggleHelp) => app.toggle_help(),
             Some(events::AppAction::Refresh) => app.refresh(),
-            Some(events::AppAction::Random) => app.select_random(),
+            Some(events::AppAction::AnalyzeLoudness) => app.analyze_loudness(),
+            Some(events::AppAction::Random) => app.play_random(),
+            Some(events::AppAction::ToggleShuffle) => app.toggle_shuffle(),
+            Some(events::AppAction::CycleRepeat) => app.cycle_repeat(),
             Some(events::AppAction::TogglePause) => app.toggle_pause(),
             Some(events::AppAction::StopPlayback) => app.stop_playback(),
+            Some(events::AppAction::NextTrack) => app.next_track(),
+            Some(events::AppAction::PreviousTrack) => app.previous_track(),
+            Some(events::AppAction::QueueAppend) => app.queue_append(),
+            Some(events::AppAction::QueuePlayNext) => app.queue_play_next(),
+            Some(events::AppAction::RemoveItem) => app.remove_item(),
+            Some(events::AppAction::MoveItemUp) => app.move_item(-1),
+            Some(events::AppAction::MoveItemDown) => app.move_item(1),
+            Some(events::AppAction::ClearQueue) => app.clear_queue(),
+            Some(events::AppAction::ShowLibrary) => app.set_view(app::ViewMode::Normal),
+            Some(events::AppAction::ShowQueue) => app.set_view(app::ViewMode::Queue),
+            Some(events::AppAction::ShowMessages) => app.set_view(app::ViewMode::Messages),
+            Some(events::AppAction::ShowBrowser) => app.set_view(app::ViewMode::Browser),
+            Some(events::AppAction::NextPane) => app.switch_pane(true),
+            Some(events::AppAction::PreviousPane) => app.switch_pane(false),
+            Some(events::AppAction::PlayAlbum) => app.play_album(),
+            Some(events::AppAction::QueueArtist) => app.queue_artist(),
+            Some(events::AppAction::ShowFolders) => app.set_view(app::ViewMode::Folders),
+            Some(events::AppAction::PlayFolder) => app.play_folder(),
+            Some(events::AppAction::QueueFolder) => app.queue_folder(),
+            Some(events::AppAction::CollapseFolder) => app.collapse_folder(),
+            Some(events::AppAction::ShowPlaylists) => app.set_view(app::ViewMode::Playlists),
+            Some(events::AppAction::ShowEqualizer) => app.set_view(app::ViewMode::Equalizer),
+            Some(events::AppAction::ShowEffects) => app.set_view(app::ViewMode::Effects),
+            Some(events::AppAction::SavePlaylist) => app.start_save_playlist(),
+            Some(events::AppAction::NewPlaylist) => app.start_new_playlist(),
+            Some(events::AppAction::NewSmartPlaylist) => app.start_smart_playlist(),
+            Some(events::AppAction::RenamePlaylist) => app.start_rename_playlist(),
+            Some(events::AppAction::AddToPlaylist) => app.start_add_to_playlist(),
+            Some(events::AppAction::ToggleMark) => app.toggle_mark(),
+            Some(events::AppAction::SeekForward) => app.seek_steps(1, false),
+            Some(events::AppAction::SeekBackward) => app.seek_steps(-1, false),
+            Some(events::AppAction::SeekForwardLong) => app.seek_steps(1, true),
+            Some(events::AppAction::SeekBackwardLong) => app.seek_steps(-1, true),
+            Some(events::AppAction::SeekTo(percent)) => app.seek_to_percent(percent),
+            Some(events::AppAction::VolumeUp) => app.change_volume(1),
+            Some(events::AppAction::VolumeDown) => app.change_volume(-1),
+            Some(events::AppAction::ToggleMute) => app.toggle_mute(),
+            Some(events::AppAction::StartSearch) => app.start_search(SearchMode::Substring),
+            Some(events::AppAction::StartFinder) => app.start_search(SearchMode::Fuzzy),
+            Some(events::AppAction::NextMatch) => app.jump_to_match(true),
+            Some(events::AppAction::PreviousMatch) => app.jump_to_match(false),
+            Some(events::AppAction::InputChar(c)) => app.input_char(c),
+            Some(events::AppAction::InputBackspace) => app.input_backspace(),
+            Some(events::AppAction::InputSubmit) => app.input_submit(),
+            Some(events::AppAction::InputCancel) => app.input_cancel(),
             None => {} // Hiçbir aksiyon yok, devam et
         }
     }
@@ -0,0 +1,72 @@
+use std::collections::VecDeque;
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+/// Günlükte tutulan en fazla mesaj sayısı
+const MAX_MESSAGES: usize = 500;
+/// Yeni mesajın bildirim olarak ekranda kalma süresi
+const TOAST_DURATION: Duration = Duration::from_secs(4);
+
+/// Mesajın önem derecesi
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Level {
+    Info,
+    /// Kullanıcının başlattığı bir işlem tamamlandı (ör. liste kaydedildi)
+    Success,
+    Warning,
+    Error,
+}
+
+/// Günlükteki tek bir mesaj
+#[derive(Debug, Clone)]
+pub struct Message {
+    pub level: Level,
+    pub text: String,
+    /// Mesajın ilgili olduğu dosya
+    pub path: Option<PathBuf>,
+    pub at: Instant,
+}
+
+/// Çalma ve tarama sırasında oluşan mesajların günlüğü; en yeni mesaj kısa süre bildirim olarak gösterilir
+#[derive(Debug, Default)]
+pub struct MessageLog {
+    messages: VecDeque<Message>,
+}
+
+impl MessageLog {
+    /// Yeni mesaj ekle (en eski mesajlar atılır)
+    pub fn push(&mut self, level: Level, text: impl Into<String>, path: Option<PathBuf>) {
+        if self.messages.len() == MAX_MESSAGES {
+            self.messages.pop_front();
+        }
+        self.messages.push_back(Message {
+            level,
+            text: text.into(),
+            path,
+            at: Instant::now(),
+        });
+    }
+
+    /// Mesaj sayısı
+    pub fn len(&self) -> usize {
+        self.messages.len()
+    }
+
+    /// Günlük boş mu?
+    pub fn is_empty(&self) -> bool {
+        self.messages.is_empty()
+    }
+
+    /// Mesajlar, en yenisi başta
+    pub fn iter(&self) -> impl Iterator<Item = &Message> {
+        self.messages.iter().rev()
+    }
+
+    /// Ekranda gösterilecek bildirim: son birkaç saniyede eklenmiş bilgi dışı bir mesajsa en yeni mesaj
+    pub fn toast(&self) -> Option<&Message> {
+        self.messages
+            .back()
+            .filter(|message| message.level != Level::Info)
+            .filter(|message| message.at.elapsed() < TOAST_DURATION)
+    }
+}
@@ -0,0 +1,514 @@
+use crate::library::track::format_duration;
+use crate::playlist::smart::SmartPlaylist;
+use crate::playlist::{self, Playlist, PlaylistEntry};
+use crate::ui::app::App;
+use ratatui::{
+    Frame,
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, List, ListItem, ListState, Paragraph},
+};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/// Çalma listesi görünümündeki sütunlar
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PlaylistPane {
+    Lists,
+    Entries,
+}
+
+/// Diskten okunmuş çalma listesi
+#[derive(Debug, Clone, Default)]
+pub struct LoadedPlaylist {
+    pub playlist: Playlist,
+    /// Diskte bulunamayan satırlar
+    pub missing: Vec<bool>,
+    /// Dosya okunamadıysa hata mesajı
+    pub error: Option<String>,
+}
+
+impl LoadedPlaylist {
+    fn load(path: &Path) -> LoadedPlaylist {
+        match playlist::load(path) {
+            Ok(playlist) => LoadedPlaylist::from_playlist(playlist),
+            Err(e) => LoadedPlaylist {
+                playlist: Playlist {
+                    name: playlist::name_from_path(path),
+                    entries: Vec::new(),
+                },
+                missing: Vec::new(),
+                error: Some(e.to_string()),
+            },
+        }
+    }
+
+    fn from_playlist(playlist: Playlist) -> LoadedPlaylist {
+        let missing = playlist.entries.iter().map(|e| !e.path.is_file()).collect();
+        LoadedPlaylist {
+            playlist,
+            missing,
+            error: None,
+        }
+    }
+
+    /// Süresi bilinen satırların toplam süresi
+    pub fn total_duration(&self) -> Duration {
+        self.playlist
+            .entries
+            .iter()
+            .filter_map(|e| e.duration)
+            .sum()
+    }
+
+    /// Bulunamayan satır sayısı
+    pub fn missing_count(&self) -> usize {
+        self.missing.iter().filter(|&&missing| missing).count()
+    }
+}
+
+/// Akıllı liste ve son değerlendirmenin sonucu
+#[derive(Debug, Clone)]
+pub struct SmartList {
+    pub definition: SmartPlaylist,
+    /// Config dosyasında tanımlı (arayüzden silinemez)
+    pub from_config: bool,
+    pub list: LoadedPlaylist,
+}
+
+impl SmartList {
+    /// Değerlendirme sonucundan liste oluştur; kurallar geçersizse hata gösterilir
+    pub fn new(
+        definition: SmartPlaylist,
+        from_config: bool,
+        result: Result<Vec<PlaylistEntry>, String>,
+    ) -> SmartList {
+        let (entries, error) = match result {
+            Ok(entries) => (entries, None),
+            Err(e) => (Vec::new(), Some(e)),
+        };
+        let mut list = LoadedPlaylist::from_playlist(Playlist {
+            name: definition.name.clone(),
+            entries,
+        });
+        list.error = error;
+        SmartList {
+            definition,
+            from_config,
+            list,
+        }
+    }
+}
+
+/// Listeler sütunundaki satır (seçimi korumak için)
+#[derive(Debug, Clone, PartialEq)]
+enum Row {
+    Smart(String),
+    Path(PathBuf),
+}
+
+/// Akıllı ve adlandırılmış listeler, taramada bulunan çalma listeleri ve seçili listenin satırları
+pub struct PlaylistsView {
+    /// Kurallarla doldurulan listeler (listede en üstte gösterilir)
+    pub smart: Vec<SmartList>,
+    /// meloic içinde oluşturulan listeler, ada göre sıralı (listede önce gösterilir)
+    pub named: Vec<PathBuf>,
+    /// Kütüphanedeki çalma listesi dosyaları, yola göre sıralı
+    pub files: Vec<PathBuf>,
+    pub pane: PlaylistPane,
+    pub list_state: ListState,
+    pub entry_state: ListState,
+    /// Okunmuş listeler (dosyalar bir kez okunur)
+    loaded: HashMap<PathBuf, LoadedPlaylist>,
+    /// Satır sütununun gösterdiği liste
+    shown: Option<usize>,
+}
+
+impl Default for PlaylistsView {
+    fn default() -> Self {
+        PlaylistsView {
+            smart: Vec::new(),
+            named: Vec::new(),
+            files: Vec::new(),
+            pane: PlaylistPane::Lists,
+            list_state: ListState::default(),
+            entry_state: ListState::default(),
+            loaded: HashMap::new(),
+            shown: None,
+        }
+    }
+}
+
+impl PlaylistsView {
+    /// Akıllı listelerden sonra gösterilen yollar: önce adlandırılmış listeler, sonra dosyalar
+    pub fn paths(&self) -> impl Iterator<Item = &PathBuf> {
+        self.named.iter().chain(&self.files)
+    }
+
+    /// Toplam liste sayısı
+    pub fn len(&self) -> usize {
+        self.smart.len() + self.named.len() + self.files.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// meloic içinde oluşturulmuş (düzenlenebilir) liste mi?
+    pub fn is_named(&self, path: &Path) -> bool {
+        self.named.iter().any(|named| named == path)
+    }
+
+    /// Adlandırılmış listeleri değiştir (oluşturma, yeniden adlandırma, silme sonrası)
+    pub fn set_named(&mut self, named: Vec<PathBuf>) {
+        let selected = self.selected_row();
+        for path in &self.named {
+            if !named.contains(path) {
+                self.loaded.remove(path);
+            }
+        }
+        self.named = named;
+        self.shown = None;
+        self.select_row(selected);
+    }
+
+    /// Akıllı listeleri yeniden değerlendirilmiş halleriyle değiştir
+    pub fn set_smart(&mut self, smart: Vec<SmartList>) {
+        let selected = self.selected_row();
+        if matches!(selected, Some(Row::Smart(_))) {
+            self.shown = None;
+        }
+        self.smart = smart;
+        self.select_row(selected);
+    }
+
+    /// Adlandırılmış listenin satırlarını değiştir (diske yazıldıktan sonra)
+    pub fn set_entries(&mut self, path: &Path, entries: Vec<PlaylistEntry>) {
+        let list = LoadedPlaylist::from_playlist(Playlist {
+            name: playlist::name_from_path(path),
+            entries,
+        });
+        self.loaded.insert(path.to_path_buf(), list);
+    }
+
+    /// Taramada bulunan listeyi ekle (zaten varsa bir şey yapma)
+    pub fn add(&mut self, path: PathBuf) {
+        if let Err(index) = self.files.binary_search(&path) {
+            let selected = self.selected_row();
+            self.files.insert(index, path);
+            self.select_row(selected);
+        }
+    }
+
+    /// Tarama bittiğinde listeleri bulunanlarla değiştir; dosyalar tekrar okunur
+    pub fn set_files(&mut self, mut files: Vec<PathBuf>) {
+        files.sort();
+        files.dedup();
+        let selected = self.selected_row();
+        self.files = files;
+        self.loaded.clear();
+        self.shown = None;
+        self.select_row(selected);
+    }
+
+    /// Listeyi diskten yeniden oku (ör. kaydedildikten sonra)
+    pub fn reload(&mut self, path: &Path) {
+        self.loaded.remove(path);
+        if self
+            .selected_path()
+            .is_some_and(|selected| selected == path)
+        {
+            self.shown = None;
+        }
+    }
+
+    fn selected_row(&self) -> Option<Row> {
+        match self.selected_smart() {
+            Some(smart) => Some(Row::Smart(smart.definition.name.clone())),
+            None => self.selected_path().cloned().map(Row::Path),
+        }
+    }
+
+    /// Verilen satırı seç; bulunamazsa ilk liste seçilir
+    fn select_row(&mut self, row: Option<Row>) {
+        let index = row
+            .and_then(|row| match row {
+                Row::Smart(name) => self.smart.iter().position(|s| s.definition.name == name),
+                Row::Path(path) => self
+                    .paths()
+                    .position(|p| *p == path)
+                    .map(|i| i + self.smart.len()),
+            })
+            .or_else(|| (!self.is_empty()).then_some(0));
+        self.list_state.select(index);
+    }
+
+    /// Verilen listeyi seç; bulunamazsa ilk liste seçilir
+    pub fn select_path(&mut self, path: Option<PathBuf>) {
+        self.select_row(path.map(Row::Path));
+    }
+
+    /// Verilen akıllı listeyi seç
+    pub fn select_smart(&mut self, name: &str) {
+        self.select_row(Some(Row::Smart(name.to_string())));
+    }
+
+    /// Seçili akıllı liste
+    pub fn selected_smart(&self) -> Option<&SmartList> {
+        self.list_state.selected().and_then(|i| self.smart.get(i))
+    }
+
+    /// Seçili listenin yolu (akıllı listelerin yolu yoktur)
+    pub fn selected_path(&self) -> Option<&PathBuf> {
+        let index = self.list_state.selected()?.checked_sub(self.smart.len())?;
+        self.paths().nth(index)
+    }
+
+    /// Listeyi gerekiyorsa okuyup döndür
+    pub fn playlist(&mut self, path: &Path) -> &LoadedPlaylist {
+        self.loaded
+            .entry(path.to_path_buf())
+            .or_insert_with(|| LoadedPlaylist::load(path))
+    }
+
+    /// Seçili liste, okunmuşsa
+    pub fn current(&self) -> Option<&LoadedPlaylist> {
+        match self.selected_smart() {
+            Some(smart) => Some(&smart.list),
+            None => self.selected_path().and_then(|path| self.loaded.get(path)),
+        }
+    }
+
+    /// Seçili liste (gerekirse okunur)
+    pub fn selected(&mut self) -> Option<&LoadedPlaylist> {
+        if let Some(index) = self.list_state.selected()
+            && index < self.smart.len()
+        {
+            return Some(&self.smart[index].list);
+        }
+        let path = self.selected_path()?.clone();
+        Some(self.playlist(&path))
+    }
+
+    /// Liste seçimi değiştiyse satır sütununu başa al
+    fn sync(&mut self) {
+        if self.list_state.selected() != self.shown {
+            self.shown = self.list_state.selected();
+            let has_entries = self
+                .selected()
+                .is_some_and(|list| !list.playlist.entries.is_empty());
+            self.entry_state = ListState::default();
+            self.entry_state.select(has_entries.then_some(0));
+        }
+    }
+
+    /// Etkin sütunun durumu ve uzunluğu
+    pub fn active_list(&mut self) -> (&mut ListState, usize) {
+        self.sync();
+        match self.pane {
+            PlaylistPane::Lists => {
+                let len = self.len();
+                (&mut self.list_state, len)
+            }
+            PlaylistPane::Entries => {
+                let len = self
+                    .selected()
+                    .map_or(0, |list| list.playlist.entries.len());
+                (&mut self.entry_state, len)
+            }
+        }
+    }
+
+    /// Diğer sütuna geç
+    pub fn switch_pane(&mut self) {
+        self.pane = match self.pane {
+            PlaylistPane::Lists => PlaylistPane::Entries,
+            PlaylistPane::Entries => PlaylistPane::Lists,
+        };
+    }
+}
+
+/// Çalma listelerini ve seçili listenin satırlarını çiz
+pub fn draw_playlists(f: &mut Frame, area: Rect, app: &mut App) {
+    if app.playlists.is_empty() {
+        let empty_widget = Paragraph::new(
+            "📃 No playlists found\n\n📝 Press 'c' to create a playlist or 'w' to save the queue\n💾 .m3u8, .pls and .xspf files in the library show up here too",
+        )
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title("📃 Playlists")
+                .style(Style::default().fg(app.theme.secondary)),
+        )
+        .style(Style::default().fg(app.theme.accent))
+        .alignment(Alignment::Center);
+        f.render_widget(empty_widget, area);
+        return;
+    }
+
+    let columns = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(35), Constraint::Percentage(65)])
+        .split(area);
+
+    // Satır sayıları için tüm listeler okunur; her dosya yalnızca bir kez okunur
+    app.playlists.active_list();
+    for path in app.playlists.paths().cloned().collect::<Vec<_>>() {
+        app.playlists.playlist(&path);
+    }
+
+    let view = &app.playlists;
+    let theme = &app.theme;
+    let playing = app.player.current.as_ref().map(|t| &t.path);
+
+    let row = |icon: &'static str, list: &LoadedPlaylist, extension: String| {
+        let (count, style) = match &list.error {
+            Some(_) => ("⚠".to_string(), Style::default().fg(theme.error)),
+            None => (
+                format!(
+                    "{} · {}",
+                    list.playlist.entries.len(),
+                    format_duration(list.total_duration())
+                ),
+                Style::default().fg(theme.muted),
+            ),
+        };
+        ListItem::new(Line::from(vec![
+            Span::raw(icon),
+            Span::styled(list.playlist.name.clone(), Style::default().fg(theme.text)),
+            Span::styled(extension, Style::default().fg(theme.text_dim)),
+            Span::styled(format!("  {}", count), style),
+        ]))
+    };
+    // Akıllı listeler önce, sonra adlandırılmış listeler, en son uzantılarıyla dosyalar
+    let lists: Vec<ListItem> = view
+        .smart
+        .iter()
+        .map(|smart| row("✨ ", &smart.list, String::new()))
+        .chain(view.paths().map(|path| {
+            let list = &view.loaded[path];
+            if view.is_named(path) {
+                row("📝 ", list, String::new())
+            } else {
+                let extension = path
+                    .extension()
+                    .map(|ext| format!(".{}", ext.to_string_lossy()))
+                    .unwrap_or_default();
+                row("📄 ", list, extension)
+            }
+        }))
+        .collect();
+
+    let selected = view.current();
+    let entries: Vec<ListItem> = selected
+        .map(|list| {
+            list.playlist
+                .entries
+                .iter()
+                .zip(&list.missing)
+                .map(|(entry, &missing)| {
+                    let name = entry
+                        .title
+                        .clone()
+                        .unwrap_or_else(|| playlist::name_from_path(&entry.path));
+                    let (marker, style) = if Some(&entry.path) == playing {
+                        (
+                            "▶ ",
+                            Style::default()
+                                .fg(theme.success)
+                                .add_modifier(Modifier::BOLD),
+                        )
+                    } else if missing {
+                        (
+                            "⚠ ",
+                            Style::default()
+                                .fg(theme.error)
+                                .add_modifier(Modifier::CROSSED_OUT),
+                        )
+                    } else {
+                        ("  ", Style::default().fg(theme.text))
+                    };
+                    let mut spans = vec![Span::styled(marker, style), Span::styled(name, style)];
+                    if let Some(duration) = entry.duration {
+                        spans.push(Span::styled(
+                            format!("  {}", format_duration(duration)),
+                            Style::default().fg(theme.muted),
+                        ));
+                    }
+                    ListItem::new(Line::from(spans))
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let entries_title = match selected {
+        // Akıllı listelerde kurallar da gösterilir
+        Some(list)
+            if let Some(smart) = view.selected_smart()
+                && list.error.is_none() =>
+        {
+            format!("✨ {} · {}", list.playlist.name, smart.definition.rules)
+        }
+        Some(list) => match &list.error {
+            Some(error) => format!("⚠ {}: {}", list.playlist.name, error),
+            None if list.missing_count() > 0 => format!(
+                "🎵 {} · {} missing",
+                list.playlist.name,
+                list.missing_count()
+            ),
+            None => format!("🎵 {}", list.playlist.name),
+        },
+        None => "🎵 Entries".to_string(),
+    };
+    let titles = [format!("📃 Playlists · {}", view.len()), entries_title];
+
+    let pane = view.pane;
+    let mut widgets = Vec::new();
+    for ((items, list_pane), title) in [
+        (lists, PlaylistPane::Lists),
+        (entries, PlaylistPane::Entries),
+    ]
+    .into_iter()
+    .zip(titles)
+    {
+        let active = list_pane == pane;
+        // Etkin sütun vurgulanır, diğerinde seçim yalnızca renkle gösterilir
+        let (border, highlight) = if active {
+            (
+                theme.primary,
+                Style::default()
+                    .bg(theme.secondary)
+                    .fg(theme.text)
+                    .add_modifier(Modifier::BOLD),
+            )
+        } else {
+            (
+                theme.muted,
+                Style::default()
+                    .fg(theme.accent)
+                    .add_modifier(Modifier::BOLD),
+            )
+        };
+        widgets.push(
+            List::new(items)
+                .block(
+                    Block::default()
+                        .borders(Borders::ALL)
+                        .title(title)
+                        .border_style(Style::default().fg(border)),
+                )
+                .highlight_style(highlight)
+                .highlight_symbol(if active { "▶ " } else { "  " }),
+        );
+    }
+
+    let states = [
+        &mut app.playlists.list_state,
+        &mut app.playlists.entry_state,
+    ];
+    for ((widget, column), state) in widgets.into_iter().zip(columns.iter()).zip(states) {
+        f.render_stateful_widget(widget, *column, state);
+    }
+}
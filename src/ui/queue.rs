@@ -0,0 +1,124 @@
+use crate::library::track::format_duration;
+use crate::ui::app::App;
+use ratatui::{
+    Frame,
+    layout::{Alignment, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, List, ListItem, Paragraph},
+};
+use std::time::Duration;
+
+/// Çalma kuyruğunu çiz
+pub fn draw_queue_list(f: &mut Frame, area: Rect, app: &mut App) {
+    if app.queue.is_empty() {
+        let empty_widget = Paragraph::new(
+            "📭 The queue is empty\n\n➕ Press 'e' in the library to add a track, 'E' to play it next",
+        )
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title("🎶 Play Queue")
+                .style(Style::default().fg(app.theme.secondary)),
+        )
+        .style(Style::default().fg(app.theme.accent))
+        .alignment(Alignment::Center);
+
+        f.render_widget(empty_widget, area);
+        return;
+    }
+
+    // Çalan parça kuyruktan çıkarılmış olabilir; işaret yalnızca gerçekten çalıyorsa konur
+    let playing = app.queue.current_index().filter(|&i| {
+        let current = app.player.current.as_ref();
+        current
+            .zip(app.queue.get(i))
+            .is_some_and(|(a, b)| a.is_same(b))
+    });
+    let selected = app.queue_state.selected();
+
+    let items: Vec<ListItem> = app
+        .queue
+        .iter()
+        .enumerate()
+        .map(|(i, track)| {
+            // Karışık modda liste sırası çalma sırası değildir
+            let played = !app.queue.is_shuffled()
+                && app.queue.current_index().is_some_and(|current| i < current);
+            let (marker, title_style) = if Some(i) == playing {
+                (
+                    "▶ ",
+                    Style::default()
+                        .fg(app.theme.success)
+                        .add_modifier(Modifier::BOLD),
+                )
+            } else if app.broken.contains(&track.path) {
+                (
+                    "⚠ ",
+                    Style::default()
+                        .fg(app.theme.error)
+                        .add_modifier(Modifier::CROSSED_OUT),
+                )
+            } else if played {
+                ("  ", Style::default().fg(app.theme.muted))
+            } else if Some(i) == selected {
+                (
+                    "  ",
+                    Style::default()
+                        .fg(app.theme.text)
+                        .add_modifier(Modifier::BOLD),
+                )
+            } else {
+                ("  ", Style::default().fg(app.theme.text_dim))
+            };
+
+            let mut spans = vec![
+                Span::styled(
+                    format!("{:3}. ", i + 1),
+                    Style::default().fg(app.theme.muted),
+                ),
+                Span::styled(marker, title_style),
+                Span::styled(track.display_name(), title_style),
+            ];
+            if let Some(duration) = track.duration {
+                spans.push(Span::styled(
+                    format!("  {}", format_duration(duration)),
+                    Style::default().fg(app.theme.muted),
+                ));
+            }
+            ListItem::new(Line::from(spans))
+        })
+        .collect();
+
+    // Sıradaki parçaların toplam süresi
+    let upcoming = app.queue.upcoming();
+    let remaining: Duration = upcoming.iter().filter_map(|t| t.duration).sum();
+    let order = if app.queue.is_shuffled() {
+        " · 🔀"
+    } else {
+        ""
+    };
+    let title = format!(
+        "🎶 Play Queue · {} up next · {}{}",
+        upcoming.len(),
+        format_duration(remaining),
+        order
+    );
+
+    let list = List::new(items)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(title)
+                .border_style(Style::default().fg(app.theme.primary)),
+        )
+        .highlight_style(
+            Style::default()
+                .bg(app.theme.secondary)
+                .fg(app.theme.text)
+                .add_modifier(Modifier::BOLD),
+        )
+        .highlight_symbol("▶ ");
+
+    f.render_stateful_widget(list, area, &mut app.queue_state);
+}
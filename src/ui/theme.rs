@@ -0,0 +1,124 @@
+use ratatui::style::Color;
+use std::str::FromStr;
+
+use crate::config::ThemeConfig;
+
+/// UI'da kullanılan renk paleti
+#[derive(Debug, Clone, PartialEq)]
+pub struct Theme {
+    pub primary: Color,   // Başlıklar, seçili satır numarası
+    pub secondary: Color, // Çerçeveler, seçim arka planı
+    pub accent: Color,    // İstatistikler, ipuçları
+    pub success: Color,   // Çalan parça, ikonlar
+    pub info: Color,      // Albüm bilgisi
+    pub warning: Color,
+    pub error: Color,
+    pub text: Color,
+    pub text_dim: Color,
+    pub muted: Color,
+}
+
+/// Hazır tema isimleri
+pub const PRESETS: [&str; 3] = ["neon", "classic", "mono"];
+
+impl Theme {
+    /// Varsayılan neon tema
+    pub fn neon() -> Theme {
+        Theme {
+            primary: Color::Rgb(0, 255, 255),    // Neon cyan
+            secondary: Color::Rgb(255, 0, 255),  // Neon magenta
+            accent: Color::Rgb(255, 255, 0),     // Neon yellow
+            success: Color::Rgb(0, 255, 0),      // Neon green
+            info: Color::Rgb(100, 150, 255),     // Neon blue
+            warning: Color::Rgb(255, 100, 0),    // Neon orange
+            error: Color::Rgb(255, 60, 60),      // Neon red
+            text: Color::Rgb(255, 255, 255),     // Parlak beyaz
+            text_dim: Color::Rgb(180, 180, 180), // Parlak gri
+            muted: Color::Rgb(100, 100, 100),    // Koyu gri
+        }
+    }
+
+    /// Terminalin kendi 16 rengini kullanan tema
+    pub fn classic() -> Theme {
+        Theme {
+            primary: Color::Cyan,
+            secondary: Color::Magenta,
+            accent: Color::Yellow,
+            success: Color::Green,
+            info: Color::Blue,
+            warning: Color::LightRed,
+            error: Color::Red,
+            text: Color::White,
+            text_dim: Color::Gray,
+            muted: Color::DarkGray,
+        }
+    }
+
+    /// Renksiz, gri tonlarında tema
+    pub fn mono() -> Theme {
+        Theme {
+            primary: Color::White,
+            secondary: Color::Gray,
+            accent: Color::White,
+            success: Color::Gray,
+            info: Color::Gray,
+            warning: Color::White,
+            error: Color::White,
+            text: Color::White,
+            text_dim: Color::Gray,
+            muted: Color::DarkGray,
+        }
+    }
+
+    /// İsimden hazır tema bul
+    pub fn preset(name: &str) -> Option<Theme> {
+        match name {
+            "neon" => Some(Theme::neon()),
+            "classic" => Some(Theme::classic()),
+            "mono" => Some(Theme::mono()),
+            _ => None,
+        }
+    }
+
+    /// Config'deki hazır tema ve renk değişikliklerinden tema oluştur
+    pub fn from_config(config: &ThemeConfig) -> Result<Theme, String> {
+        let mut theme = Theme::preset(&config.preset).ok_or_else(|| {
+            format!(
+                "unknown theme preset '{}' (expected one of: {})",
+                config.preset,
+                PRESETS.join(", ")
+            )
+        })?;
+
+        let overrides = [
+            ("primary", &config.primary, &mut theme.primary),
+            ("secondary", &config.secondary, &mut theme.secondary),
+            ("accent", &config.accent, &mut theme.accent),
+            ("success", &config.success, &mut theme.success),
+            ("info", &config.info, &mut theme.info),
+            ("warning", &config.warning, &mut theme.warning),
+            ("error", &config.error, &mut theme.error),
+            ("text", &config.text, &mut theme.text),
+            ("text_dim", &config.text_dim, &mut theme.text_dim),
+            ("muted", &config.muted, &mut theme.muted),
+        ];
+        for (name, value, slot) in overrides {
+            if let Some(value) = value {
+                *slot = Color::from_str(value).map_err(|_| {
+                    format!(
+                        "invalid color '{}' for theme.{} (use a name like \"cyan\" or \"#rrggbb\")",
+                        value, name
+                    )
+                })?;
+            }
+        }
+
+        Ok(theme)
+    }
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Theme::neon()
+    }
+}
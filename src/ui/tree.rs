@@ -0,0 +1,305 @@
+use crate::library::Track;
+use crate::library::tree::FolderTree;
+use crate::ui::app::App;
+use crate::ui::widgets::visible_window;
+use ratatui::{
+    Frame,
+    layout::{Alignment, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, List, ListItem, ListState, Paragraph},
+};
+use std::collections::HashSet;
+use std::path::PathBuf;
+
+/// Klasör ağacında görünen bir satır
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TreeRow {
+    Folder {
+        node: usize,
+        depth: usize,
+    },
+    Track {
+        track: usize,
+        folder: usize,
+        depth: usize,
+    },
+}
+
+/// Klasör ağacı görünümünün durumu
+pub struct TreeView {
+    pub tree: FolderTree,
+    /// Açık klasörler (ağaç yeniden kurulunca korunsun diye yol olarak tutulur)
+    expanded: HashSet<PathBuf>,
+    pub rows: Vec<TreeRow>,
+    pub state: ListState,
+    /// Parça listesi değişti, ağaç yeniden kurulmalı
+    stale: bool,
+    /// Ağaç hiç kurulmadı; ilk kurulumda kökler açılır
+    built: bool,
+    /// Parça listesi değiştirilmeden önce seçili olan satırın yolu
+    pinned: Option<PathBuf>,
+}
+
+impl Default for TreeView {
+    fn default() -> Self {
+        TreeView {
+            tree: FolderTree::default(),
+            expanded: HashSet::new(),
+            rows: Vec::new(),
+            state: ListState::default(),
+            stale: true,
+            built: false,
+            pinned: None,
+        }
+    }
+}
+
+impl TreeView {
+    /// Parça listesi değiştiğinde çağrılır; ağaç bir sonraki kullanımda yeniden kurulur
+    pub fn mark_stale(&mut self) {
+        self.stale = true;
+    }
+
+    /// Parça listesi tamamen değiştirilmeden önce çağrılır; seçili satırın yolu saklanır
+    pub fn pin_selection(&mut self, tracks: &[Track]) {
+        if self.pinned.is_none() {
+            self.pinned = self.selected_row().map(|row| self.row_path(row, tracks));
+        }
+    }
+
+    /// Gerekiyorsa ağacı yeniden kur; açık klasörler ve seçili satır korunur
+    pub fn refresh(&mut self, roots: &[PathBuf], tracks: &[Track]) {
+        if !self.stale {
+            return;
+        }
+        // Liste yalnızca büyüdüyse eski parça sıraları hâlâ geçerlidir
+        let selected = self
+            .pinned
+            .take()
+            .or_else(|| self.selected_row().map(|row| self.row_path(row, tracks)));
+
+        self.tree = FolderTree::build(roots, tracks);
+        self.stale = false;
+        if !self.built {
+            self.built = true;
+            for &root in &self.tree.roots {
+                self.expanded.insert(self.tree.nodes[root].path.clone());
+            }
+        }
+        self.rebuild_rows();
+
+        let index = selected
+            .and_then(|path| {
+                self.rows
+                    .iter()
+                    .position(|&row| self.row_path(row, tracks) == path)
+            })
+            .or_else(|| (!self.rows.is_empty()).then_some(0));
+        self.state.select(index);
+    }
+
+    fn row_path(&self, row: TreeRow, tracks: &[Track]) -> PathBuf {
+        match row {
+            TreeRow::Folder { node, .. } => self.tree.nodes[node].path.clone(),
+            TreeRow::Track { track, .. } => tracks
+                .get(track)
+                .map(|t| t.path.clone())
+                .unwrap_or_default(),
+        }
+    }
+
+    /// Görünen satırları açık klasörlere göre yeniden oluştur
+    fn rebuild_rows(&mut self) {
+        let mut rows = Vec::new();
+        for &root in &self.tree.roots {
+            self.push_rows(root, 0, &mut rows);
+        }
+        self.rows = rows;
+    }
+
+    fn push_rows(&self, node: usize, depth: usize, rows: &mut Vec<TreeRow>) {
+        rows.push(TreeRow::Folder { node, depth });
+        let folder = &self.tree.nodes[node];
+        if !self.expanded.contains(&folder.path) {
+            return;
+        }
+        for &child in &folder.folders {
+            self.push_rows(child, depth + 1, rows);
+        }
+        rows.extend(folder.tracks.iter().map(|&track| TreeRow::Track {
+            track,
+            folder: node,
+            depth: depth + 1,
+        }));
+    }
+
+    /// Seçili satır
+    pub fn selected_row(&self) -> Option<TreeRow> {
+        self.state
+            .selected()
+            .and_then(|i| self.rows.get(i).copied())
+    }
+
+    /// Seçili klasör; parça seçiliyse içinde bulunduğu klasör
+    pub fn selected_folder(&self) -> Option<usize> {
+        match self.selected_row()? {
+            TreeRow::Folder { node, .. } => Some(node),
+            TreeRow::Track { folder, .. } => Some(folder),
+        }
+    }
+
+    /// Klasörün açık olup olmadığı
+    pub fn is_expanded(&self, node: usize) -> bool {
+        self.expanded.contains(&self.tree.nodes[node].path)
+    }
+
+    /// Seçili klasörü aç veya kapat
+    pub fn toggle(&mut self) {
+        if let Some(TreeRow::Folder { node, .. }) = self.selected_row() {
+            let path = self.tree.nodes[node].path.clone();
+            if !self.expanded.remove(&path) {
+                self.expanded.insert(path);
+            }
+            self.rebuild_rows();
+        }
+    }
+
+    /// Açık klasörü kapat; kapalı klasörde veya parçada üst klasöre git
+    pub fn collapse(&mut self) {
+        let target = match self.selected_row() {
+            Some(TreeRow::Folder { node, .. }) if self.is_expanded(node) => {
+                self.expanded.remove(&self.tree.nodes[node].path);
+                self.rebuild_rows();
+                return;
+            }
+            Some(TreeRow::Folder { node, .. }) => self.tree.nodes[node].parent,
+            Some(TreeRow::Track { folder, .. }) => Some(folder),
+            None => None,
+        };
+        if let Some(target) = target {
+            let index = self
+                .rows
+                .iter()
+                .position(|row| matches!(*row, TreeRow::Folder { node, .. } if node == target));
+            self.state.select(index);
+        }
+    }
+
+    /// Seçili satırın parçaları: klasörde alt klasörler dahil tümü, parçada yalnızca kendisi
+    pub fn selected_tracks(&self) -> Vec<usize> {
+        match self.selected_row() {
+            Some(TreeRow::Folder { node, .. }) => self.tree.tracks(node),
+            Some(TreeRow::Track { track, .. }) => vec![track],
+            None => Vec::new(),
+        }
+    }
+}
+
+/// Klasör ağacını çiz
+pub fn draw_tree(f: &mut Frame, area: Rect, app: &mut App) {
+    app.folders.refresh(&app.library.roots, &app.items);
+
+    if app.folders.rows.is_empty() {
+        let empty_widget =
+            Paragraph::new("📂 No library folders\n\n💡 Add roots under [library] in the config")
+                .block(
+                    Block::default()
+                        .borders(Borders::ALL)
+                        .title("📂 Folders")
+                        .style(Style::default().fg(app.theme.secondary)),
+                )
+                .style(Style::default().fg(app.theme.accent))
+                .alignment(Alignment::Center);
+        f.render_widget(empty_widget, area);
+        return;
+    }
+
+    // Tüm klasörler açıkken satır sayısı kütüphane kadar olabilir; yalnızca görünenler çizilir
+    let height = area.height.saturating_sub(2) as usize;
+    let (start, end) = visible_window(&mut app.folders.state, app.folders.rows.len(), height);
+    let selected = app.folders.state.selected();
+    let tree = &app.folders;
+    let theme = &app.theme;
+    let playing = app.player.current.as_ref();
+
+    let items: Vec<ListItem> = tree.rows[start..end]
+        .iter()
+        .map(|&row| match row {
+            TreeRow::Folder { node, depth } => {
+                let folder = &tree.tree.nodes[node];
+                let (arrow, icon) = if tree.is_expanded(node) {
+                    ("▾ ", "📂")
+                } else {
+                    ("▸ ", "📁")
+                };
+                ListItem::new(Line::from(vec![
+                    Span::raw("  ".repeat(depth)),
+                    Span::styled(arrow, Style::default().fg(theme.muted)),
+                    Span::styled(
+                        format!("{} {}", icon, folder.name),
+                        Style::default().fg(theme.info).add_modifier(Modifier::BOLD),
+                    ),
+                    Span::styled(
+                        format!("  {}", folder.track_count),
+                        Style::default().fg(theme.muted),
+                    ),
+                ]))
+            }
+            TreeRow::Track { track, depth, .. } => {
+                let Some(track) = app.items.get(track) else {
+                    return ListItem::new("");
+                };
+                // CUE sayfasındaki parçalar aynı dosyada olduğundan başlıklarıyla gösterilir
+                let file_name = match (track.cue, track.path.file_name()) {
+                    (None, Some(name)) => name.to_string_lossy().into_owned(),
+                    _ => track.title.clone(),
+                };
+                let (marker, style) = if playing.is_some_and(|p| p.is_same(track)) {
+                    (
+                        "▶ ",
+                        Style::default()
+                            .fg(theme.success)
+                            .add_modifier(Modifier::BOLD),
+                    )
+                } else if app.broken.contains(&track.path) {
+                    (
+                        "⚠ ",
+                        Style::default()
+                            .fg(theme.error)
+                            .add_modifier(Modifier::CROSSED_OUT),
+                    )
+                } else {
+                    ("  ", Style::default().fg(theme.text))
+                };
+                ListItem::new(Line::from(vec![
+                    Span::raw("  ".repeat(depth)),
+                    Span::styled(marker, style),
+                    Span::styled(file_name, style),
+                ]))
+            }
+        })
+        .collect();
+
+    let title = match tree.selected_folder() {
+        Some(node) => format!("📂 Folders · {}", tree.tree.nodes[node].path.display()),
+        None => "📂 Folders".to_string(),
+    };
+    let list = List::new(items)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(title)
+                .border_style(Style::default().fg(theme.primary)),
+        )
+        .highlight_style(
+            Style::default()
+                .bg(theme.secondary)
+                .fg(theme.text)
+                .add_modifier(Modifier::BOLD),
+        )
+        .highlight_symbol("▶ ");
+
+    let mut window_state = ListState::default().with_selected(selected.map(|i| i - start));
+    f.render_stateful_widget(list, area, &mut window_state);
+}
@@ -1,19 +1,38 @@
-use crate::ui::app::{App, ViewMode};
+use crate::library::finder;
+use crate::library::track::format_duration;
+use crate::player::equalizer::{BAND_COUNT, band_label};
+use crate::player::{PlaybackState, RepeatMode};
+use crate::ui::app::{App, Prompt, SearchMode, ViewMode};
+use crate::ui::browser::{Pane, draw_browser};
+use crate::ui::effects::{EffectRow, draw_effects, effect_rows};
+use crate::ui::equalizer::draw_equalizer;
 use crate::ui::help::draw_help_screen;
+use crate::ui::log::{draw_message_log, draw_toast};
+use crate::ui::playlists::{PlaylistPane, draw_playlists};
+use crate::ui::queue::draw_queue_list;
+use crate::ui::tree::draw_tree;
 use ratatui::{
     Frame,
     layout::{Alignment, Constraint, Direction, Layout, Rect},
-    style::{Color, Modifier, Style},
+    style::{Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, List, ListItem, Paragraph},
+    widgets::{Block, Borders, Gauge, List, ListItem, ListState, Paragraph},
 };
 
 /// Ana UI'ı çiz
 pub fn draw_ui(f: &mut Frame, app: &mut App) {
     match app.current_view {
         ViewMode::Help => draw_help_screen(f, app),
-        ViewMode::Normal => draw_main_screen(f, app),
+        ViewMode::Normal
+        | ViewMode::Queue
+        | ViewMode::Messages
+        | ViewMode::Browser
+        | ViewMode::Folders
+        | ViewMode::Playlists
+        | ViewMode::Equalizer
+        | ViewMode::Effects => draw_main_screen(f, app),
     }
+    draw_toast(f, app);
 }
 
 /// Ana ekranı çiz
@@ -24,7 +43,8 @@ fn draw_main_screen(f: &mut Frame, app: &mut App) {
         .constraints(
             [
                 Constraint::Length(3), // Başlık + durum çubuğu
-                Constraint::Min(8),    // Ana liste 
+                Constraint::Min(8),    // Ana liste
+                Constraint::Length(3), // Çalan parça ilerleme çubuğu
                 Constraint::Length(1), // Alt durum çubuğu (tek satır)
             ]
             .as_ref(),
@@ -34,11 +54,29 @@ fn draw_main_screen(f: &mut Frame, app: &mut App) {
     // Üst bilgi paneli çiz
     draw_header_panel(f, main_chunks[0], app);
 
-    // Müzik listesini çiz
-    draw_enhanced_music_list(f, main_chunks[1], app);
+    // Aktif görünümün listesini çiz
+    match app.current_view {
+        ViewMode::Queue => draw_queue_list(f, main_chunks[1], app),
+        ViewMode::Messages => draw_message_log(f, main_chunks[1], app),
+        ViewMode::Browser => draw_browser(f, main_chunks[1], app),
+        ViewMode::Folders => draw_tree(f, main_chunks[1], app),
+        ViewMode::Playlists => draw_playlists(f, main_chunks[1], app),
+        ViewMode::Equalizer => draw_equalizer(f, main_chunks[1], app),
+        ViewMode::Effects => draw_effects(f, main_chunks[1], app),
+        _ => draw_enhanced_music_list(f, main_chunks[1], app),
+    }
 
-    // Alt durum çubuğunu çiz
-    draw_status_bar(f, main_chunks[2], app);
+    // Çalan parçanın ilerlemesini çiz
+    draw_now_playing(f, main_chunks[2], app);
+
+    // Alt durum çubuğunu (arama veya istem sırasında giriş satırını) çiz
+    if let Some(prompt) = &app.prompt {
+        draw_prompt_line(f, main_chunks[3], app, prompt);
+    } else if app.search_input {
+        draw_search_line(f, main_chunks[3], app);
+    } else {
+        draw_status_bar(f, main_chunks[3], app);
+    }
 }
 
 /// Gelişmiş başlık paneli
@@ -60,70 +98,163 @@ fn draw_header_panel(f: &mut Frame, area: Rect, app: &App) {
         Span::styled(
             "🎵 ",
             Style::default()
-                .fg(Color::Rgb(255, 0, 255)) // Neon magenta
+                .fg(app.theme.secondary) // Neon magenta
                 .add_modifier(Modifier::BOLD),
         ),
         Span::styled(
             "MELOIC",
             Style::default()
-                .fg(Color::Rgb(0, 255, 255)) // Neon cyan
+                .fg(app.theme.primary) // Neon cyan
                 .add_modifier(Modifier::BOLD),
         ),
     ])])
-    .block(Block::default()
-        .borders(Borders::ALL)
-        .border_style(Style::default().fg(Color::Rgb(255, 0, 255)))) // Neon magenta border
+    .block(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(app.theme.secondary)),
+    ) // Neon magenta border
     .alignment(Alignment::Center);
     f.render_widget(title, header_chunks[0]);
 
+    // Orta: Tarama ilerlemesi
+    draw_scan_progress(f, header_chunks[1], app);
+
     // Sağ: İstatistikler - Neon tema
-    let stats_text = format!("📀 {} tracks", app.total_tracks);
+    let stats_text = format!(
+        "📀 {} tracks · 🎶 {} queued",
+        app.total_tracks,
+        app.queue.upcoming().len()
+    );
 
     let stats = Paragraph::new(vec![Line::from(vec![Span::styled(
         stats_text,
-        Style::default().fg(Color::Rgb(255, 255, 0)), // Neon yellow
+        Style::default().fg(app.theme.accent), // Neon yellow
     )])])
-    .block(Block::default()
-        .borders(Borders::ALL)
-        .title("Library")
-        .border_style(Style::default().fg(Color::Rgb(0, 255, 0)))) // Neon green border
+    .block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title("Library")
+            .border_style(Style::default().fg(app.theme.success)),
+    ) // Neon green border
     .alignment(Alignment::Center);
     f.render_widget(stats, header_chunks[2]);
 }
 
+/// Kütüphane taramasının ilerleme göstergesi
+fn draw_scan_progress(f: &mut Frame, area: Rect, app: &App) {
+    if app.is_analyzing() {
+        draw_loudness_progress(f, area, app);
+        return;
+    }
+    let progress = &app.scan_progress;
+    let (icon, label, color) = if app.is_scanning() {
+        const SPINNER: [&str; 4] = ["◐", "◓", "◑", "◒"];
+        let frame = progress.seen / 16 % SPINNER.len();
+        (SPINNER[frame], "Scanning", app.theme.accent) // Neon yellow
+    } else if progress.done {
+        ("✔", "Scan complete", app.theme.success) // Neon green
+    } else {
+        return;
+    };
+
+    let mut spans = vec![
+        Span::styled(
+            format!("{} {} ", icon, label),
+            Style::default().fg(color).add_modifier(Modifier::BOLD),
+        ),
+        Span::styled(
+            format!("{} seen · {} parsed", progress.seen, progress.parsed),
+            Style::default().fg(app.theme.text_dim),
+        ),
+    ];
+    if progress.errors > 0 {
+        spans.push(Span::styled(
+            format!(" · {} errors", progress.errors),
+            Style::default().fg(app.theme.error), // Neon red
+        ));
+    }
+
+    let widget = Paragraph::new(Line::from(spans))
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(app.theme.muted)),
+        )
+        .alignment(Alignment::Center);
+    f.render_widget(widget, area);
+}
+
+/// Ses yüksekliği ölçümünün ilerleme göstergesi
+fn draw_loudness_progress(f: &mut Frame, area: Rect, app: &App) {
+    let (done, total) = app.loudness_progress;
+    let spans = vec![
+        Span::styled(
+            "🔊 Measuring loudness ",
+            Style::default()
+                .fg(app.theme.accent)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::styled(
+            format!("{} / {} files", done, total),
+            Style::default().fg(app.theme.text_dim),
+        ),
+    ];
+
+    let widget = Paragraph::new(Line::from(spans))
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(app.theme.muted)),
+        )
+        .alignment(Alignment::Center);
+    f.render_widget(widget, area);
+}
+
 /// Gelişmiş müzik listesi
 fn draw_enhanced_music_list(f: &mut Frame, area: Rect, app: &mut App) {
     if app.is_empty() {
-        let empty_msg = "🎵 No music files found in the 'music' directory\n\n📁 Add some .mp3, .flac, .wav or .ogg files";
+        let empty_msg = if app.is_searching() && !app.items.is_empty() {
+            format!("🔍 No tracks match '{}'", app.search_query)
+        } else if app.is_scanning() {
+            "🔍 Scanning your music library...".to_string()
+        } else {
+            "🎵 No music files found in the 'music' directory\n\n📁 Add some .mp3, .flac, .wav or .ogg files".to_string()
+        };
 
         let empty_widget = Paragraph::new(empty_msg)
             .block(
                 Block::default()
                     .borders(Borders::ALL)
                     .title("🎼 Music Library")
-                    .style(Style::default().fg(Color::Rgb(255, 0, 255))), // Neon magenta
+                    .style(Style::default().fg(app.theme.secondary)), // Neon magenta
             )
-            .style(Style::default().fg(Color::Rgb(255, 255, 0))) // Neon yellow
+            .style(Style::default().fg(app.theme.accent)) // Neon yellow
             .alignment(Alignment::Center);
 
         f.render_widget(empty_widget, area);
         return;
     }
 
-    let display_items = app.get_display_items().clone();
+    // Büyük kütüphanelerde yalnızca görünen satırlar için öğe üretilir
+    let height = area.height.saturating_sub(2) as usize;
+    let (start, end) = visible_window(&mut app.state, app.filtered_items.len(), height);
     let selected = app.state.selected();
+    let query = app.search_query.trim();
+    let highlight_style = Style::default()
+        .fg(app.theme.accent) // Neon yellow
+        .add_modifier(Modifier::BOLD | Modifier::UNDERLINED);
 
-    let items: Vec<ListItem> = display_items
+    let items: Vec<ListItem> = app.filtered_items[start..end]
         .iter()
+        .map(|&i| &app.items[i])
         .enumerate()
-        .map(|(display_idx, (_, path))| {
-            let filename = path
-                .file_name()
-                .and_then(|name| name.to_str())
-                .unwrap_or("Unknown");
-
-            // Dosya uzantısına göre ikon seç
-            let icon = match path.extension().and_then(|ext| ext.to_str()) {
+        .map(|(row, track)| {
+            let display_idx = start + row;
+            // Çalınamayan parçalar işaretlenir, diğerlerinde dosya uzantısına göre ikon seç
+            let broken = app.broken.contains(&track.path);
+            let marked = app.marked.contains(&track.id());
+            let icon = match track.extension().as_deref() {
+                _ if broken => "⚠️",
                 Some("mp3") => "🎵",
                 Some("flac") => "🎶",
                 Some("wav") => "🔊",
@@ -134,46 +265,258 @@ fn draw_enhanced_music_list(f: &mut Frame, area: Rect, app: &mut App) {
             // Seçili öğe için özel stil - Neon tema
             let number_style = if Some(display_idx) == selected {
                 Style::default()
-                    .fg(Color::Rgb(0, 255, 255)) // Neon cyan
+                    .fg(app.theme.primary) // Neon cyan
+                    .add_modifier(Modifier::BOLD)
+            } else if marked {
+                Style::default()
+                    .fg(app.theme.accent) // Neon yellow
                     .add_modifier(Modifier::BOLD)
             } else {
-                Style::default().fg(Color::Rgb(100, 100, 100)) // Daha parlak gri
+                Style::default().fg(app.theme.muted) // Daha parlak gri
             };
 
-            let filename_style = if Some(display_idx) == selected {
+            let title_style = if Some(display_idx) == selected {
                 Style::default()
-                    .fg(Color::Rgb(255, 255, 255)) // Parlak beyaz
+                    .fg(app.theme.text) // Parlak beyaz
                     .add_modifier(Modifier::BOLD)
+            } else if broken {
+                Style::default()
+                    .fg(app.theme.error) // Neon red
+                    .add_modifier(Modifier::CROSSED_OUT)
+            } else if marked {
+                Style::default().fg(app.theme.accent) // Neon yellow
             } else {
-                Style::default().fg(Color::Rgb(180, 180, 180)) // Parlak gri
+                Style::default().fg(app.theme.text_dim) // Parlak gri
             };
 
-            ListItem::new(Line::from(vec![
-                Span::styled(format!("{:3}. ", display_idx + 1), number_style),
-                Span::styled(format!("{} ", icon), Style::default().fg(Color::Rgb(0, 255, 0))), // Neon green
-                Span::styled(filename, filename_style),
-            ]))
+            let icon_color = if broken {
+                app.theme.error // Neon red
+            } else {
+                app.theme.success // Neon green
+            };
+
+            // Bulanık aramada eşleşen karakterler vurgulanır (ad, ardından boşluk ve albüm)
+            let name = track.display_name();
+            let positions = if app.fuzzy_active() {
+                app.finder.positions(&finder::candidate_text(track), query)
+            } else {
+                Vec::new()
+            };
+            let album_offset = name.chars().count() + 1;
+
+            // İşaretli parçalarda numaradan sonra nokta yerine ● gösterilir
+            let separator = if marked { "●" } else { "." };
+            let mut spans = vec![
+                Span::styled(format!("{:3}{} ", display_idx + 1, separator), number_style),
+                Span::styled(format!("{} ", icon), Style::default().fg(icon_color)),
+            ];
+            spans.extend(highlighted_spans(
+                &name,
+                &positions,
+                0,
+                title_style,
+                highlight_style,
+            ));
+            if let Some(album) = &track.album {
+                let album_style = Style::default().fg(app.theme.info); // Neon blue
+                spans.push(Span::styled("  💿 ", album_style));
+                spans.extend(highlighted_spans(
+                    album,
+                    &positions,
+                    album_offset,
+                    album_style,
+                    highlight_style,
+                ));
+            }
+            if let Some(duration) = track.duration {
+                spans.push(Span::styled(
+                    format!("  {}", format_duration(duration)),
+                    Style::default().fg(app.theme.muted),
+                ));
+            }
+
+            ListItem::new(Line::from(spans))
         })
         .collect();
 
-    let list_title = "🎼 Music Library".to_string();
+    let list_title = if app.is_searching() {
+        let (icon, pending) = match app.search_mode {
+            SearchMode::Fuzzy if app.fuzzy_pending => ("🔎", " · matching…"),
+            SearchMode::Fuzzy => ("🔎", ""),
+            SearchMode::Substring => ("🔍", ""),
+        };
+        format!(
+            "🎼 Music Library · {} '{}' · {} of {}{}",
+            icon,
+            app.search_query,
+            app.filtered_items.len(),
+            app.items.len(),
+            pending
+        )
+    } else {
+        "🎼 Music Library".to_string()
+    };
+    let list_title = if app.marked.is_empty() {
+        list_title
+    } else {
+        format!("{} · {} marked", list_title, app.marked.len())
+    };
 
     let list = List::new(items)
         .block(
             Block::default()
                 .borders(Borders::ALL)
                 .title(list_title)
-                .border_style(Style::default().fg(Color::Rgb(0, 255, 255))), // Neon cyan border
+                .border_style(Style::default().fg(app.theme.primary)), // Neon cyan border
         )
         .highlight_style(
             Style::default()
-                .bg(Color::Rgb(255, 0, 255)) // Neon magenta background
-                .fg(Color::Rgb(255, 255, 255)) // Parlak beyaz text
+                .bg(app.theme.secondary) // Neon magenta background
+                .fg(app.theme.text) // Parlak beyaz text
                 .add_modifier(Modifier::BOLD),
         )
         .highlight_symbol("▶ ");
 
-    f.render_stateful_widget(list, area, &mut app.state);
+    let mut window_state = ListState::default().with_selected(selected.map(|i| i - start));
+    f.render_stateful_widget(list, area, &mut window_state);
+}
+
+/// Seçili öğeyi görünür tutacak şekilde kaydırma konumunu güncelle ve görünen aralığı döndür
+pub fn visible_window(state: &mut ListState, len: usize, height: usize) -> (usize, usize) {
+    let height = height.max(1);
+    let mut offset = state.offset().min(len.saturating_sub(1));
+    if let Some(selected) = state.selected() {
+        if selected < offset {
+            offset = selected;
+        } else if selected >= offset + height {
+            offset = selected + 1 - height;
+        }
+    }
+    *state.offset_mut() = offset;
+    (offset, (offset + height).min(len))
+}
+
+/// Metni, verilen karakter sıralarındakiler vurgulanmış parçalara böl;
+/// `offset` metnin eşleşme metnindeki başlangıç sırasıdır
+fn highlighted_spans(
+    text: &str,
+    positions: &[usize],
+    offset: usize,
+    style: Style,
+    highlight: Style,
+) -> Vec<Span<'static>> {
+    let mut spans = Vec::new();
+    let mut run = String::new();
+    let mut run_highlighted = false;
+    for (i, c) in text.chars().enumerate() {
+        let highlighted = positions.binary_search(&(offset + i)).is_ok();
+        if highlighted != run_highlighted && !run.is_empty() {
+            let style = if run_highlighted { highlight } else { style };
+            spans.push(Span::styled(std::mem::take(&mut run), style));
+        }
+        run_highlighted = highlighted;
+        run.push(c);
+    }
+    if !run.is_empty() {
+        let style = if run_highlighted { highlight } else { style };
+        spans.push(Span::styled(run, style));
+    }
+    spans
+}
+
+/// Çalan parçanın geçen/toplam süresi ve ilerleme çubuğu
+fn draw_now_playing(f: &mut Frame, area: Rect, app: &App) {
+    let Some(track) = &app.player.current else {
+        let idle = Paragraph::new("🎵 Nothing playing")
+            .style(Style::default().fg(app.theme.muted))
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(app.theme.muted)),
+            )
+            .alignment(Alignment::Center);
+        f.render_widget(idle, area);
+        return;
+    };
+
+    let elapsed = app.player.position();
+    let total = app.player.duration();
+    let (ratio, label) = match total {
+        Some(total) if !total.is_zero() => (
+            (elapsed.as_secs_f64() / total.as_secs_f64()).clamp(0.0, 1.0),
+            format!("{} / {}", format_duration(elapsed), format_duration(total)),
+        ),
+        _ => (0.0, format_duration(elapsed)),
+    };
+
+    let gauge = Gauge::default()
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(format!("🎧 {}", track.display_name()))
+                .border_style(Style::default().fg(app.theme.info)), // Neon blue border
+        )
+        .gauge_style(
+            Style::default()
+                .fg(app.theme.primary) // Neon cyan
+                .bg(app.theme.muted),
+        )
+        .label(Span::styled(
+            label,
+            Style::default()
+                .fg(app.theme.text)
+                .add_modifier(Modifier::BOLD),
+        ))
+        .ratio(ratio);
+    f.render_widget(gauge, area);
+}
+
+/// Arama satırı
+fn draw_search_line(f: &mut Frame, area: Rect, app: &App) {
+    let prompt = match app.search_mode {
+        SearchMode::Substring => "🔍 /",
+        SearchMode::Fuzzy => "🔎 fuzzy: ",
+    };
+    let line = Line::from(vec![
+        Span::styled(
+            prompt,
+            Style::default()
+                .fg(app.theme.accent) // Neon yellow
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::styled(
+            app.search_query.clone(),
+            Style::default().fg(app.theme.text),
+        ),
+        Span::styled("█", Style::default().fg(app.theme.primary)),
+        Span::styled(
+            format!(
+                "  {} matches · Enter to keep, Esc to clear",
+                app.filtered_items.len()
+            ),
+            Style::default().fg(app.theme.muted),
+        ),
+    ]);
+    f.render_widget(Paragraph::new(line), area);
+}
+
+/// Metin isteyen komutun giriş satırı
+fn draw_prompt_line(f: &mut Frame, area: Rect, app: &App, prompt: &Prompt) {
+    let line = Line::from(vec![
+        Span::styled(
+            prompt.label(),
+            Style::default()
+                .fg(app.theme.accent)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::styled(prompt.text.clone(), Style::default().fg(app.theme.text)),
+        Span::styled("█", Style::default().fg(app.theme.primary)),
+        Span::styled(
+            "  Enter to confirm, Esc to cancel",
+            Style::default().fg(app.theme.muted),
+        ),
+    ]);
+    f.render_widget(Paragraph::new(line), area);
 }
 
 /// Alt durum çubuğu
@@ -189,46 +532,143 @@ fn draw_status_bar(f: &mut Frame, area: Rect, app: &App) {
         .split(area);
 
     // Sol: Seçili track bilgisi
-    let current_selection = app
-        .state
-        .selected()
-        .map(|i| format!("Track {}/{}", i + 1, app.filtered_items.len()))
-        .unwrap_or_else(|| "No selection".to_string());
+    let current_selection = match app.current_view {
+        ViewMode::Queue => app
+            .queue_state
+            .selected()
+            .map(|i| format!("Queue {}/{}", i + 1, app.queue.len())),
+        ViewMode::Messages => app
+            .messages_state
+            .selected()
+            .map(|i| format!("Message {}/{}", i + 1, app.messages.len())),
+        ViewMode::Browser => {
+            let browser = &app.browser;
+            match browser.pane {
+                Pane::Artists => browser
+                    .artist_state
+                    .selected()
+                    .map(|i| format!("Artist {}/{}", i + 1, browser.artists.len())),
+                Pane::Albums => browser.album_state.selected().map(|i| {
+                    let len = browser.selected_artist().map_or(0, |a| a.albums.len());
+                    format!("Album {}/{}", i + 1, len)
+                }),
+                Pane::Tracks => browser.track_state.selected().map(|i| {
+                    let len = browser.selected_album().map_or(0, |a| a.tracks.len());
+                    format!("Track {}/{}", i + 1, len)
+                }),
+            }
+        }
+        ViewMode::Folders => app.folders.selected_folder().map(|node| {
+            let folder = &app.folders.tree.nodes[node];
+            format!("Folder · {} tracks", folder.track_count)
+        }),
+        ViewMode::Playlists => match app.playlists.pane {
+            PlaylistPane::Lists => app
+                .playlists
+                .list_state
+                .selected()
+                .map(|i| format!("Playlist {}/{}", i + 1, app.playlists.len())),
+            PlaylistPane::Entries => app.playlists.entry_state.selected().map(|i| {
+                let len = app
+                    .playlists
+                    .current()
+                    .map_or(0, |list| list.playlist.entries.len());
+                format!("Entry {}/{}", i + 1, len)
+            }),
+        },
+        ViewMode::Equalizer => Some(format!(
+            "Band {}/{} · {} Hz",
+            app.eq_band + 1,
+            BAND_COUNT,
+            band_label(app.eq_band)
+        )),
+        ViewMode::Effects => {
+            let chain = app.player.effects.chain();
+            let rows = effect_rows(&chain);
+            app.effects_state
+                .selected()
+                .and_then(|i| rows.get(i))
+                .map(|row| match *row {
+                    EffectRow::Effect(i) => {
+                        format!(
+                            "Effect {}/{} · {}",
+                            i + 1,
+                            chain.len(),
+                            chain[i].spec().label
+                        )
+                    }
+                    EffectRow::Param(i, p) => {
+                        let spec = chain[i].spec();
+                        format!("{} · {}", spec.label, spec.params[p].label)
+                    }
+                })
+        }
+        _ => app
+            .state
+            .selected()
+            .map(|i| format!("Track {}/{}", i + 1, app.filtered_items.len())),
+    }
+    .unwrap_or_else(|| "No selection".to_string());
 
     let selection_widget = Paragraph::new(current_selection).style(
         Style::default()
-            .fg(Color::Rgb(0, 255, 255)) // Neon cyan
+            .fg(app.theme.primary) // Neon cyan
             .add_modifier(Modifier::BOLD),
     );
 
     f.render_widget(selection_widget, top_chunks[0]);
 
-    // Orta: Help mesajı - Neon tema
-    let help_text = "Press 'h' for help";
-    let help_widget = Paragraph::new(help_text)
-        .style(
+    // Orta: Çalma modları ve help mesajı - Neon tema
+    let mode_style = |active: bool| {
+        if active {
             Style::default()
-                .fg(Color::Rgb(255, 255, 0)) // Neon yellow
+                .fg(app.theme.success)
                 .add_modifier(Modifier::BOLD)
-                .add_modifier(Modifier::UNDERLINED),
+        } else {
+            Style::default().fg(app.theme.muted)
+        }
+    };
+    let repeat_text = match app.queue.repeat {
+        RepeatMode::Off => "🔁 off",
+        RepeatMode::All => "🔁 all",
+        RepeatMode::One => "🔂 one",
+    };
+    let volume_span = if app.player.muted {
+        Span::styled("🔇 muted", Style::default().fg(app.theme.warning))
+    } else {
+        Span::styled(
+            format!("🔊 {}%", (app.player.volume * 100.0).round()),
+            Style::default().fg(app.theme.info),
         )
-        .alignment(Alignment::Center);
+    };
+    let help_widget = Paragraph::new(Line::from(vec![
+        Span::styled("🔀 shuffle", mode_style(app.queue.is_shuffled())),
+        Span::raw("  "),
+        Span::styled(repeat_text, mode_style(app.queue.repeat != RepeatMode::Off)),
+        Span::raw("  "),
+        volume_span,
+        Span::raw("  "),
+        Span::styled(
+            "Press 'h' for help",
+            Style::default()
+                .fg(app.theme.accent) // Neon yellow
+                .add_modifier(Modifier::BOLD)
+                .add_modifier(Modifier::UNDERLINED),
+        ),
+    ]))
+    .alignment(Alignment::Center);
 
     f.render_widget(help_widget, top_chunks[1]);
 
     // Sağ: Çalan şarkı durumu - Neon tema
-    let playback_info = match (&app.current_track, &app.playback_state) {
+    let playback_info = match (&app.player.current, &app.player.state) {
         (Some(track), state) => {
-            let filename = track
-                .file_name()
-                .and_then(|name| name.to_str())
-                .unwrap_or("Unknown");
             let status_icon = match state {
-                crate::ui::app::PlaybackState::Playing => "▶️",
-                crate::ui::app::PlaybackState::Paused => "⏸️",
-                crate::ui::app::PlaybackState::Stopped => "⏹️",
+                PlaybackState::Playing => "▶️",
+                PlaybackState::Paused => "⏸️",
+                PlaybackState::Stopped => "⏹️",
             };
-            format!("{} {}", status_icon, filename)
+            format!("{} {}", status_icon, track.display_name())
         }
         (None, _) => "🎵 No track playing".to_string(),
     };
@@ -236,13 +676,10 @@ fn draw_status_bar(f: &mut Frame, area: Rect, app: &App) {
     let playback_widget = Paragraph::new(playback_info)
         .style(
             Style::default()
-                .fg(Color::Rgb(0, 255, 0)) // Neon green
+                .fg(app.theme.success) // Neon green
                 .add_modifier(Modifier::BOLD),
         )
         .alignment(Alignment::Right);
 
     f.render_widget(playback_widget, top_chunks[2]);
 }
-
-
-